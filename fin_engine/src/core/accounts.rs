@@ -1,8 +1,11 @@
 //! Investment accounts and asset tracking
 
+use super::capital_gains::GainKind;
+use super::expenses::ExpenseCategory;
+use crate::market::MarketProfile;
 use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
-use std::time::Duration;
 
 /// Type of investment account
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -17,6 +20,65 @@ pub enum AccountKind {
     SinkingFund { goal: String },
 }
 
+impl AccountKind {
+    /// Order in which accounts are drawn down in retirement (lowest first)
+    /// Taxable accounts go first so tax-advantaged growth lasts longest;
+    /// emergency and sinking funds are kept as a last resort.
+    pub fn withdrawal_priority(&self) -> u8 {
+        match self {
+            AccountKind::Taxable => 0,
+            AccountKind::Retirement { .. } => 1,
+            AccountKind::EmergencyFund => 2,
+            AccountKind::SinkingFund { .. } => 3,
+        }
+    }
+}
+
+/// Split of an invested balance between asset classes
+/// Shares are fractions of the balance and add up to one.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Allocation {
+    /// Share held in stocks
+    pub equity: Decimal,
+    /// Share held in bonds
+    pub bonds: Decimal,
+}
+
+impl Allocation {
+    /// Creates an allocation with `equity` in stocks and the rest in bonds
+    pub fn new(equity: Decimal) -> Result<Self, String> {
+        if equity < Decimal::ZERO || equity > Decimal::ONE {
+            return Err("Equity share must be between 0 and 1".to_string());
+        }
+        Ok(Allocation {
+            equity,
+            bonds: Decimal::ONE - equity,
+        })
+    }
+
+    /// Everything in stocks
+    pub fn all_equity() -> Self {
+        Allocation {
+            equity: Decimal::ONE,
+            bonds: Decimal::ZERO,
+        }
+    }
+
+    /// Everything in bonds
+    pub fn all_bonds() -> Self {
+        Allocation {
+            equity: Decimal::ZERO,
+            bonds: Decimal::ONE,
+        }
+    }
+}
+
+impl Default for Allocation {
+    fn default() -> Self {
+        Self::all_equity()
+    }
+}
+
 /// An investment or savings account
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Account {
@@ -28,12 +90,22 @@ pub struct Account {
     pub kind: AccountKind,
     /// Current balance
     pub balance: Decimal,
-    /// Date account was opened (for holding period calculations)
-    pub opened_at: std::time::SystemTime,
     /// Total contributions made to this account
     pub total_contributions: Decimal,
     /// Total withdrawals from this account
     pub total_withdrawals: Decimal,
+    /// Contributions made in the current calendar year
+    #[serde(default)]
+    pub contributions_this_year: Decimal,
+    /// Current stocks/bonds split of invested accounts (drifts with returns)
+    #[serde(default)]
+    pub allocation: Allocation,
+    /// Split the account is rebalanced back to
+    #[serde(default)]
+    pub target_allocation: Allocation,
+    /// Game month the account was opened in (see `GameState::months_elapsed`)
+    #[serde(default)]
+    pub opened_month: u32,
 }
 
 impl Account {
@@ -44,12 +116,21 @@ impl Account {
             name,
             kind,
             balance: Decimal::ZERO,
-            opened_at: std::time::SystemTime::now(),
             total_contributions: Decimal::ZERO,
             total_withdrawals: Decimal::ZERO,
+            contributions_this_year: Decimal::ZERO,
+            allocation: Allocation::default(),
+            target_allocation: Allocation::default(),
+            opened_month: 0,
         }
     }
 
+    /// Invests the account at a new target split
+    pub fn set_allocation(&mut self, allocation: Allocation) {
+        self.allocation = allocation;
+        self.target_allocation = allocation;
+    }
+
     /// Deposits money into the account
     pub fn deposit(&mut self, amount: Decimal) -> Result<(), String> {
         if amount <= Decimal::ZERO {
@@ -57,10 +138,13 @@ impl Account {
         }
         self.balance += amount;
         self.total_contributions += amount;
+        self.contributions_this_year += amount;
         Ok(())
     }
 
     /// Withdraws money from the account
+    /// Withdrawals cancel out contributions made earlier in the year, so money
+    /// moved in and straight back out doesn't count toward tax deductions.
     pub fn withdraw(&mut self, amount: Decimal) -> Result<(), String> {
         if amount <= Decimal::ZERO {
             return Err("Withdrawal amount must be positive".to_string());
@@ -70,14 +154,13 @@ impl Account {
         }
         self.balance -= amount;
         self.total_withdrawals += amount;
+        self.contributions_this_year = (self.contributions_this_year - amount).max(Decimal::ZERO);
         Ok(())
     }
 
-    /// Returns the account's holding period
-    pub fn holding_period(&self) -> Duration {
-        std::time::SystemTime::now()
-            .duration_since(self.opened_at)
-            .unwrap_or(Duration::ZERO)
+    /// Returns the number of game months since the account was opened
+    pub fn holding_months(&self, current_month: u32) -> u32 {
+        current_month.saturating_sub(self.opened_month)
     }
 
     /// Returns the capital gain/loss (balance - contributions + withdrawals)
@@ -85,12 +168,56 @@ impl Account {
         self.balance - self.total_contributions + self.total_withdrawals
     }
 
+    /// Months left until gains in this taxable account pass the market's time test
+    /// Zero once past it; None for other account kinds or markets without a time test.
+    pub fn months_until_tax_exempt(
+        &self,
+        current_month: u32,
+        market: &dyn MarketProfile,
+    ) -> Option<u32> {
+        if !matches!(self.kind, AccountKind::Taxable) {
+            return None;
+        }
+        Some(
+            market
+                .tax_free_holding_months(GainKind::Securities)?
+                .saturating_sub(self.holding_months(current_month)),
+        )
+    }
+
     /// Applies market returns (can be positive or negative)
     pub fn apply_return(&mut self, return_rate: Decimal) {
         self.balance *= Decimal::ONE + return_rate;
     }
+
+    /// Restores the target split by selling the overweight sleeve
+    /// Returns the amount sold (and bought back in the other sleeve).
+    pub fn rebalance(&mut self) -> Decimal {
+        let sold = (self.balance * (self.allocation.equity - self.target_allocation.equity)).abs();
+        self.allocation = self.target_allocation;
+        sold
+    }
+
+    /// Applies separate returns to the stock and bond sleeves
+    /// The split drifts toward whichever sleeve grew faster.
+    pub fn apply_sleeve_returns(&mut self, equity_return: Decimal, bond_return: Decimal) {
+        let equity = self.balance * self.allocation.equity * (Decimal::ONE + equity_return);
+        let bonds = self.balance * self.allocation.bonds * (Decimal::ONE + bond_return);
+        self.balance = equity + bonds;
+        if self.balance > Decimal::ZERO {
+            let equity_share = equity / self.balance;
+            self.allocation = Allocation {
+                equity: equity_share,
+                bonds: Decimal::ONE - equity_share,
+            };
+        }
+    }
 }
 
+/// Typical annual rent as a share of a property's value (gross rental yield)
+/// Markets can set their own through `MarketProfile::gross_rental_yield`.
+pub const GROSS_RENTAL_YIELD: Decimal = dec!(0.04);
+
 /// Physical or other asset
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Asset {
@@ -104,10 +231,17 @@ pub struct Asset {
     pub value: Decimal,
     /// Purchase price
     pub purchase_price: Decimal,
-    /// Date acquired
-    pub acquired_at: std::time::SystemTime,
     /// Monthly maintenance/depreciation cost
     pub monthly_cost: Decimal,
+    /// Whether the property is let to tenants (real estate only)
+    #[serde(default)]
+    pub rented: bool,
+    /// Monthly rent charged to tenants
+    #[serde(default)]
+    pub rental_income: Decimal,
+    /// Game month the asset was acquired in (see `GameState::months_elapsed`)
+    #[serde(default)]
+    pub acquired_month: u32,
 }
 
 /// Category of physical asset
@@ -118,6 +252,18 @@ pub enum AssetCategory {
     Other,
 }
 
+impl AssetCategory {
+    /// Typical share of the value lost when selling in a hurry (fees, discounts, dealer margin)
+    /// Markets can set their own through `MarketProfile::liquidation_haircut`.
+    pub fn liquidation_haircut(&self) -> Decimal {
+        match self {
+            AssetCategory::RealEstate => dec!(0.10),
+            AssetCategory::Vehicle => dec!(0.20),
+            AssetCategory::Other => dec!(0.30),
+        }
+    }
+}
+
 impl Asset {
     /// Creates a new asset
     pub fn new(
@@ -133,8 +279,10 @@ impl Asset {
             category,
             value: purchase_price, // Initial value = purchase price
             purchase_price,
-            acquired_at: std::time::SystemTime::now(),
             monthly_cost,
+            rented: false,
+            rental_income: Decimal::ZERO,
+            acquired_month: 0,
         }
     }
 
@@ -143,6 +291,45 @@ impl Asset {
         self.value - self.purchase_price
     }
 
+    /// Returns the number of game months the asset has been owned
+    pub fn holding_months(&self, current_month: u32) -> u32 {
+        current_month.saturating_sub(self.acquired_month)
+    }
+
+    /// Returns the expense category its upkeep is charged to
+    pub fn upkeep_category(&self) -> ExpenseCategory {
+        match self.category {
+            AssetCategory::RealEstate => ExpenseCategory::Essential,
+            AssetCategory::Vehicle => ExpenseCategory::Transportation,
+            AssetCategory::Other => ExpenseCategory::Other,
+        }
+    }
+
+    /// Returns the ID of the recurring upkeep expense added with the asset
+    pub fn upkeep_expense_id(&self) -> String {
+        format!("asset_{}", self.id)
+    }
+
+    /// Returns the ID of the passive income source for its rent
+    pub fn rental_income_id(&self) -> String {
+        format!("rent_{}", self.id)
+    }
+
+    /// Monthly rent tenants would typically pay at its current value,
+    /// given the market's annual gross rental yield
+    pub fn market_rent(&self, gross_yield: Decimal) -> Decimal {
+        (self.value * gross_yield / Decimal::from(12)).round_dp(0)
+    }
+
+    /// Rent left after the upkeep, before tax (zero unless rented out)
+    pub fn net_rental_income(&self) -> Decimal {
+        if self.rented {
+            self.rental_income - self.monthly_cost
+        } else {
+            Decimal::ZERO
+        }
+    }
+
     /// Applies depreciation (negative percentage)
     pub fn depreciate(&mut self, rate: Decimal) {
         self.value *= Decimal::ONE + rate;
@@ -156,6 +343,7 @@ impl Asset {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::markets::czech::CzechMarket;
     use rust_decimal_macros::dec;
 
     #[test]
@@ -184,6 +372,7 @@ mod tests {
         account.withdraw(dec!(300)).unwrap();
         assert_eq!(account.balance, dec!(700));
         assert_eq!(account.total_withdrawals, dec!(300));
+        assert_eq!(account.contributions_this_year, dec!(700));
 
         // Should fail - insufficient funds
         assert!(account.withdraw(dec!(800)).is_err());
@@ -204,6 +393,51 @@ mod tests {
         assert_eq!(gain, dec!(100)); // Gained 100
     }
 
+    #[test]
+    fn test_months_until_tax_exempt() {
+        let market = CzechMarket;
+        let mut account = Account::new(
+            "acc1".to_string(),
+            "Brokerage".to_string(),
+            AccountKind::Taxable,
+        );
+        account.opened_month = 5;
+
+        assert_eq!(account.months_until_tax_exempt(5, &market), Some(36));
+        assert_eq!(account.months_until_tax_exempt(40, &market), Some(1));
+        assert_eq!(account.months_until_tax_exempt(41, &market), Some(0));
+        assert_eq!(account.months_until_tax_exempt(100, &market), Some(0));
+
+        account.kind = AccountKind::EmergencyFund;
+        assert_eq!(account.months_until_tax_exempt(5, &market), None);
+
+        #[cfg(feature = "usa")]
+        {
+            account.kind = AccountKind::Taxable;
+            let usa = crate::markets::usa::UsaMarket;
+            assert_eq!(account.months_until_tax_exempt(5, &usa), None);
+        }
+    }
+
+    #[test]
+    fn test_market_rent_follows_rental_yield() {
+        let flat = Asset::new(
+            "flat".to_string(),
+            "Flat".to_string(),
+            AssetCategory::RealEstate,
+            dec!(3000000),
+            Decimal::ZERO,
+        );
+        assert_eq!(flat.market_rent(GROSS_RENTAL_YIELD), dec!(10000));
+
+        #[cfg(feature = "usa")]
+        {
+            use crate::market::MarketProfile;
+            let usa = crate::markets::usa::UsaMarket;
+            assert_eq!(flat.market_rent(usa.gross_rental_yield()), dec!(15000));
+        }
+    }
+
     #[test]
     fn test_asset_depreciation() {
         let mut car = Asset::new(
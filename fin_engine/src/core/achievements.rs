@@ -0,0 +1,190 @@
+//! Achievements unlocked once and kept for the rest of the game
+
+use super::accounts::AccountKind;
+use super::game_state::GameState;
+use crate::market::MarketProfile;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+
+/// Cash plus account balances needed for the savings achievement (CZK, see `MarketProfile::scale_czk`)
+pub const SAVINGS_MILESTONE: Decimal = dec!(100000);
+
+/// A one-time unlock recorded when its condition is first reached
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Achievement {
+    /// Got a job
+    FirstJob,
+    /// Cash and accounts reached `SAVINGS_MILESTONE` in the market currency
+    FirstHundredKSaved,
+    /// Made it to the recovery after a recession
+    SurvivedRecession,
+    /// Contributed a retirement account's full annual limit in one year
+    MaxedRetirementYear,
+}
+
+impl Achievement {
+    /// Returns all achievements
+    pub fn all() -> [Achievement; 4] {
+        [
+            Achievement::FirstJob,
+            Achievement::FirstHundredKSaved,
+            Achievement::SurvivedRecession,
+            Achievement::MaxedRetirementYear,
+        ]
+    }
+
+    /// Returns display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Achievement::FirstJob => "First Job",
+            Achievement::FirstHundredKSaved => "First Savings Milestone",
+            Achievement::SurvivedRecession => "Survived a Recession",
+            Achievement::MaxedRetirementYear => "Maxed Retirement Savings for a Year",
+        }
+    }
+
+    /// Checks whether the unlock condition holds right now
+    pub fn is_reached(&self, state: &GameState, market: &dyn MarketProfile) -> bool {
+        let finances = &state.finances;
+        match self {
+            Achievement::FirstJob => state.career.is_employed(),
+            Achievement::FirstHundredKSaved => {
+                let saved: Decimal =
+                    finances.cash + finances.accounts.iter().map(|a| a.balance).sum::<Decimal>();
+                saved >= market.scale_czk(SAVINGS_MILESTONE)
+            }
+            Achievement::SurvivedRecession => state.economy.recessions_survived > 0,
+            Achievement::MaxedRetirementYear => {
+                let limits = market.available_accounts();
+                finances.accounts.iter().any(|account| {
+                    let AccountKind::Retirement { account_type_id } = &account.kind else {
+                        return false;
+                    };
+                    limits
+                        .iter()
+                        .find(|t| &t.id == account_type_id)
+                        .and_then(|t| t.annual_limit)
+                        .is_some_and(|limit| account.contributions_this_year >= limit)
+                })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::accounts::Account;
+    use crate::core::career::{CareerField, Job, JobLevel};
+    use crate::core::economy::{EconomyPhase, MARKET_CRASH_DROP};
+    use crate::core::events::Event;
+    use crate::core::phase::GamePhase;
+    use crate::markets::czech::CzechMarket;
+
+    fn settle(state: &mut GameState) {
+        state.phase = GamePhase::Execution { current_day: 30 };
+        state.advance_execution_day(&CzechMarket).unwrap();
+        state.phase = GamePhase::Planning;
+    }
+
+    #[test]
+    fn test_first_job_unlocks_and_sticks() {
+        let mut state =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 25, 2024).unwrap();
+        settle(&mut state);
+        assert!(!state.achievements.contains(&Achievement::FirstJob));
+
+        state.career.accept_job(Job::new(
+            "job".to_string(),
+            "Clerk".to_string(),
+            CareerField::Retail,
+            JobLevel::Entry,
+            dec!(25000),
+            None,
+        ));
+        settle(&mut state);
+        assert!(state.achievements.contains(&Achievement::FirstJob));
+
+        // Quitting doesn't take it away, even across a save
+        state.quit_job();
+        settle(&mut state);
+        let restored = GameState::from_json(&state.to_json().unwrap()).unwrap();
+        assert!(restored.achievements.contains(&Achievement::FirstJob));
+    }
+
+    #[test]
+    fn test_savings_milestone_sticks_after_spending() {
+        let mut state =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 25, 2024).unwrap();
+        state.finances.cash = dec!(99999);
+        settle(&mut state);
+        assert!(!state
+            .achievements
+            .contains(&Achievement::FirstHundredKSaved));
+
+        state.finances.cash = dec!(100000);
+        settle(&mut state);
+        assert!(state
+            .achievements
+            .contains(&Achievement::FirstHundredKSaved));
+
+        state.finances.cash = Decimal::ZERO;
+        settle(&mut state);
+        assert!(state
+            .achievements
+            .contains(&Achievement::FirstHundredKSaved));
+    }
+
+    #[cfg(feature = "usa")]
+    #[test]
+    fn test_savings_milestone_follows_market() {
+        let usa = crate::markets::usa::UsaMarket;
+        let mut state =
+            GameState::new("save1".to_string(), "usa".to_string(), None, 25, 2024).unwrap();
+        let milestone = usa.scale_czk(SAVINGS_MILESTONE);
+
+        state.finances.cash = milestone - dec!(1);
+        assert!(!Achievement::FirstHundredKSaved.is_reached(&state, &usa));
+        state.finances.cash = milestone;
+        assert!(Achievement::FirstHundredKSaved.is_reached(&state, &usa));
+    }
+
+    #[test]
+    fn test_recession_must_end_before_it_counts() {
+        let mut state =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 25, 2024).unwrap();
+        state.economy.phase = EconomyPhase::Expansion;
+
+        // A crash brings a recovery, but there was no recession to survive
+        state.apply_event(Event::MarketCrash {
+            drop: MARKET_CRASH_DROP,
+        });
+        assert_eq!(state.economy.phase, EconomyPhase::Recovery);
+        assert!(!Achievement::SurvivedRecession.is_reached(&state, &CzechMarket));
+
+        state.economy.enter_phase(EconomyPhase::Recession);
+        assert!(!Achievement::SurvivedRecession.is_reached(&state, &CzechMarket));
+        state.economy.enter_phase(EconomyPhase::Recovery);
+        assert!(Achievement::SurvivedRecession.is_reached(&state, &CzechMarket));
+    }
+
+    #[test]
+    fn test_maxed_retirement_account() {
+        let mut state =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 25, 2024).unwrap();
+        let mut dip = Account::new(
+            "dip".to_string(),
+            "DIP".to_string(),
+            AccountKind::Retirement {
+                account_type_id: "dip".to_string(),
+            },
+        );
+        dip.deposit(dec!(47999)).unwrap();
+        state.finances.add_account(dip);
+        assert!(!Achievement::MaxedRetirementYear.is_reached(&state, &CzechMarket));
+
+        state.finances.accounts[0].deposit(dec!(1)).unwrap();
+        assert!(Achievement::MaxedRetirementYear.is_reached(&state, &CzechMarket));
+    }
+}
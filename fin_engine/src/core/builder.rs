@@ -0,0 +1,270 @@
+//! Builder for setting up a freshly started game
+
+use super::career::Job;
+use super::difficulty::Difficulty;
+use super::expenses::ExpenseCategory;
+use super::game_state::GameState;
+use super::player::Gender;
+use crate::markets::market_profile;
+use rust_decimal::Decimal;
+
+/// Market a new game is started in unless set otherwise
+pub const DEFAULT_MARKET_ID: &str = "czech";
+
+/// Builds a started game: the player, their first job and opening finances
+#[derive(Debug, Clone)]
+pub struct GameStateBuilder {
+    save_id: String,
+    market_id: String,
+    player_name: Option<String>,
+    player_age: u8,
+    gender: Option<Gender>,
+    start_year: u32,
+    difficulty: Difficulty,
+    starting_job: Option<Job>,
+    starting_cash: Option<Decimal>,
+    budgets: Vec<(ExpenseCategory, Decimal)>,
+}
+
+impl GameStateBuilder {
+    /// Creates a builder for a new game in the default market
+    pub fn new(
+        save_id: String,
+        player_name: Option<String>,
+        player_age: u8,
+        start_year: u32,
+    ) -> Self {
+        GameStateBuilder {
+            save_id,
+            market_id: DEFAULT_MARKET_ID.to_string(),
+            player_name,
+            player_age,
+            gender: None,
+            start_year,
+            difficulty: Difficulty::default(),
+            starting_job: None,
+            starting_cash: None,
+            budgets: Vec::new(),
+        }
+    }
+
+    /// Sets the market the game is played in
+    pub fn with_market(mut self, market_id: impl Into<String>) -> Self {
+        self.market_id = market_id.into();
+        self
+    }
+
+    /// Sets the player's gender, for markets whose pension rules depend on it
+    pub fn with_gender(mut self, gender: Gender) -> Self {
+        self.gender = Some(gender);
+        self
+    }
+
+    /// Sets the difficulty, which also decides the default starting cash
+    pub fn with_difficulty(mut self, difficulty: Difficulty) -> Self {
+        self.difficulty = difficulty;
+        self
+    }
+
+    /// Starts the player in the given job
+    pub fn with_starting_job(mut self, job: Job) -> Self {
+        self.starting_job = Some(job);
+        self
+    }
+
+    /// Overrides the cash the player starts with, with or without a job
+    /// Without it the player gets the difficulty's share of the starting salary.
+    pub fn with_starting_cash(mut self, cash: Decimal) -> Self {
+        self.starting_cash = Some(cash);
+        self
+    }
+
+    /// Sets a monthly budget, replacing the survival minimum for Essential
+    pub fn with_budget(mut self, category: ExpenseCategory, amount: Decimal) -> Self {
+        self.budgets.push((category, amount));
+        self
+    }
+
+    /// Creates the game state
+    pub fn build(self) -> Result<GameState, String> {
+        let Some(market) = market_profile(&self.market_id) else {
+            return Err(format!("Unknown market: {}", self.market_id));
+        };
+        if self.starting_cash.is_some_and(|cash| cash < Decimal::ZERO) {
+            return Err("Starting cash cannot be negative".to_string());
+        }
+        if let Some(job) = &self.starting_job {
+            market.check_minimum_wage(job)?;
+        }
+        let mut state = GameState::new(
+            self.save_id,
+            self.market_id,
+            self.player_name,
+            self.player_age,
+            self.start_year,
+        )?;
+        state.difficulty = self.difficulty;
+        state.player.gender = self.gender;
+        state.economy.base_interest_rate = market.base_interest_rate();
+
+        if let Some(job) = self.starting_job {
+            state.accept_job(job, true, market.as_ref());
+        }
+
+        if let Some(cash) = self.starting_cash {
+            state.finances.cash = cash;
+        }
+        for (category, amount) in self.budgets {
+            state.finances.set_budget(category, amount);
+        }
+
+        Ok(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::career::{CareerField, JobLevel};
+    use crate::core::income::{Income, IncomeKind};
+    use crate::markets::czech::CzechMarket;
+    use crate::MarketProfile;
+    use rust_decimal_macros::dec;
+
+    fn junior_job() -> Job {
+        Job::new(
+            "dev1".to_string(),
+            "Junior Developer".to_string(),
+            CareerField::Technology,
+            JobLevel::Junior,
+            dec!(40000),
+            None,
+        )
+    }
+
+    #[test]
+    fn test_builder_matches_manual_setup() {
+        let market = CzechMarket::new();
+        let job = junior_job();
+
+        let mut manual = GameState::new(
+            "save".to_string(),
+            "czech".to_string(),
+            Some("Ana".to_string()),
+            25,
+            2024,
+        )
+        .unwrap();
+        manual.difficulty = Difficulty::Hard;
+        manual.finances.cash = manual.difficulty.starting_cash(job.monthly_salary);
+        manual
+            .finances
+            .set_budget(ExpenseCategory::Essential, market.essential_minimum());
+        manual.career.accept_job(job.clone());
+        manual.update_commute_expense(&market);
+        manual.finances.income_sources.push(Income::new(
+            format!("job_{}", job.id),
+            job.title.clone(),
+            IncomeKind::Employment,
+            job.monthly_salary,
+        ));
+
+        let built = GameStateBuilder::new("save".to_string(), Some("Ana".to_string()), 25, 2024)
+            .with_market("czech")
+            .with_difficulty(Difficulty::Hard)
+            .with_starting_job(job)
+            .build()
+            .unwrap();
+
+        assert_eq!(built, manual);
+    }
+
+    #[test]
+    fn test_builder_overrides() {
+        let built = GameStateBuilder::new("save".to_string(), None, 25, 2024)
+            .with_starting_job(junior_job())
+            .with_starting_cash(dec!(100000))
+            .with_budget(ExpenseCategory::Essential, dec!(8000))
+            .build()
+            .unwrap();
+        assert_eq!(built.finances.cash, dec!(100000));
+        assert_eq!(
+            built.finances.budget[&ExpenseCategory::Essential].allocated,
+            dec!(8000)
+        );
+
+        let unemployed = GameStateBuilder::new("save".to_string(), None, 25, 2024)
+            .build()
+            .unwrap();
+        assert!(unemployed.career.current_job.is_none());
+        assert_eq!(unemployed.finances.cash, Decimal::ZERO);
+        assert!(GameStateBuilder::new("save".to_string(), None, 25, 2024)
+            .with_market("atlantis")
+            .build()
+            .is_err());
+    }
+
+    #[cfg(feature = "usa")]
+    #[test]
+    fn test_economy_starts_at_market_base_rate() {
+        let usa = crate::markets::usa::UsaMarket;
+        let built = GameStateBuilder::new("save".to_string(), None, 25, 2024)
+            .with_market("usa")
+            .build()
+            .unwrap();
+        assert_eq!(built.economy.base_interest_rate, usa.base_interest_rate());
+        assert_ne!(
+            built.economy.base_interest_rate,
+            CzechMarket.base_interest_rate()
+        );
+    }
+
+    #[test]
+    fn test_starting_savings_without_job() {
+        let built = GameStateBuilder::new("save".to_string(), None, 25, 2024)
+            .with_starting_cash(dec!(150000))
+            .build()
+            .unwrap();
+        assert!(built.career.current_job.is_none());
+        assert_eq!(built.finances.cash, dec!(150000));
+        assert_eq!(built.finances.net_worth(), dec!(150000));
+
+        // Starting from zero with a job is allowed too
+        let broke = GameStateBuilder::new("save".to_string(), None, 25, 2024)
+            .with_starting_job(junior_job())
+            .with_starting_cash(Decimal::ZERO)
+            .build()
+            .unwrap();
+        assert_eq!(broke.finances.net_worth(), Decimal::ZERO);
+
+        assert!(GameStateBuilder::new("save".to_string(), None, 25, 2024)
+            .with_starting_cash(dec!(-1))
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn test_custom_starting_job_must_pay_minimum_wage() {
+        let mut job = junior_job();
+        job.monthly_salary = dec!(10000);
+        let err = GameStateBuilder::new("save".to_string(), None, 25, 2024)
+            .with_starting_job(job)
+            .build()
+            .unwrap_err();
+        assert!(err.contains("below the minimum wage"));
+    }
+
+    #[test]
+    fn test_first_job_grant_keeps_starting_savings() {
+        let mut state = GameStateBuilder::new("save".to_string(), None, 25, 2024)
+            .with_starting_cash(dec!(150000))
+            .build()
+            .unwrap();
+        assert!(state.is_first_job());
+
+        let job = junior_job();
+        let grant = state.difficulty.starting_cash(job.monthly_salary);
+        state.accept_job(job, true, &CzechMarket);
+        assert_eq!(state.finances.cash, dec!(150000) + grant);
+    }
+}
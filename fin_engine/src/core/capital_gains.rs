@@ -0,0 +1,52 @@
+//! Realized capital gains, settled once per tax year
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// What was sold, since markets exempt or tax them differently
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GainKind {
+    /// Shares, funds and other investments
+    Securities,
+    /// Real estate
+    Property,
+}
+
+/// A gain or loss realized by a sale during the current tax year
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RealizedGain {
+    /// What was sold (account or asset name)
+    pub description: String,
+    /// Type of holding sold
+    pub kind: GainKind,
+    /// Gross sale proceeds
+    pub proceeds: Decimal,
+    /// Gain (negative for a loss)
+    pub gain: Decimal,
+    /// Game months the holding was owned
+    #[serde(default)]
+    pub holding_months: u32,
+    /// Tax already withheld at the time of sale
+    pub tax_withheld: Decimal,
+}
+
+impl RealizedGain {
+    /// Records a sale
+    pub fn new(
+        description: String,
+        kind: GainKind,
+        proceeds: Decimal,
+        gain: Decimal,
+        holding_months: u32,
+        tax_withheld: Decimal,
+    ) -> Self {
+        RealizedGain {
+            description,
+            kind,
+            proceeds,
+            gain,
+            holding_months,
+            tax_withheld,
+        }
+    }
+}
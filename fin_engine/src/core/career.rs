@@ -1,7 +1,23 @@
 //! Career and job system
 
+use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Share of experience from other fields that counts toward a new field
+/// (e.g. 0.5 means 4 years in Retail count as 2 years in Technology)
+pub const CROSS_FIELD_EXPERIENCE_CREDIT: Decimal = dec!(0.5);
+
+/// Skill points that count as one year of experience when qualifying for jobs
+pub const SKILL_POINTS_PER_YEAR: u8 = 30;
+
+/// Longest career break the player can take at once
+pub const MAX_SABBATICAL_MONTHS: u8 = 12;
+
+/// Share of full-time hours worked in a standard part-time position
+pub const PART_TIME_HOURS: Decimal = dec!(0.5);
 
 /// Job level/seniority
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -41,6 +57,18 @@ impl JobLevel {
         }
     }
 
+    /// Returns monthly burnout from working at this level
+    /// More senior roles carry more responsibility and longer hours
+    pub fn monthly_burnout(&self) -> u8 {
+        match self {
+            JobLevel::Entry => 3,
+            JobLevel::Junior => 4,
+            JobLevel::Mid => 6,
+            JobLevel::Senior => 9,
+            JobLevel::Lead => 12,
+        }
+    }
+
     /// Returns all job levels in order
     pub fn all() -> Vec<JobLevel> {
         vec![
@@ -79,6 +107,29 @@ impl CareerField {
         }
     }
 
+    /// Returns the field's typical burnout multiplier (1.0 = the level's usual stress)
+    pub fn burnout_rate(&self) -> Decimal {
+        match self {
+            CareerField::Technology => dec!(1.0),
+            CareerField::Finance => dec!(1.2),
+            CareerField::Healthcare => dec!(1.4),
+            CareerField::Education => dec!(1.1),
+            CareerField::Retail => dec!(1.1),
+            CareerField::Manufacturing => dec!(1.2),
+            CareerField::Other(_) => dec!(1.0),
+        }
+    }
+
+    /// Returns the field's typical monthly happiness from the work itself
+    pub fn happiness_impact(&self) -> i8 {
+        match self {
+            CareerField::Education => 2,
+            CareerField::Healthcare => 1,
+            CareerField::Retail => -1,
+            _ => 0,
+        }
+    }
+
     /// Returns available career fields
     pub fn available_fields() -> Vec<CareerField> {
         vec![
@@ -109,10 +160,30 @@ pub struct Job {
     pub required_experience: u8,
     /// Company name (optional)
     pub company: Option<String>,
+    /// Share of full-time hours (1.0 = full-time, 0.5 = half-time)
+    #[serde(default = "full_time")]
+    pub hours_fraction: Decimal,
+    /// Year-end bonus in months of salary, paid with December's pay (e.g. 1.0 = 13th salary)
+    #[serde(default)]
+    pub annual_bonus_months: Decimal,
+    /// Multiplier on the level's monthly burnout (1.0 = typical)
+    #[serde(default = "typical_burnout")]
+    pub burnout_rate: Decimal,
+    /// Happiness gained (or lost) each month from the work itself
+    #[serde(default)]
+    pub happiness_impact: i8,
+}
+
+fn full_time() -> Decimal {
+    Decimal::ONE
+}
+
+fn typical_burnout() -> Decimal {
+    Decimal::ONE
 }
 
 impl Job {
-    /// Creates a new job
+    /// Creates a new job with its field's typical work-life profile
     pub fn new(
         id: String,
         title: String,
@@ -124,11 +195,79 @@ impl Job {
         Job {
             id,
             title,
+            burnout_rate: field.burnout_rate(),
+            happiness_impact: field.happiness_impact(),
             field,
             required_experience: level.min_experience(),
             level,
             monthly_salary,
             company,
+            hours_fraction: full_time(),
+            annual_bonus_months: Decimal::ZERO,
+        }
+    }
+
+    /// Overrides the field's typical burnout multiplier and monthly happiness
+    pub fn with_work_life(mut self, burnout_rate: Decimal, happiness_impact: i8) -> Self {
+        self.burnout_rate = burnout_rate.max(Decimal::ZERO);
+        self.happiness_impact = happiness_impact;
+        self
+    }
+
+    /// Overrides the level's minimum years of experience
+    pub fn with_required_experience(mut self, years: u8) -> Self {
+        self.required_experience = years;
+        self
+    }
+
+    /// Adds a year-end bonus worth the given number of monthly salaries
+    pub fn with_annual_bonus(mut self, months: Decimal) -> Self {
+        self.annual_bonus_months = months;
+        self
+    }
+
+    /// Returns the gross year-end bonus
+    pub fn annual_bonus(&self) -> Decimal {
+        (self.monthly_salary * self.annual_bonus_months).round_dp(0)
+    }
+
+    /// Turns a full-time position into a part-time one
+    /// Salary is prorated by hours; the job gets its own ID so both versions
+    /// can be offered side by side.
+    pub fn part_time(mut self, hours_fraction: Decimal) -> Result<Job, String> {
+        if hours_fraction <= Decimal::ZERO || hours_fraction >= Decimal::ONE {
+            return Err("Part-time hours must be between 0 and 1".to_string());
+        }
+        if self.is_part_time() {
+            return Err(format!("{} is already part-time", self.title));
+        }
+        self.id = format!("{}_part_time", self.id);
+        self.monthly_salary = (self.monthly_salary * hours_fraction).round_dp(0);
+        self.hours_fraction = hours_fraction;
+        Ok(self)
+    }
+
+    /// Returns true if the job is less than full-time
+    pub fn is_part_time(&self) -> bool {
+        self.hours_fraction < Decimal::ONE
+    }
+
+    /// Returns monthly burnout from working this job, scaled by its burnout
+    /// rate and prorated by hours
+    pub fn monthly_burnout(&self) -> u8 {
+        (Decimal::from(self.level.monthly_burnout()) * self.burnout_rate * self.hours_fraction)
+            .round()
+            .to_u8()
+            .unwrap_or(0)
+    }
+
+    /// Describes the monthly burnout net of the job's happiness
+    pub fn work_life_balance(&self) -> &'static str {
+        match self.monthly_burnout() as i16 - self.happiness_impact as i16 {
+            i16::MIN..=3 => "Relaxed",
+            4..=7 => "Balanced",
+            8..=11 => "Demanding",
+            _ => "Intense",
         }
     }
 
@@ -150,10 +289,25 @@ pub struct Career {
     pub current_job: Option<Job>,
     /// Total years of work experience
     pub years_experience: u8,
+    /// Years of work experience per career field
+    #[serde(default)]
+    pub field_experience: HashMap<CareerField, u8>,
+    /// Months worked toward the next year of total experience
+    #[serde(default)]
+    pub experience_months: u8,
+    /// Months worked toward the next year of experience, per career field
+    #[serde(default)]
+    pub field_experience_months: HashMap<CareerField, u8>,
     /// Months in current job
-    pub months_in_current_job: u8,
+    pub months_in_current_job: u32,
     /// Previous jobs (job history)
     pub job_history: Vec<Job>,
+    /// Job IDs that turned the player down this month (can't reapply until next month)
+    #[serde(default)]
+    pub rejected_applications: Vec<String>,
+    /// Months left of a voluntary career break (0 when not on sabbatical)
+    #[serde(default)]
+    pub sabbatical_months_left: u8,
 }
 
 impl Career {
@@ -162,8 +316,13 @@ impl Career {
         Career {
             current_job: None,
             years_experience: 0,
+            field_experience: HashMap::new(),
+            experience_months: 0,
+            field_experience_months: HashMap::new(),
             months_in_current_job: 0,
             job_history: Vec::new(),
+            rejected_applications: Vec::new(),
+            sabbatical_months_left: 0,
         }
     }
 
@@ -176,6 +335,7 @@ impl Career {
 
         self.current_job = Some(job);
         self.months_in_current_job = 0;
+        self.sabbatical_months_left = 0;
     }
 
     /// Quits the current job
@@ -186,18 +346,71 @@ impl Career {
         self.months_in_current_job = 0;
     }
 
+    /// Leaves the current job for a career break of the given length
+    /// Unlike being laid off, the break is planned; skills slowly rust while
+    /// away, so progress toward the next year of experience decays.
+    pub fn start_sabbatical(&mut self, months: u8) -> Result<(), String> {
+        if self.current_job.is_none() {
+            return Err("You need a job to take a sabbatical from".to_string());
+        }
+        if months == 0 || months > MAX_SABBATICAL_MONTHS {
+            return Err(format!(
+                "A sabbatical must last between 1 and {} months",
+                MAX_SABBATICAL_MONTHS
+            ));
+        }
+        self.quit_job();
+        self.sabbatical_months_left = months;
+        Ok(())
+    }
+
+    /// Returns true while on a career break
+    pub fn is_on_sabbatical(&self) -> bool {
+        self.sabbatical_months_left > 0
+    }
+
     /// Advances career by one month (call at end of month)
     pub fn advance_month(&mut self) {
-        if self.current_job.is_some() {
-            self.months_in_current_job += 1;
+        self.rejected_applications.clear();
+
+        if self.is_on_sabbatical() {
+            self.sabbatical_months_left -= 1;
+            self.experience_months = self.experience_months.saturating_sub(1);
+            return;
+        }
+
+        if let Some(job) = &self.current_job {
+            self.months_in_current_job = self.months_in_current_job.saturating_add(1);
+
+            // Every 12 months worked, gain 1 year of experience. Months carry
+            // over across job changes so partial tenure is never lost.
+            self.experience_months += 1;
+            if self.experience_months >= 12 {
+                self.experience_months = 0;
+                self.years_experience = self.years_experience.saturating_add(1);
+            }
 
-            // Every 12 months, gain 1 year of experience
-            if self.months_in_current_job % 12 == 0 {
-                self.years_experience += 1;
+            let field_months = self
+                .field_experience_months
+                .entry(job.field.clone())
+                .or_insert(0);
+            *field_months += 1;
+            if *field_months >= 12 {
+                *field_months = 0;
+                let years = self.field_experience.entry(job.field.clone()).or_insert(0);
+                *years = years.saturating_add(1);
             }
         }
     }
 
+    /// Returns the time in the current job as whole years and remaining months
+    pub fn tenure(&self) -> (u32, u32) {
+        (
+            self.months_in_current_job / 12,
+            self.months_in_current_job % 12,
+        )
+    }
+
     /// Returns true if currently employed
     pub fn is_employed(&self) -> bool {
         self.current_job.is_some()
@@ -211,10 +424,110 @@ impl Career {
             .unwrap_or(Decimal::ZERO)
     }
 
-    /// Returns the highest job level the player qualifies for
-    pub fn max_qualified_level(&self) -> JobLevel {
+    /// Returns the average monthly salary across current and past jobs
+    pub fn average_monthly_salary(&self) -> Decimal {
+        let salaries: Vec<Decimal> = self
+            .job_history
+            .iter()
+            .chain(self.current_job.iter())
+            .map(|j| j.monthly_salary)
+            .collect();
+        if salaries.is_empty() {
+            return Decimal::ZERO;
+        }
+        salaries.iter().sum::<Decimal>() / Decimal::from(salaries.len())
+    }
+
+    /// Returns years of experience that count toward jobs in the given field
+    ///
+    /// Experience in the field counts fully, experience in other fields only
+    /// partially (see `CROSS_FIELD_EXPERIENCE_CREDIT`). Experience not tracked
+    /// per field (e.g. from older saves) counts fully everywhere.
+    pub fn effective_experience(&self, field: &CareerField) -> u8 {
+        let tracked: u8 = self.field_experience.values().sum();
+        let untracked = self.years_experience.saturating_sub(tracked);
+        let in_field = self.field_experience.get(field).copied().unwrap_or(0);
+        let other_fields = tracked - in_field;
+
+        let transferred = (Decimal::from(other_fields) * CROSS_FIELD_EXPERIENCE_CREDIT)
+            .floor()
+            .to_u8()
+            .unwrap_or(0);
+
+        in_field
+            .saturating_add(untracked)
+            .saturating_add(transferred)
+    }
+
+    /// Returns years that count toward qualifying for jobs in the given field:
+    /// effective experience plus a year for every `SKILL_POINTS_PER_YEAR` of skill
+    pub fn qualifying_experience(
+        &self,
+        field: &CareerField,
+        skills: &HashMap<CareerField, u8>,
+    ) -> u8 {
+        let skill = skills.get(field).copied().unwrap_or(0);
+        self.effective_experience(field)
+            .saturating_add(skill / SKILL_POINTS_PER_YEAR)
+    }
+
+    /// Checks if the player qualifies for the job given their experience and skill in its field
+    pub fn qualifies_for(&self, job: &Job, skills: &HashMap<CareerField, u8>) -> bool {
+        job.qualifies(self.qualifying_experience(&job.field, skills))
+    }
+
+    /// Returns the highest job level the player qualifies for in the given field
+    pub fn max_qualified_level_in(
+        &self,
+        field: &CareerField,
+        skills: &HashMap<CareerField, u8>,
+    ) -> JobLevel {
+        Self::level_for_experience(self.qualifying_experience(field, skills))
+    }
+
+    /// Returns how many levels the job sits above what the player qualifies for
+    /// in its field (0 for jobs the player is qualified for)
+    pub fn levels_above_qualification(&self, job: &Job, skills: &HashMap<CareerField, u8>) -> u8 {
+        (job.level as u8).saturating_sub(self.max_qualified_level_in(&job.field, skills) as u8)
+    }
+
+    /// Returns how far the player is from their current level toward the next
+    /// one in the given field (0.0 = just reached it, close to 1.0 = almost there)
+    pub fn progress_to_next_level(
+        &self,
+        field: &CareerField,
+        skills: &HashMap<CareerField, u8>,
+    ) -> Decimal {
+        let experience = self.qualifying_experience(field, skills);
+        let current = self.max_qualified_level_in(field, skills);
+        let Some(next) = JobLevel::all()
+            .into_iter()
+            .find(|l| *l as u8 == current as u8 + 1)
+        else {
+            return Decimal::ONE;
+        };
+        let span = next.min_experience() - current.min_experience();
+        Decimal::from(experience - current.min_experience()) / Decimal::from(span)
+    }
+
+    /// Returns the qualifying experience in the player's strongest field
+    pub fn best_qualifying_experience(&self, skills: &HashMap<CareerField, u8>) -> u8 {
+        self.field_experience
+            .keys()
+            .chain(skills.keys())
+            .map(|field| self.qualifying_experience(field, skills))
+            .max()
+            .unwrap_or(self.years_experience)
+    }
+
+    /// Returns the highest job level the player qualifies for in any field
+    pub fn max_qualified_level(&self, skills: &HashMap<CareerField, u8>) -> JobLevel {
+        Self::level_for_experience(self.best_qualifying_experience(skills))
+    }
+
+    fn level_for_experience(years: u8) -> JobLevel {
         for level in JobLevel::all().iter().rev() {
-            if self.years_experience >= level.min_experience() {
+            if years >= level.min_experience() {
                 return *level;
             }
         }
@@ -231,7 +544,6 @@ impl Default for Career {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use rust_decimal_macros::dec;
 
     #[test]
     fn test_job_level_requirements() {
@@ -286,6 +598,30 @@ mod tests {
         assert_eq!(career.months_in_current_job, 12);
     }
 
+    #[test]
+    fn test_long_tenure_does_not_overflow() {
+        let mut career = Career::new();
+        career.accept_job(Job::new(
+            "job1".to_string(),
+            "Teacher".to_string(),
+            CareerField::Education,
+            JobLevel::Mid,
+            dec!(40000),
+            None,
+        ));
+
+        for _ in 0..300 {
+            career.advance_month();
+        }
+
+        // A u8 counter would have stopped at 255 and skipped later raises
+        assert_eq!(career.months_in_current_job, 300);
+        assert_eq!(career.tenure(), (25, 0));
+        assert_eq!(career.years_experience, 25);
+        assert_eq!(career.field_experience[&CareerField::Education], 25);
+        assert_eq!(career.experience_months, 0);
+    }
+
     #[test]
     fn test_job_switching() {
         let mut career = Career::new();
@@ -319,15 +655,242 @@ mod tests {
     #[test]
     fn test_max_qualified_level() {
         let mut career = Career::new();
-        assert_eq!(career.max_qualified_level(), JobLevel::Entry);
+        assert_eq!(career.max_qualified_level(&HashMap::new()), JobLevel::Entry);
 
         career.years_experience = 3;
-        assert_eq!(career.max_qualified_level(), JobLevel::Junior);
+        assert_eq!(
+            career.max_qualified_level(&HashMap::new()),
+            JobLevel::Junior
+        );
 
         career.years_experience = 8;
-        assert_eq!(career.max_qualified_level(), JobLevel::Senior);
+        assert_eq!(
+            career.max_qualified_level(&HashMap::new()),
+            JobLevel::Senior
+        );
 
         career.years_experience = 15;
-        assert_eq!(career.max_qualified_level(), JobLevel::Lead);
+        assert_eq!(career.max_qualified_level(&HashMap::new()), JobLevel::Lead);
+    }
+
+    #[test]
+    fn test_partial_tenure_carries_over() {
+        let mut career = Career::new();
+        let stint = |id: &str| {
+            Job::new(
+                id.to_string(),
+                "Developer".to_string(),
+                CareerField::Technology,
+                JobLevel::Entry,
+                dec!(40000),
+                None,
+            )
+        };
+
+        career.accept_job(stint("first"));
+        for _ in 0..11 {
+            career.advance_month();
+        }
+        assert_eq!(career.years_experience, 0);
+
+        career.accept_job(stint("second"));
+        for _ in 0..11 {
+            career.advance_month();
+        }
+
+        // 22 months worked across two jobs grant a year with 10 months left over
+        assert_eq!(career.years_experience, 1);
+        assert_eq!(career.experience_months, 10);
+        assert_eq!(career.field_experience[&CareerField::Technology], 1);
+
+        // Months while unemployed don't count
+        career.quit_job();
+        for _ in 0..6 {
+            career.advance_month();
+        }
+        assert_eq!(career.experience_months, 10);
+    }
+
+    #[test]
+    fn test_field_switch_resets_qualification() {
+        let mut career = Career::new();
+        career.accept_job(Job::new(
+            "tech".to_string(),
+            "Developer".to_string(),
+            CareerField::Technology,
+            JobLevel::Entry,
+            dec!(40000),
+            None,
+        ));
+        for _ in 0..(8 * 12) {
+            career.advance_month();
+        }
+        assert_eq!(career.effective_experience(&CareerField::Technology), 8);
+        assert_eq!(
+            career.max_qualified_level_in(&CareerField::Technology, &HashMap::new()),
+            JobLevel::Senior
+        );
+
+        // Switching to retail only carries half the seniority
+        career.accept_job(Job::new(
+            "retail".to_string(),
+            "Store Manager".to_string(),
+            CareerField::Retail,
+            JobLevel::Mid,
+            dec!(35000),
+            None,
+        ));
+        assert_eq!(career.effective_experience(&CareerField::Retail), 4);
+        assert_eq!(
+            career.max_qualified_level_in(&CareerField::Retail, &HashMap::new()),
+            JobLevel::Mid
+        );
+
+        let senior_retail = Job::new(
+            "retail_senior".to_string(),
+            "Regional Manager".to_string(),
+            CareerField::Retail,
+            JobLevel::Senior,
+            dec!(55000),
+            None,
+        );
+        assert!(!career.qualifies_for(&senior_retail, &HashMap::new()));
+
+        // Technology experience is retained
+        for _ in 0..12 {
+            career.advance_month();
+        }
+        assert_eq!(career.field_experience[&CareerField::Technology], 8);
+        assert_eq!(career.effective_experience(&CareerField::Retail), 5);
+        assert_eq!(career.effective_experience(&CareerField::Technology), 8);
+        assert_eq!(
+            career.max_qualified_level(&HashMap::new()),
+            JobLevel::Senior
+        );
+    }
+
+    #[test]
+    fn test_levels_above_qualification() {
+        let mut career = Career::new();
+        career.years_experience = 3; // Junior, halfway to Mid
+
+        let job = |level| {
+            Job::new(
+                "job".to_string(),
+                "Job".to_string(),
+                CareerField::Retail,
+                level,
+                dec!(30000),
+                None,
+            )
+        };
+        assert_eq!(
+            career.levels_above_qualification(&job(JobLevel::Entry), &HashMap::new()),
+            0
+        );
+        assert_eq!(
+            career.levels_above_qualification(&job(JobLevel::Junior), &HashMap::new()),
+            0
+        );
+        assert_eq!(
+            career.levels_above_qualification(&job(JobLevel::Mid), &HashMap::new()),
+            1
+        );
+        assert_eq!(
+            career.levels_above_qualification(&job(JobLevel::Senior), &HashMap::new()),
+            2
+        );
+        assert_eq!(
+            career.progress_to_next_level(&CareerField::Retail, &HashMap::new()),
+            dec!(0.5)
+        );
+
+        career.years_experience = 12;
+        assert_eq!(
+            career.progress_to_next_level(&CareerField::Retail, &HashMap::new()),
+            Decimal::ONE
+        );
+    }
+
+    #[test]
+    fn test_skill_supplements_experience() {
+        let mut career = Career::new();
+        career.years_experience = 2; // Junior on experience alone
+        let mut skills = HashMap::new();
+        skills.insert(CareerField::Technology, 60);
+
+        assert_eq!(
+            career.max_qualified_level_in(&CareerField::Technology, &HashMap::new()),
+            JobLevel::Junior
+        );
+        assert_eq!(
+            career.qualifying_experience(&CareerField::Technology, &skills),
+            4
+        );
+        assert_eq!(
+            career.max_qualified_level_in(&CareerField::Technology, &skills),
+            JobLevel::Mid
+        );
+        // Skill only counts in its own field
+        assert_eq!(
+            career.max_qualified_level_in(&CareerField::Retail, &skills),
+            JobLevel::Junior
+        );
+        assert_eq!(career.max_qualified_level(&skills), JobLevel::Mid);
+    }
+
+    #[test]
+    fn test_part_time_job() {
+        let full_time = Job::new(
+            "dev".to_string(),
+            "Developer".to_string(),
+            CareerField::Technology,
+            JobLevel::Senior,
+            dec!(80000),
+            None,
+        );
+        let part_time = full_time.clone().part_time(PART_TIME_HOURS).unwrap();
+
+        assert!(part_time.is_part_time());
+        assert!(!full_time.is_part_time());
+        assert_eq!(part_time.monthly_salary, dec!(40000));
+        assert_ne!(part_time.id, full_time.id);
+        assert!(part_time.monthly_burnout() < full_time.monthly_burnout());
+
+        assert!(part_time.clone().part_time(dec!(0.5)).is_err());
+        assert!(full_time.clone().part_time(Decimal::ONE).is_err());
+        assert!(full_time.part_time(Decimal::ZERO).is_err());
+    }
+
+    #[test]
+    fn test_sabbatical_pauses_experience() {
+        let mut career = Career::new();
+        assert!(career.start_sabbatical(3).is_err());
+
+        career.accept_job(Job::new(
+            "dev".to_string(),
+            "Developer".to_string(),
+            CareerField::Technology,
+            JobLevel::Junior,
+            dec!(40000),
+            None,
+        ));
+        for _ in 0..6 {
+            career.advance_month();
+        }
+        assert!(career.start_sabbatical(0).is_err());
+        assert!(career.start_sabbatical(MAX_SABBATICAL_MONTHS + 1).is_err());
+        career.start_sabbatical(3).unwrap();
+        assert!(!career.is_employed());
+        assert!(career.is_on_sabbatical());
+        assert_eq!(career.job_history.len(), 1);
+
+        for _ in 0..3 {
+            career.advance_month();
+        }
+        assert!(!career.is_on_sabbatical());
+        assert_eq!(career.years_experience, 0);
+        // Three months of progress toward the next year have rusted away
+        assert_eq!(career.experience_months, 3);
     }
 }
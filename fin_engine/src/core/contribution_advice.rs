@@ -0,0 +1,238 @@
+//! Which accounts to save into first
+
+use super::accounts::{Account, AccountKind};
+use super::financial_state::FinancialState;
+use super::income::IncomeKind;
+use super::player::PlayerStats;
+use crate::market::MarketProfile;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Why an account is suggested
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SuggestionReason {
+    /// The employer adds to what the player puts in
+    EmployerMatch,
+    /// Contributions lower the income tax base
+    TaxDeduction,
+    /// Whatever is left once the tax-advantaged room is used
+    Taxable,
+}
+
+impl SuggestionReason {
+    /// Returns display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            SuggestionReason::EmployerMatch => "Employer match",
+            SuggestionReason::TaxDeduction => "Tax deduction",
+            SuggestionReason::Taxable => "Taxable account",
+        }
+    }
+}
+
+/// How much to put into one account this year
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Suggestion {
+    /// Market account type to open or top up (None for a taxable account)
+    pub account_type_id: Option<String>,
+    /// Display name of the account
+    pub account_name: String,
+    /// Why it comes at this point in the order
+    pub reason: SuggestionReason,
+    /// Amount to contribute for the rest of the year
+    pub annual_amount: Decimal,
+    /// Income tax saved by the contribution
+    pub expected_tax_savings: Decimal,
+}
+
+impl Suggestion {
+    /// Amount to contribute each month
+    pub fn monthly_amount(&self) -> Decimal {
+        (self.annual_amount / Decimal::from(12)).round_dp(2)
+    }
+}
+
+/// Whether the account is a retirement account of the given market type
+fn is_of_type(account: &Account, type_id: &str) -> bool {
+    matches!(&account.kind, AccountKind::Retirement { account_type_id } if account_type_id == type_id)
+}
+
+/// Splits a year of surplus between the market's tax-advantaged accounts and a taxable one
+///
+/// Employer-matched accounts come first while the player is employed, then
+/// accounts in order of the income tax they save, each up to its remaining
+/// annual limit. Accounts that would neither be matched nor save tax are
+/// skipped, as are retirement accounts once the player reaches retirement age.
+/// Whatever surplus is left goes to a taxable account.
+pub fn suggest_contributions(
+    finances: &FinancialState,
+    market: &dyn MarketProfile,
+    player: &PlayerStats,
+) -> Result<Vec<Suggestion>, String> {
+    let gross = finances.monthly_gross_income();
+    let taxes = if gross > Decimal::ZERO {
+        finances.monthly_taxes(market)?.total
+    } else {
+        Decimal::ZERO
+    };
+    let mut budget =
+        ((gross - taxes - finances.monthly_expenses()) * Decimal::from(12)).max(Decimal::ZERO);
+    let annual_employment = finances.monthly_employment_gross() * Decimal::from(12);
+    let employed = finances
+        .income_sources
+        .iter()
+        .any(|i| i.active && matches!(i.kind, IncomeKind::Employment));
+
+    let mut accounts = finances.accounts.clone();
+    let tax_with = |accounts: &[Account]| -> Result<Decimal, String> {
+        let deductions = market.tax_deductible_contributions(accounts);
+        Ok(market
+            .calculate_income_tax_with_deductions(annual_employment, deductions)?
+            .total)
+    };
+    let contribute = |accounts: &mut Vec<Account>, type_id: &str, amount: Decimal| match accounts
+        .iter_mut()
+        .find(|a| is_of_type(a, type_id))
+    {
+        Some(account) => {
+            account.balance += amount;
+            account.contributions_this_year += amount;
+        }
+        None => {
+            let mut account = Account::new(
+                type_id.to_string(),
+                type_id.to_string(),
+                AccountKind::Retirement {
+                    account_type_id: type_id.to_string(),
+                },
+            );
+            account.balance = amount;
+            account.contributions_this_year = amount;
+            accounts.push(account);
+        }
+    };
+
+    // Rank by employer match, then by the tax each unit of room saves on its own
+    let base_tax = tax_with(&accounts)?;
+    let mut candidates = Vec::new();
+    if player.age < market.retirement_age() {
+        for account_type in market.available_accounts() {
+            let contributed: Decimal = accounts
+                .iter()
+                .filter(|a| is_of_type(a, &account_type.id))
+                .map(|a| a.contributions_this_year)
+                .sum();
+            let room = match account_type.annual_limit {
+                Some(limit) => limit - contributed,
+                None => budget,
+            };
+            if room <= Decimal::ZERO {
+                continue;
+            }
+            let mut trial = accounts.clone();
+            contribute(&mut trial, &account_type.id, room);
+            let savings_rate = (base_tax - tax_with(&trial)?) / room;
+            let matched = employed && account_type.employer_match;
+            candidates.push((account_type, room, matched, savings_rate));
+        }
+    }
+    candidates.sort_by(|a, b| b.2.cmp(&a.2).then(b.3.cmp(&a.3)));
+
+    let mut suggestions = Vec::new();
+    for (account_type, room, matched, _) in candidates {
+        let amount = room.min(budget);
+        if amount <= Decimal::ZERO {
+            break;
+        }
+        let before = tax_with(&accounts)?;
+        let mut trial = accounts.clone();
+        contribute(&mut trial, &account_type.id, amount);
+        let savings = (before - tax_with(&trial)?).round_dp(2);
+        let reason = if matched {
+            SuggestionReason::EmployerMatch
+        } else if savings > Decimal::ZERO {
+            SuggestionReason::TaxDeduction
+        } else {
+            continue;
+        };
+        accounts = trial;
+        budget -= amount;
+        suggestions.push(Suggestion {
+            account_type_id: Some(account_type.id),
+            account_name: account_type.name,
+            reason,
+            annual_amount: amount.round_dp(2),
+            expected_tax_savings: savings,
+        });
+    }
+
+    if budget > Decimal::ZERO {
+        suggestions.push(Suggestion {
+            account_type_id: None,
+            account_name: "Taxable account".to_string(),
+            reason: SuggestionReason::Taxable,
+            annual_amount: budget.round_dp(2),
+            expected_tax_savings: Decimal::ZERO,
+        });
+    }
+    Ok(suggestions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::income::Income;
+    use crate::markets::czech::CzechMarket;
+    use rust_decimal_macros::dec;
+
+    fn employed(salary: Decimal) -> FinancialState {
+        let mut finances = FinancialState::new();
+        finances.add_income(Income::new(
+            "job".to_string(),
+            "Job".to_string(),
+            IncomeKind::Employment,
+            salary,
+        ));
+        finances
+    }
+
+    #[test]
+    fn test_employer_matched_account_comes_first() {
+        let player = PlayerStats::new(30, None, None);
+        let suggestions =
+            suggest_contributions(&employed(dec!(60000)), &CzechMarket, &player).unwrap();
+
+        let first = &suggestions[0];
+        assert_eq!(first.account_type_id.as_deref(), Some("dip"));
+        assert_eq!(first.reason, SuggestionReason::EmployerMatch);
+        assert_eq!(first.annual_amount, dec!(48000));
+        // 15% income tax on the 48,000 CZK deduction
+        assert_eq!(first.expected_tax_savings, dec!(7200));
+
+        // The shared deduction limit is used up, so the rest is invested taxably
+        let last = suggestions.last().unwrap();
+        assert_eq!(last.reason, SuggestionReason::Taxable);
+        assert!(suggestions
+            .iter()
+            .all(|s| s.account_type_id.as_deref() != Some("third_pillar")));
+    }
+
+    #[test]
+    fn test_deductions_without_employer_or_past_retirement() {
+        let mut finances = employed(dec!(60000));
+        finances.income_sources[0].kind = IncomeKind::Passive;
+        let player = PlayerStats::new(30, None, None);
+        let suggestions = suggest_contributions(&finances, &CzechMarket, &player).unwrap();
+        assert_eq!(suggestions[0].reason, SuggestionReason::TaxDeduction);
+        assert_eq!(suggestions[0].expected_tax_savings, dec!(7200));
+
+        let retired = PlayerStats::new(70, None, None);
+        let suggestions = suggest_contributions(&finances, &CzechMarket, &retired).unwrap();
+        assert_eq!(suggestions.len(), 1);
+        assert_eq!(suggestions[0].reason, SuggestionReason::Taxable);
+
+        // Nothing to suggest without a surplus
+        let broke = suggest_contributions(&FinancialState::new(), &CzechMarket, &player).unwrap();
+        assert!(broke.is_empty());
+    }
+}
@@ -0,0 +1,201 @@
+//! Revolving credit card debt
+//!
+//! Cards are not a `LoanKind`: loans amortize on a fixed schedule, while a
+//! card has no term and its payment follows the balance, so cards are kept in
+//! their own list on `FinancialState` next to the loans.
+
+use crate::market::MarketProfile;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+
+/// Annual interest charged on a carried card balance
+/// Markets can set their own through `MarketProfile::credit_card_rate`.
+pub const CREDIT_CARD_ANNUAL_RATE: Decimal = dec!(0.24);
+
+/// Share of the balance due each month as the minimum payment
+pub const CREDIT_CARD_MIN_PAYMENT_RATE: Decimal = dec!(0.03);
+
+/// Smallest minimum payment, unless the balance itself is lower
+/// (CZK, see `MarketProfile::scale_czk`)
+pub const CREDIT_CARD_MIN_PAYMENT_FLOOR: Decimal = dec!(500);
+
+fn default_min_payment_floor() -> Decimal {
+    CREDIT_CARD_MIN_PAYMENT_FLOOR
+}
+
+/// A revolving credit line
+///
+/// Each statement the player pays either the whole balance or only the
+/// minimum. A balance paid in full costs nothing; whatever is carried over
+/// accrues monthly interest, which is added to the balance and compounds.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreditCard {
+    /// Unique identifier
+    pub id: String,
+    /// Display name
+    pub name: String,
+    /// Amount currently owed
+    pub balance: Decimal,
+    /// Annual interest rate on a carried balance
+    pub annual_rate: Decimal,
+    /// Whether the full statement balance is paid each month (otherwise the minimum)
+    pub pays_in_full: bool,
+    /// Interest charged since the card was opened
+    pub total_interest: Decimal,
+    /// Smallest minimum payment, in the market currency
+    #[serde(default = "default_min_payment_floor")]
+    pub min_payment_floor: Decimal,
+}
+
+impl CreditCard {
+    /// Opens a card with no balance that is paid in full each month
+    pub fn new(
+        id: String,
+        name: String,
+        annual_rate: Decimal,
+        min_payment_floor: Decimal,
+    ) -> Result<Self, String> {
+        if annual_rate < Decimal::ZERO {
+            return Err("Interest rate cannot be negative".to_string());
+        }
+        if min_payment_floor < Decimal::ZERO {
+            return Err("Minimum payment cannot be negative".to_string());
+        }
+        Ok(CreditCard {
+            id,
+            name,
+            balance: Decimal::ZERO,
+            annual_rate,
+            pays_in_full: true,
+            total_interest: Decimal::ZERO,
+            min_payment_floor,
+        })
+    }
+
+    /// Opens a card on the market's typical rate and minimum payment
+    pub fn open(id: String, name: String, market: &dyn MarketProfile) -> Result<Self, String> {
+        Self::new(
+            id,
+            name,
+            market.credit_card_rate(),
+            market.scale_czk(CREDIT_CARD_MIN_PAYMENT_FLOOR),
+        )
+    }
+
+    /// Puts a purchase on the card
+    pub fn charge(&mut self, amount: Decimal) -> Result<(), String> {
+        if amount <= Decimal::ZERO {
+            return Err("Charge amount must be positive".to_string());
+        }
+        self.balance += amount;
+        Ok(())
+    }
+
+    /// Minimum payment: `CREDIT_CARD_MIN_PAYMENT_RATE` of the balance, at least
+    /// the card's floor, never more than the balance
+    pub fn minimum_payment(&self) -> Decimal {
+        (self.balance * CREDIT_CARD_MIN_PAYMENT_RATE)
+            .round_dp(2)
+            .max(self.min_payment_floor)
+            .min(self.balance)
+            .max(Decimal::ZERO)
+    }
+
+    /// Payment due this month under the chosen repayment
+    pub fn payment_due(&self) -> Decimal {
+        if self.pays_in_full {
+            self.balance.max(Decimal::ZERO)
+        } else {
+            self.minimum_payment()
+        }
+    }
+
+    /// Pays this month's statement, then charges interest on what is carried over
+    /// Returns the amount paid.
+    pub fn make_payment(&mut self) -> Decimal {
+        let payment = self.payment_due();
+        self.balance -= payment;
+        let interest = (self.balance * self.annual_rate / Decimal::from(12)).round_dp(2);
+        if interest > Decimal::ZERO {
+            self.balance += interest;
+            self.total_interest += interest;
+        }
+        payment
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn card_with(balance: Decimal, pays_in_full: bool) -> CreditCard {
+        let mut card = CreditCard::new(
+            "card".to_string(),
+            "Credit Card".to_string(),
+            CREDIT_CARD_ANNUAL_RATE,
+            CREDIT_CARD_MIN_PAYMENT_FLOOR,
+        )
+        .unwrap();
+        card.charge(balance).unwrap();
+        card.pays_in_full = pays_in_full;
+        card
+    }
+
+    #[test]
+    fn test_minimum_payment_rule() {
+        assert_eq!(card_with(dec!(50000), false).minimum_payment(), dec!(1500));
+        // The floor applies to small balances, but never above the balance
+        assert_eq!(card_with(dec!(5000), false).minimum_payment(), dec!(500));
+        assert_eq!(card_with(dec!(300), false).minimum_payment(), dec!(300));
+    }
+
+    #[test]
+    fn test_cards_open_on_market_terms() {
+        let card = CreditCard::open(
+            "card".to_string(),
+            "Credit Card".to_string(),
+            &crate::markets::czech::CzechMarket,
+        )
+        .unwrap();
+        assert_eq!(card.annual_rate, CREDIT_CARD_ANNUAL_RATE);
+        assert_eq!(card.min_payment_floor, CREDIT_CARD_MIN_PAYMENT_FLOOR);
+
+        #[cfg(feature = "usa")]
+        {
+            let mut card = CreditCard::open(
+                "card".to_string(),
+                "Credit Card".to_string(),
+                &crate::markets::usa::UsaMarket,
+            )
+            .unwrap();
+            assert_eq!(card.annual_rate, dec!(0.21));
+            // 500 CZK scaled to dollars
+            assert_eq!(card.min_payment_floor, dec!(43));
+            card.charge(dec!(1000)).unwrap();
+            assert_eq!(card.minimum_payment(), dec!(43));
+        }
+    }
+
+    #[test]
+    fn test_paying_only_the_minimum_compounds() {
+        let mut minimum = card_with(dec!(50000), false);
+        let mut full = card_with(dec!(50000), true);
+
+        let mut paid_minimum = Decimal::ZERO;
+        for _ in 0..12 {
+            paid_minimum += minimum.make_payment();
+        }
+        let paid_full = full.make_payment();
+
+        // A year of minimum payments barely dents the debt and costs a lot of interest
+        assert!(minimum.total_interest > dec!(9000));
+        assert!(minimum.balance > dec!(40000));
+        assert!(paid_minimum + minimum.balance > dec!(55000));
+
+        // Paying in full clears the card without any interest
+        assert_eq!(paid_full, dec!(50000));
+        assert_eq!(full.balance, Decimal::ZERO);
+        assert_eq!(full.total_interest, Decimal::ZERO);
+    }
+}
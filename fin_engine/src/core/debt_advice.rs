@@ -0,0 +1,147 @@
+//! Pay off debt versus invest advice
+
+use super::financial_state::FinancialState;
+use crate::market::MarketProfile;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// What to do with spare money
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AdviceAction {
+    /// Prepay the most expensive debt first
+    PayOffDebt,
+    /// Invest and keep paying the debt on schedule
+    Invest,
+}
+
+impl AdviceAction {
+    /// Returns display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            AdviceAction::PayOffDebt => "Pay off debt",
+            AdviceAction::Invest => "Invest",
+        }
+    }
+}
+
+/// Recommendation with the numbers behind it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Advice {
+    /// Recommended use of spare money
+    pub action: AdviceAction,
+    /// Name of the highest-rate loan or card (None without debt)
+    pub debt_name: Option<String>,
+    /// Its annual interest rate
+    pub debt_rate: Decimal,
+    /// The market's expected annual stock return
+    pub expected_return: Decimal,
+    /// That return after the market's long-term capital gains tax
+    pub after_tax_return: Decimal,
+}
+
+/// Compares the highest-rate debt with the market's expected after-tax return
+///
+/// Prepaying a loan earns its interest rate risk-free, so payoff wins whenever
+/// the rate exceeds what investing is expected to earn after tax.
+pub fn debt_payoff_advice(finances: &FinancialState, market: &dyn MarketProfile) -> Advice {
+    let expected_return = market.expected_equity_return();
+    let after_tax_return = expected_return * (Decimal::ONE - market.long_term_gains_tax_rate());
+
+    let loans = finances
+        .loans
+        .iter()
+        .filter(|loan| loan.balance > Decimal::ZERO)
+        .map(|loan| (&loan.name, loan.annual_rate));
+    let cards = finances
+        .credit_cards
+        .iter()
+        .filter(|card| card.balance > Decimal::ZERO)
+        .map(|card| (&card.name, card.annual_rate));
+    let worst = loans.chain(cards).max_by_key(|(_, rate)| *rate);
+
+    let (debt_name, debt_rate) = match worst {
+        Some((name, rate)) => (Some(name.clone()), rate),
+        None => (None, Decimal::ZERO),
+    };
+    let action = if debt_name.is_some() && debt_rate > after_tax_return {
+        AdviceAction::PayOffDebt
+    } else {
+        AdviceAction::Invest
+    };
+
+    Advice {
+        action,
+        debt_name,
+        debt_rate,
+        expected_return,
+        after_tax_return,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::credit_card::{
+        CreditCard, CREDIT_CARD_ANNUAL_RATE, CREDIT_CARD_MIN_PAYMENT_FLOOR,
+    };
+    use crate::core::loans::{Loan, LoanKind};
+    use crate::markets::czech::CzechMarket;
+    use rust_decimal_macros::dec;
+
+    fn with_loan(kind: LoanKind, name: &str, rate: Decimal) -> FinancialState {
+        let mut finances = FinancialState::new();
+        finances.loans.push(
+            Loan::new(
+                "loan".to_string(),
+                name.to_string(),
+                kind,
+                dec!(100000),
+                rate,
+                60,
+            )
+            .unwrap(),
+        );
+        finances
+    }
+
+    #[test]
+    fn test_high_rate_card_debt_is_paid_off_first() {
+        let mut finances = with_loan(LoanKind::Personal, "Personal Loan", dec!(0.09));
+        let mut card = CreditCard::new(
+            "card".to_string(),
+            "Credit Card".to_string(),
+            CREDIT_CARD_ANNUAL_RATE,
+            CREDIT_CARD_MIN_PAYMENT_FLOOR,
+        )
+        .unwrap();
+        card.charge(dec!(20000)).unwrap();
+        finances.add_credit_card(card);
+
+        let advice = debt_payoff_advice(&finances, &CzechMarket);
+        assert_eq!(advice.action, AdviceAction::PayOffDebt);
+        assert_eq!(advice.debt_name.as_deref(), Some("Credit Card"));
+        assert_eq!(advice.debt_rate, CREDIT_CARD_ANNUAL_RATE);
+    }
+
+    #[test]
+    fn test_cheap_mortgage_favours_investing() {
+        let finances = with_loan(LoanKind::Mortgage, "Mortgage", dec!(0.03));
+        let advice = debt_payoff_advice(&finances, &CzechMarket);
+        assert_eq!(advice.action, AdviceAction::Invest);
+        // Czech gains held past the time test are tax-free
+        assert_eq!(advice.after_tax_return, advice.expected_return);
+
+        let debt_free = debt_payoff_advice(&FinancialState::new(), &CzechMarket);
+        assert_eq!(debt_free.action, AdviceAction::Invest);
+        assert!(debt_free.debt_name.is_none());
+
+        // US long-term gains are taxed, which shrinks the return to beat
+        #[cfg(feature = "usa")]
+        {
+            let usa = crate::markets::usa::UsaMarket;
+            let advice = debt_payoff_advice(&finances, &usa);
+            assert_eq!(advice.after_tax_return, advice.expected_return * dec!(0.85));
+            assert_eq!(advice.action, AdviceAction::Invest);
+        }
+    }
+}
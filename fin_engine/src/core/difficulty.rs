@@ -0,0 +1,125 @@
+//! Difficulty levels chosen at game start
+
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+
+/// How forgiving the simulation is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    /// Returns all difficulty levels
+    pub fn all() -> [Difficulty; 3] {
+        [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard]
+    }
+
+    /// Returns display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Difficulty::Easy => "Easy",
+            Difficulty::Normal => "Normal",
+            Difficulty::Hard => "Hard",
+        }
+    }
+
+    /// Returns a short description for the selection screen
+    pub fn description(&self) -> &'static str {
+        match self {
+            Difficulty::Easy => "A full month's salary to start, fewer and cheaper emergencies",
+            Difficulty::Normal => "Half a month's salary to start, typical life and markets",
+            Difficulty::Hard => "A quarter of a salary to start, frequent costly emergencies",
+        }
+    }
+
+    /// Cash given with the first job, as a share of its monthly salary
+    pub fn starting_cash_share(&self) -> Decimal {
+        match self {
+            Difficulty::Easy => Decimal::ONE,
+            Difficulty::Normal => dec!(0.5),
+            Difficulty::Hard => dec!(0.25),
+        }
+    }
+
+    /// Returns the starting cash for a first job paying `monthly_salary`
+    pub fn starting_cash(&self, monthly_salary: Decimal) -> Decimal {
+        monthly_salary * self.starting_cash_share()
+    }
+
+    /// Multiplier on the daily emergency chance
+    pub fn event_frequency(&self) -> f64 {
+        match self {
+            Difficulty::Easy => 0.5,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => 1.5,
+        }
+    }
+
+    /// Multiplier on emergency costs
+    pub fn event_severity(&self) -> Decimal {
+        match self {
+            Difficulty::Easy => dec!(0.75),
+            Difficulty::Normal => Decimal::ONE,
+            Difficulty::Hard => dec!(1.25),
+        }
+    }
+
+    /// Multiplier on the daily windfall chance
+    pub fn windfall_frequency(&self) -> f64 {
+        match self {
+            Difficulty::Easy => 1.5,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => 0.5,
+        }
+    }
+
+    /// Multiplier on windfall amounts
+    pub fn windfall_size(&self) -> Decimal {
+        match self {
+            Difficulty::Easy => dec!(1.25),
+            Difficulty::Normal => Decimal::ONE,
+            Difficulty::Hard => dec!(0.75),
+        }
+    }
+
+    /// Added to the assumed annual market return
+    pub fn return_adjustment(&self) -> Decimal {
+        match self {
+            Difficulty::Easy => dec!(0.01),
+            Difficulty::Normal => Decimal::ZERO,
+            Difficulty::Hard => dec!(-0.01),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::events::Event;
+    use crate::core::rng::GameRng;
+    use crate::markets::czech::CzechMarket;
+
+    #[test]
+    fn test_hard_starts_with_less_cash() {
+        let salary = dec!(40000);
+        assert_eq!(Difficulty::Easy.starting_cash(salary), dec!(40000));
+        assert_eq!(Difficulty::Normal.starting_cash(salary), dec!(20000));
+        assert!(Difficulty::Hard.starting_cash(salary) < Difficulty::Easy.starting_cash(salary));
+    }
+
+    #[test]
+    fn test_hard_has_more_events_than_easy() {
+        let count = |difficulty: Difficulty| {
+            let mut rng = GameRng::new(21);
+            (0..3000)
+                .filter_map(|_| Event::roll_daily(&mut rng, 70, difficulty, &CzechMarket))
+                .count()
+        };
+        assert!(count(Difficulty::Hard) > count(Difficulty::Easy));
+    }
+}
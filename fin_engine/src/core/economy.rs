@@ -0,0 +1,401 @@
+//! Economic cycle that modulates returns, hiring and layoffs
+
+use super::loans::LoanKind;
+use super::rng::GameRng;
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+
+/// Long-run average annual return of an invested portfolio
+/// Markets can assume their own through `MarketProfile::expected_equity_return`.
+pub const DEFAULT_ANNUAL_RETURN: Decimal = dec!(0.07);
+
+/// Annual volatility (standard deviation) of stock returns
+/// Markets can assume their own through `MarketProfile::equity_volatility`.
+pub const EQUITY_ANNUAL_VOLATILITY: Decimal = dec!(0.15);
+
+/// Long-run average annual return of bonds
+/// Markets can assume their own through `MarketProfile::expected_bond_return`.
+pub const BOND_ANNUAL_RETURN: Decimal = dec!(0.03);
+
+/// Annual volatility (standard deviation) of bond returns
+pub const BOND_ANNUAL_VOLATILITY: Decimal = dec!(0.05);
+
+/// Monthly chance of a stock market crash (roughly once every 15 years)
+pub const MARKET_CRASH_MONTHLY_CHANCE: f64 = 0.0055;
+
+/// Stock return in the month of a crash
+pub const MARKET_CRASH_DROP: Decimal = dec!(-0.30);
+
+/// Annual dividend yield of stocks, paid out monthly
+/// Markets can assume their own through `MarketProfile::equity_dividend_yield`.
+pub const EQUITY_DIVIDEND_YIELD: Decimal = dec!(0.02);
+
+/// Annual raise given on each job anniversary in a normal economy
+pub const BASE_ANNUAL_RAISE: Decimal = dec!(0.03);
+
+/// Central bank rate at the start of a game
+/// Markets can start from their own through `MarketProfile::base_interest_rate`.
+pub const DEFAULT_BASE_INTEREST_RATE: Decimal = dec!(0.04);
+
+/// Highest the base rate can climb
+const MAX_BASE_INTEREST_RATE: Decimal = dec!(0.12);
+
+/// Savings accounts pay this much below the base rate
+const SAVINGS_RATE_DISCOUNT: Decimal = dec!(0.01);
+
+/// Mortgages are priced this much above the base rate
+const MORTGAGE_RATE_SPREAD: Decimal = dec!(0.015);
+
+/// Unsecured loans are priced this much above the base rate
+const PERSONAL_LOAN_RATE_SPREAD: Decimal = dec!(0.05);
+
+/// Student loans are priced this much above the base rate
+const STUDENT_LOAN_RATE_SPREAD: Decimal = dec!(0.01);
+
+/// Overdrawn cash is charged this much above the base rate
+const OVERDRAFT_RATE_SPREAD: Decimal = dec!(0.15);
+
+fn default_base_interest_rate() -> Decimal {
+    DEFAULT_BASE_INTEREST_RATE
+}
+
+/// Phase of the business cycle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum EconomyPhase {
+    /// Growing economy: strong returns, plenty of jobs
+    #[default]
+    Expansion,
+    /// Top of the cycle: growth stalls
+    Peak,
+    /// Shrinking economy: falling markets, layoffs, few openings
+    Recession,
+    /// Bounce back after a recession
+    Recovery,
+}
+
+impl EconomyPhase {
+    /// Returns display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            EconomyPhase::Expansion => "Expansion",
+            EconomyPhase::Peak => "Peak",
+            EconomyPhase::Recession => "Recession",
+            EconomyPhase::Recovery => "Recovery",
+        }
+    }
+
+    /// Returns the phase that follows this one
+    pub fn next(&self) -> Self {
+        match self {
+            EconomyPhase::Expansion => EconomyPhase::Peak,
+            EconomyPhase::Peak => EconomyPhase::Recession,
+            EconomyPhase::Recession => EconomyPhase::Recovery,
+            EconomyPhase::Recovery => EconomyPhase::Expansion,
+        }
+    }
+
+    /// Average length of the phase in months
+    pub fn average_months(&self) -> u32 {
+        match self {
+            EconomyPhase::Expansion => 60,
+            EconomyPhase::Peak => 6,
+            EconomyPhase::Recession => 12,
+            EconomyPhase::Recovery => 18,
+        }
+    }
+
+    /// Added to the long-run annual return while in this phase
+    pub fn return_adjustment(&self) -> Decimal {
+        match self {
+            EconomyPhase::Expansion => dec!(0.03),
+            EconomyPhase::Peak => Decimal::ZERO,
+            EconomyPhase::Recession => dec!(-0.20),
+            EconomyPhase::Recovery => dec!(0.08),
+        }
+    }
+
+    /// Share of job openings still advertised (1.0 = all)
+    pub fn job_availability(&self) -> Decimal {
+        match self {
+            EconomyPhase::Expansion | EconomyPhase::Peak => Decimal::ONE,
+            EconomyPhase::Recession => dec!(0.4),
+            EconomyPhase::Recovery => dec!(0.7),
+        }
+    }
+
+    /// Monthly chance of being laid off
+    pub fn layoff_chance(&self) -> f64 {
+        match self {
+            EconomyPhase::Expansion => 0.001,
+            EconomyPhase::Peak => 0.002,
+            EconomyPhase::Recession => 0.02,
+            EconomyPhase::Recovery => 0.005,
+        }
+    }
+
+    /// Monthly drift of the base interest rate: central banks tighten in
+    /// good times and cut in recessions
+    pub fn rate_drift(&self) -> Decimal {
+        match self {
+            EconomyPhase::Expansion => dec!(0.0005),
+            EconomyPhase::Peak => dec!(0.001),
+            EconomyPhase::Recession => dec!(-0.0025),
+            EconomyPhase::Recovery => dec!(-0.0005),
+        }
+    }
+
+    /// Multiplier applied to the base annual raise
+    pub fn raise_multiplier(&self) -> Decimal {
+        match self {
+            EconomyPhase::Expansion => dec!(1.5),
+            EconomyPhase::Peak => Decimal::ONE,
+            EconomyPhase::Recession => Decimal::ZERO,
+            EconomyPhase::Recovery => dec!(0.5),
+        }
+    }
+}
+
+/// State of the economy, evolved monthly from the game's seeded RNG
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EconomyState {
+    /// Current phase of the cycle
+    pub phase: EconomyPhase,
+    /// Months spent in the current phase
+    pub months_in_phase: u32,
+    /// Central bank base rate that savings and new loans are priced from
+    #[serde(default = "default_base_interest_rate")]
+    pub base_interest_rate: Decimal,
+    /// Stock return drawn for the latest month
+    #[serde(default)]
+    pub last_equity_return: Decimal,
+    /// Bond return drawn for the latest month
+    #[serde(default)]
+    pub last_bond_return: Decimal,
+    /// Recessions the economy has come out of since the game started
+    #[serde(default)]
+    pub recessions_survived: u32,
+}
+
+impl Default for EconomyState {
+    fn default() -> Self {
+        Self::new(EconomyPhase::default())
+    }
+}
+
+impl EconomyState {
+    /// Creates an economy in the given phase
+    pub fn new(phase: EconomyPhase) -> Self {
+        EconomyState {
+            phase,
+            months_in_phase: 0,
+            base_interest_rate: DEFAULT_BASE_INTEREST_RATE,
+            last_equity_return: Decimal::ZERO,
+            last_bond_return: Decimal::ZERO,
+            recessions_survived: 0,
+        }
+    }
+
+    /// Moves the cycle to a new phase, counting the recession it leaves behind
+    pub fn enter_phase(&mut self, phase: EconomyPhase) {
+        if self.phase == EconomyPhase::Recession && phase != EconomyPhase::Recession {
+            self.recessions_survived += 1;
+        }
+        self.phase = phase;
+        self.months_in_phase = 0;
+    }
+
+    /// Advances the cycle by one month
+    /// Each month the phase ends with probability 1 / its average length,
+    /// and the base rate drifts with the phase plus some noise.
+    pub fn advance_month(&mut self, rng: &mut GameRng) {
+        self.months_in_phase += 1;
+        if rng.chance(1.0 / self.phase.average_months() as f64) {
+            self.enter_phase(self.phase.next());
+        }
+
+        let noise = Decimal::from_f64(rng.next_normal() * 0.001).unwrap_or(Decimal::ZERO);
+        self.base_interest_rate = (self.base_interest_rate + self.phase.rate_drift() + noise)
+            .clamp(Decimal::ZERO, MAX_BASE_INTEREST_RATE)
+            .round_dp(4);
+    }
+
+    /// Annual interest paid on savings (floats with the base rate)
+    pub fn savings_interest_rate(&self) -> Decimal {
+        (self.base_interest_rate - SAVINGS_RATE_DISCOUNT).max(Decimal::ZERO)
+    }
+
+    /// Annual interest charged on an overdrawn cash balance
+    pub fn overdraft_rate(&self) -> Decimal {
+        self.base_interest_rate + OVERDRAFT_RATE_SPREAD
+    }
+
+    /// Annual rate offered on a new loan of the given kind
+    pub fn loan_rate(&self, kind: LoanKind) -> Decimal {
+        let spread = match kind {
+            LoanKind::Mortgage => MORTGAGE_RATE_SPREAD,
+            LoanKind::Personal | LoanKind::Installment => PERSONAL_LOAN_RATE_SPREAD,
+            LoanKind::Student => STUDENT_LOAN_RATE_SPREAD,
+        };
+        self.base_interest_rate + spread
+    }
+
+    /// Returns the monthly return applied to invested accounts
+    pub fn monthly_return(&self, annual_return: Decimal) -> Decimal {
+        (annual_return + self.phase.return_adjustment()) / Decimal::from(12)
+    }
+
+    /// Draws this month's (equity, bond) returns and remembers them
+    /// Stocks follow the cycle around `annual_return` with `equity_volatility`;
+    /// bonds earn `bond_return` with much smaller swings.
+    pub fn sample_sleeve_returns(
+        &mut self,
+        annual_return: Decimal,
+        equity_volatility: Decimal,
+        bond_return: Decimal,
+        rng: &mut GameRng,
+    ) -> (Decimal, Decimal) {
+        let mut shock = |annual_volatility: Decimal| {
+            let monthly = annual_volatility.to_f64().unwrap_or(0.0) / 12f64.sqrt();
+            Decimal::from_f64(monthly * rng.next_normal()).unwrap_or(Decimal::ZERO)
+        };
+        let equity = self.monthly_return(annual_return) + shock(equity_volatility);
+        let bonds = bond_return / Decimal::from(12) + shock(BOND_ANNUAL_VOLATILITY);
+        self.last_equity_return = equity.round_dp(6);
+        self.last_bond_return = bonds.round_dp(6);
+        (self.last_equity_return, self.last_bond_return)
+    }
+
+    /// Returns the annual raise given on a job anniversary
+    pub fn annual_raise(&self) -> Decimal {
+        BASE_ANNUAL_RAISE * self.phase.raise_multiplier()
+    }
+
+    /// Returns how many of `offered` openings are actually advertised
+    /// At least one opening always remains.
+    pub fn available_openings(&self, offered: usize) -> usize {
+        if offered == 0 {
+            return 0;
+        }
+        (Decimal::from(offered) * self.phase.job_availability())
+            .ceil()
+            .to_usize()
+            .unwrap_or(offered)
+            .clamp(1, offered)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::accounts::{Account, AccountKind, Allocation};
+
+    #[test]
+    fn test_bonds_grow_more_steadily_than_equity() {
+        let mut rng = GameRng::new(11);
+        let mut economy = EconomyState::default();
+        let account = |allocation| {
+            let mut account = Account::new(
+                "inv".to_string(),
+                "Investments".to_string(),
+                AccountKind::Taxable,
+            );
+            account.deposit(dec!(100000)).unwrap();
+            account.allocation = allocation;
+            account
+        };
+        let mut stocks = account(Allocation::all_equity());
+        let mut bonds = account(Allocation::all_bonds());
+
+        // Largest single-month move of each account under the same draws
+        let mut worst_swing = (Decimal::ZERO, Decimal::ZERO);
+        for _ in 0..120 {
+            let (before_stocks, before_bonds) = (stocks.balance, bonds.balance);
+            let (equity_return, bond_return) = economy.sample_sleeve_returns(
+                DEFAULT_ANNUAL_RETURN,
+                EQUITY_ANNUAL_VOLATILITY,
+                BOND_ANNUAL_RETURN,
+                &mut rng,
+            );
+            stocks.apply_sleeve_returns(equity_return, bond_return);
+            bonds.apply_sleeve_returns(equity_return, bond_return);
+            worst_swing.0 = worst_swing
+                .0
+                .max((stocks.balance / before_stocks - Decimal::ONE).abs());
+            worst_swing.1 = worst_swing
+                .1
+                .max((bonds.balance / before_bonds - Decimal::ONE).abs());
+        }
+
+        assert!(worst_swing.1 < worst_swing.0);
+        assert!(bonds.balance > dec!(100000));
+        assert_eq!(bonds.allocation, Allocation::all_bonds());
+    }
+
+    #[test]
+    fn test_mixed_allocation_drifts() {
+        let mut account = Account::new(
+            "inv".to_string(),
+            "Investments".to_string(),
+            AccountKind::Taxable,
+        );
+        account.deposit(dec!(100000)).unwrap();
+        account.allocation = Allocation::new(dec!(0.6)).unwrap();
+
+        // Stocks +10%, bonds flat: 66k stocks, 40k bonds
+        account.apply_sleeve_returns(dec!(0.1), Decimal::ZERO);
+        assert_eq!(account.balance, dec!(106000));
+        assert!(account.allocation.equity > dec!(0.6));
+        assert_eq!(
+            account.allocation.equity + account.allocation.bonds,
+            Decimal::ONE
+        );
+        assert!(Allocation::new(dec!(1.5)).is_err());
+    }
+
+    #[test]
+    fn test_cycle_visits_every_phase() {
+        let mut rng = GameRng::new(3);
+        let mut economy = EconomyState::default();
+        let mut seen = vec![economy.phase];
+        for _ in 0..1200 {
+            economy.advance_month(&mut rng);
+            if !seen.contains(&economy.phase) {
+                seen.push(economy.phase);
+            }
+        }
+        assert_eq!(seen.len(), 4);
+    }
+
+    #[test]
+    fn test_recession_depresses_returns() {
+        let boom = EconomyState::new(EconomyPhase::Expansion);
+        let bust = EconomyState::new(EconomyPhase::Recession);
+        assert!(bust.monthly_return(DEFAULT_ANNUAL_RETURN) < Decimal::ZERO);
+        assert!(
+            boom.monthly_return(DEFAULT_ANNUAL_RETURN) > bust.monthly_return(DEFAULT_ANNUAL_RETURN)
+        );
+        assert_eq!(bust.annual_raise(), Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_base_rate_prices_savings_and_loans() {
+        let mut economy = EconomyState::new(EconomyPhase::Expansion);
+        economy.base_interest_rate = dec!(0.02);
+        assert_eq!(economy.savings_interest_rate(), dec!(0.01));
+        assert_eq!(economy.loan_rate(LoanKind::Mortgage), dec!(0.035));
+
+        economy.base_interest_rate = dec!(0.005);
+        assert_eq!(economy.savings_interest_rate(), Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_available_openings() {
+        let bust = EconomyState::new(EconomyPhase::Recession);
+        assert_eq!(bust.available_openings(10), 4);
+        assert_eq!(bust.available_openings(1), 1);
+        assert_eq!(bust.available_openings(0), 0);
+        assert_eq!(EconomyState::default().available_openings(10), 10);
+    }
+}
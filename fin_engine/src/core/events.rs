@@ -0,0 +1,290 @@
+//! Random life events that interrupt the month
+
+use super::difficulty::Difficulty;
+use super::expenses::ExpenseCategory;
+use super::rng::GameRng;
+use crate::market::MarketProfile;
+use rust_decimal::prelude::FromPrimitive;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+
+/// Daily chance of an emergency (roughly one every three months)
+const DAILY_EMERGENCY_CHANCE: f64 = 1.0 / 90.0;
+
+/// Daily chance of a windfall (roughly one a year)
+const DAILY_WINDFALL_CHANCE: f64 = 1.0 / 365.0;
+
+/// Windfalls worth at least this many months of expenses tempt lifestyle creep
+pub const WINDFALL_CREEP_THRESHOLD_MONTHS: Decimal = dec!(3);
+
+/// Share of a large windfall that becomes extra monthly Lifestyle spending
+pub const WINDFALL_LIFESTYLE_CREEP: Decimal = dec!(0.005);
+
+/// Recurring monthly cost of raising one child (CZK, see `MarketProfile::scale_czk`)
+pub const CHILD_MONTHLY_COST: Decimal = dec!(8000);
+
+/// One-time cost of a new child: birth, equipment, furniture (CZK, see `MarketProfile::scale_czk`)
+pub const CHILD_BIRTH_COST: Decimal = dec!(30000);
+
+/// Type of unexpected expense
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EmergencyKind {
+    CarRepair,
+    MedicalBill,
+    ApplianceFailure,
+}
+
+impl EmergencyKind {
+    /// Returns all emergency kinds
+    pub fn all() -> [EmergencyKind; 3] {
+        [
+            EmergencyKind::CarRepair,
+            EmergencyKind::MedicalBill,
+            EmergencyKind::ApplianceFailure,
+        ]
+    }
+
+    /// Returns display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            EmergencyKind::CarRepair => "Car Repair",
+            EmergencyKind::MedicalBill => "Medical Bill",
+            EmergencyKind::ApplianceFailure => "Appliance Failure",
+        }
+    }
+
+    /// Category the cost is booked under in the month's expenses
+    pub fn expense_category(&self) -> ExpenseCategory {
+        match self {
+            EmergencyKind::CarRepair => ExpenseCategory::Transportation,
+            EmergencyKind::MedicalBill => ExpenseCategory::Health,
+            EmergencyKind::ApplianceFailure => ExpenseCategory::Essential,
+        }
+    }
+
+    /// Typical cost before severity is applied, in the market currency
+    pub fn base_cost(&self, market: &dyn MarketProfile) -> Decimal {
+        market.scale_czk(match self {
+            EmergencyKind::CarRepair => dec!(15000),
+            EmergencyKind::MedicalBill => dec!(8000),
+            EmergencyKind::ApplianceFailure => dec!(10000),
+        })
+    }
+}
+
+/// Type of unexpected lump sum
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WindfallKind {
+    Bonus,
+    Inheritance,
+    Lottery,
+}
+
+impl WindfallKind {
+    /// Returns display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            WindfallKind::Bonus => "Surprise Bonus",
+            WindfallKind::Inheritance => "Inheritance",
+            WindfallKind::Lottery => "Lottery Win",
+        }
+    }
+
+    /// Typical amount before size is applied, in the market currency
+    pub fn base_amount(&self, market: &dyn MarketProfile) -> Decimal {
+        market.scale_czk(match self {
+            WindfallKind::Bonus => dec!(30000),
+            WindfallKind::Inheritance => dec!(250000),
+            WindfallKind::Lottery => dec!(1500000),
+        })
+    }
+
+    /// Picks a kind: bonuses are common, inheritances occasional, lottery wins rare
+    fn random(rng: &mut GameRng) -> Self {
+        match rng.next_below(100) {
+            0..=64 => WindfallKind::Bonus,
+            65..=96 => WindfallKind::Inheritance,
+            _ => WindfallKind::Lottery,
+        }
+    }
+}
+
+/// An event that happens during the Execution phase
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Event {
+    /// Unexpected one-time expense (paid from emergency fund, then cash, then debt)
+    Emergency { kind: EmergencyKind, cost: Decimal },
+    /// A child joins the household (one-time cost, then recurring expenses)
+    ChildBorn {
+        cost: Decimal,
+        /// Recurring cost of raising each child, in the market currency
+        #[serde(default)]
+        monthly_cost: Decimal,
+    },
+    /// The player's job was cut
+    Layoff { job_title: String },
+    /// Annual tax reconciliation (negative refund means tax was owed)
+    TaxSettlement { refund: Decimal },
+    /// Stock markets collapse (`drop` is the equity return, e.g. -0.30)
+    MarketCrash { drop: Decimal },
+    /// An unexpected lump sum (`tax` is owed on `amount` under market rules)
+    Windfall {
+        kind: WindfallKind,
+        amount: Decimal,
+        tax: Decimal,
+    },
+}
+
+impl Event {
+    /// Creates a random emergency with severity between 0.5x and 2x the base cost
+    pub fn random_emergency(rng: &mut GameRng, market: &dyn MarketProfile) -> Self {
+        let kinds = EmergencyKind::all();
+        let kind = kinds[rng.next_below(kinds.len() as u64) as usize];
+        Self::emergency_of_kind(kind, rng, market)
+    }
+
+    /// Creates an emergency of the given kind with random severity
+    pub fn emergency_of_kind(
+        kind: EmergencyKind,
+        rng: &mut GameRng,
+        market: &dyn MarketProfile,
+    ) -> Self {
+        let severity = Decimal::from_f64(rng.next_range(0.5, 2.0)).unwrap_or(Decimal::ONE);
+        Event::Emergency {
+            kind,
+            cost: (kind.base_cost(market) * severity).round_dp(0),
+        }
+    }
+
+    /// Rolls for an event on a single day
+    /// Health below 50 adds up to the same chance again of a medical bill.
+    /// Difficulty scales both how often emergencies happen and what they cost.
+    pub fn roll_daily(
+        rng: &mut GameRng,
+        health: u8,
+        difficulty: Difficulty,
+        market: &dyn MarketProfile,
+    ) -> Option<Self> {
+        let chance = DAILY_EMERGENCY_CHANCE * difficulty.event_frequency();
+        let frailty = 50u8.saturating_sub(health) as f64 / 50.0;
+        let event = if rng.chance(chance) {
+            Self::random_emergency(rng, market)
+        } else if rng.chance(chance * frailty) {
+            Self::emergency_of_kind(EmergencyKind::MedicalBill, rng, market)
+        } else {
+            return None;
+        };
+        Some(event.with_severity(difficulty.event_severity()))
+    }
+
+    /// Rolls for a windfall on a single day, returning its kind and pre-tax amount
+    /// Difficulty scales how often windfalls happen and how large they are.
+    pub fn roll_windfall(
+        rng: &mut GameRng,
+        difficulty: Difficulty,
+        market: &dyn MarketProfile,
+    ) -> Option<(WindfallKind, Decimal)> {
+        if !rng.chance(DAILY_WINDFALL_CHANCE * difficulty.windfall_frequency()) {
+            return None;
+        }
+        let kind = WindfallKind::random(rng);
+        let size = Decimal::from_f64(rng.next_range(0.5, 2.0)).unwrap_or(Decimal::ONE);
+        let amount = (kind.base_amount(market) * size * difficulty.windfall_size()).round_dp(0);
+        Some((kind, amount))
+    }
+
+    /// Scales the cost of an emergency
+    pub fn with_severity(self, multiplier: Decimal) -> Self {
+        match self {
+            Event::Emergency { kind, cost } => Event::Emergency {
+                kind,
+                cost: (cost * multiplier).round_dp(0),
+            },
+            other => other,
+        }
+    }
+
+    /// Returns the event title for display
+    pub fn title(&self) -> &'static str {
+        match self {
+            Event::Emergency { kind, .. } => kind.name(),
+            Event::ChildBorn { .. } => "New Child",
+            Event::Layoff { .. } => "Laid Off",
+            Event::MarketCrash { .. } => "Market Crash",
+            Event::Windfall { kind, .. } => kind.name(),
+            Event::TaxSettlement { refund } if *refund >= Decimal::ZERO => "Tax Refund",
+            Event::TaxSettlement { .. } => "Tax Bill",
+        }
+    }
+
+    /// Returns the one-time cost of the event
+    pub fn cost(&self) -> Decimal {
+        match self {
+            Event::Emergency { cost, .. } | Event::ChildBorn { cost, .. } => *cost,
+            Event::Layoff { .. } | Event::MarketCrash { .. } | Event::Windfall { .. } => {
+                Decimal::ZERO
+            }
+            Event::TaxSettlement { refund } => (-*refund).max(Decimal::ZERO),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::markets::czech::CzechMarket;
+
+    #[test]
+    fn test_emergency_is_deterministic_per_seed() {
+        let mut a = GameRng::new(99);
+        let mut b = GameRng::new(99);
+        assert_eq!(
+            Event::random_emergency(&mut a, &CzechMarket),
+            Event::random_emergency(&mut b, &CzechMarket)
+        );
+    }
+
+    #[test]
+    fn test_poor_health_means_more_emergencies() {
+        let count = |health: u8| {
+            let mut rng = GameRng::new(11);
+            (0..3000)
+                .filter_map(|_| {
+                    Event::roll_daily(&mut rng, health, Difficulty::Normal, &CzechMarket)
+                })
+                .count()
+        };
+        assert!(count(0) > count(100));
+    }
+
+    #[test]
+    fn test_emergency_severity_range() {
+        let mut rng = GameRng::new(5);
+        for _ in 0..100 {
+            let Event::Emergency { kind, cost } = Event::random_emergency(&mut rng, &CzechMarket)
+            else {
+                panic!("Expected an emergency");
+            };
+            assert!(cost >= kind.base_cost(&CzechMarket) * dec!(0.5));
+            assert!(cost <= kind.base_cost(&CzechMarket) * dec!(2));
+        }
+    }
+
+    #[test]
+    fn test_windfall_is_deterministic_per_seed() {
+        let roll = || {
+            let mut rng = GameRng::new(3);
+            (0..2000)
+                .filter_map(|_| Event::roll_windfall(&mut rng, Difficulty::Normal, &CzechMarket))
+                .collect::<Vec<_>>()
+        };
+        let windfalls = roll();
+        assert!(!windfalls.is_empty());
+        assert_eq!(windfalls, roll());
+        for (kind, amount) in windfalls {
+            assert!(amount >= kind.base_amount(&CzechMarket) * dec!(0.5));
+            assert!(amount <= kind.base_amount(&CzechMarket) * dec!(2));
+        }
+    }
+}
@@ -1,8 +1,32 @@
 //! Expense tracking and categorization
 
 use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 
+use super::income::LAST_DAY_OF_MONTH;
+
+/// Day of the month on which recurring bills, premiums and loan installments are due
+/// unless set otherwise
+pub const DEFAULT_DUE_DAY: u8 = 1;
+
+fn default_due_day() -> u8 {
+    DEFAULT_DUE_DAY
+}
+
+/// Monthly Health spending (gym, sports, check-ups) that keeps the player fit (CZK, see `MarketProfile::scale_czk`)
+pub const HEALTHY_LIVING_BUDGET: Decimal = dec!(1500);
+
+/// Monthly Lifestyle spending (hobbies, going out) that relieves stress (CZK, see `MarketProfile::scale_czk`)
+pub const LEISURE_BUDGET: Decimal = dec!(3000);
+
+/// Monthly spending that buys one happiness point at a multiplier of 1
+/// (CZK, see `MarketProfile::scale_czk`)
+pub const PURCHASE_HAPPINESS_UNIT: Decimal = dec!(3000);
+
+/// Most happiness spending can buy in a month
+pub const MAX_PURCHASE_HAPPINESS: i8 = 3;
+
 /// Expense category
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ExpenseCategory {
@@ -21,11 +45,43 @@ pub enum ExpenseCategory {
 }
 
 impl ExpenseCategory {
+    /// Returns display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            ExpenseCategory::Essential => "Essentials",
+            ExpenseCategory::Lifestyle => "Lifestyle",
+            ExpenseCategory::Health => "Health",
+            ExpenseCategory::Transportation => "Transportation",
+            ExpenseCategory::Education => "Education",
+            ExpenseCategory::Other => "Other",
+        }
+    }
+
+    /// Returns all categories
+    pub fn all() -> [ExpenseCategory; 6] {
+        [
+            ExpenseCategory::Essential,
+            ExpenseCategory::Lifestyle,
+            ExpenseCategory::Health,
+            ExpenseCategory::Transportation,
+            ExpenseCategory::Education,
+            ExpenseCategory::Other,
+        ]
+    }
+
     /// Returns true if this is an essential expense
     pub fn is_essential(&self) -> bool {
         matches!(self, ExpenseCategory::Essential)
     }
 
+    /// Returns true if spending in this category is a want rather than a need
+    /// (see `BudgetPreset::wants_split`)
+    pub fn is_discretionary(&self) -> bool {
+        BudgetPreset::wants_split()
+            .iter()
+            .any(|(category, _)| category == self)
+    }
+
     /// Returns the happiness impact multiplier for spending in this category
     pub fn happiness_multiplier(&self) -> f32 {
         match self {
@@ -39,7 +95,52 @@ impl ExpenseCategory {
     }
 }
 
-/// A recurring expense
+/// Budget rule of thumb used to suggest allocations
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BudgetPreset {
+    /// 50% needs, 30% wants, 20% savings
+    FiftyThirtyTwenty,
+    /// 40% needs, 10% wants, 50% savings (aggressive early retirement)
+    Fire,
+}
+
+impl BudgetPreset {
+    /// Returns display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            BudgetPreset::FiftyThirtyTwenty => "50/30/20",
+            BudgetPreset::Fire => "FIRE (40/10/50)",
+        }
+    }
+
+    /// Returns the (needs, wants, savings) shares of net income
+    pub fn shares(&self) -> (Decimal, Decimal, Decimal) {
+        match self {
+            BudgetPreset::FiftyThirtyTwenty => (dec!(0.50), dec!(0.30), dec!(0.20)),
+            BudgetPreset::Fire => (dec!(0.40), dec!(0.10), dec!(0.50)),
+        }
+    }
+
+    /// Categories counted as needs, with their share of the needs budget
+    pub fn needs_split() -> [(ExpenseCategory, Decimal); 3] {
+        [
+            (ExpenseCategory::Essential, dec!(0.70)),
+            (ExpenseCategory::Health, dec!(0.10)),
+            (ExpenseCategory::Transportation, dec!(0.20)),
+        ]
+    }
+
+    /// Categories counted as wants, with their share of the wants budget
+    pub fn wants_split() -> [(ExpenseCategory, Decimal); 3] {
+        [
+            (ExpenseCategory::Lifestyle, dec!(0.70)),
+            (ExpenseCategory::Education, dec!(0.20)),
+            (ExpenseCategory::Other, dec!(0.10)),
+        ]
+    }
+}
+
+/// A recurring expense, or a temporary one that lapses after a set number of months
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Expense {
     /// Unique identifier
@@ -52,6 +153,12 @@ pub struct Expense {
     pub monthly_amount: Decimal,
     /// Whether this expense is currently active
     pub active: bool,
+    /// Day of the month the bill is paid (1-30)
+    #[serde(default = "default_due_day")]
+    pub due_day: u8,
+    /// Months left to pay for a temporary expense (`None` recurs indefinitely)
+    #[serde(default)]
+    pub months_remaining: Option<u8>,
 }
 
 impl Expense {
@@ -68,13 +175,54 @@ impl Expense {
             category,
             monthly_amount,
             active: true,
+            due_day: DEFAULT_DUE_DAY,
+            months_remaining: None,
+        }
+    }
+
+    /// Limits the expense to the given number of months
+    /// A one-time cost (moving, a repair) lasts a single month.
+    pub fn for_months(mut self, months: u8) -> Result<Self, String> {
+        if months == 0 {
+            return Err("A temporary expense must last at least one month".to_string());
+        }
+        self.months_remaining = Some(months);
+        Ok(self)
+    }
+
+    /// Returns true if the expense lapses on its own
+    pub fn is_temporary(&self) -> bool {
+        self.months_remaining.is_some()
+    }
+
+    /// Counts off a paid month; returns true once nothing is left to pay
+    pub fn settle_month(&mut self) -> bool {
+        match self.months_remaining.as_mut() {
+            Some(months) if self.active => {
+                *months = months.saturating_sub(1);
+                *months == 0
+            }
+            _ => false,
+        }
+    }
+
+    /// Sets the day of the month the bill is due
+    pub fn with_due_day(mut self, day: u8) -> Result<Self, String> {
+        if !(1..=LAST_DAY_OF_MONTH).contains(&day) {
+            return Err(format!(
+                "Due day must be between 1 and {}",
+                LAST_DAY_OF_MONTH
+            ));
         }
+        self.due_day = day;
+        Ok(self)
     }
 
     /// Returns annual cost
     pub fn annual_cost(&self) -> Decimal {
         if self.active {
-            self.monthly_amount * Decimal::from(12)
+            let months = self.months_remaining.map_or(12, |m| m.min(12));
+            self.monthly_amount * Decimal::from(months)
         } else {
             Decimal::ZERO
         }
@@ -210,4 +358,32 @@ mod tests {
         assert_eq!(ExpenseCategory::Lifestyle.happiness_multiplier(), 1.0);
         assert!(ExpenseCategory::Essential.happiness_multiplier() < 0.5);
     }
+
+    #[test]
+    fn test_temporary_expense() {
+        let mut moving = Expense::new(
+            "moving".to_string(),
+            "Moving".to_string(),
+            ExpenseCategory::Other,
+            dec!(15000),
+        )
+        .for_months(1)
+        .unwrap();
+        assert!(moving.is_temporary());
+        assert_eq!(moving.annual_cost(), dec!(15000));
+        assert!(moving.settle_month());
+
+        let mut course = Expense::new(
+            "course".to_string(),
+            "Course".to_string(),
+            ExpenseCategory::Education,
+            dec!(2000),
+        )
+        .for_months(3)
+        .unwrap();
+        assert_eq!(course.annual_cost(), dec!(6000));
+        assert!(!course.settle_month());
+        assert_eq!(course.months_remaining, Some(2));
+        assert!(course.clone().for_months(0).is_err());
+    }
 }
@@ -1,14 +1,52 @@
 //! Top-level game state
 
-use super::career::Career;
-use super::financial_state::FinancialState;
-use super::housing::Housing;
+use super::accounts::{AccountKind, Asset};
+use super::achievements::Achievement;
+use super::career::{Career, Job};
+use super::difficulty::Difficulty;
+use super::economy::{EconomyPhase, EconomyState, MARKET_CRASH_DROP, MARKET_CRASH_MONTHLY_CHANCE};
+use super::events::{
+    Event, WindfallKind, CHILD_BIRTH_COST, CHILD_MONTHLY_COST, WINDFALL_CREEP_THRESHOLD_MONTHS,
+    WINDFALL_LIFESTYLE_CREEP,
+};
+use super::expenses::{
+    Expense, ExpenseCategory, HEALTHY_LIVING_BUDGET, LEISURE_BUDGET, PURCHASE_HAPPINESS_UNIT,
+};
+use super::financial_state::{FinancialState, BANKRUPTCY_AFTER_MONTHS, DEFAULT_WITHDRAWAL_RATE};
+use super::goals::Goal;
+use super::history::{MonthlySnapshot, WellbeingDelta, WellbeingFactor};
+use super::housing::{Housing, DEPOSIT_WEAR_AND_TEAR, LANDLORD_RENT_PREMIUM};
+use super::income::{Income, IncomeKind, DEFAULT_PAYDAY, LAST_DAY_OF_MONTH};
+use super::job_market::{JobMarket, REJECTION_HAPPINESS_PENALTY};
+use super::loans::{Loan, LoanKind};
+use super::partner::{HouseholdFinances, Partner, PARTNER_SHARED_COST, WEDDING_COST};
 use super::phase::GamePhase;
-use super::player::PlayerStats;
+use super::player::{
+    PlayerStats, EDUCATION_COST_PER_SKILL_POINT, ON_THE_JOB_SKILL_PER_MONTH,
+    SABBATICAL_BURNOUT_RECOVERY, SABBATICAL_STUDY_SKILL,
+};
+use super::projection::ProjectionResult;
+use super::purchases::{PurchaseOption, INSTALLMENT_MONTHS};
+use super::rng::GameRng;
+use super::tax_year::TaxYear;
 use super::time::GameTime;
-use crate::market::MarketProfile;
+use super::tutorial::TutorialStep;
+use crate::error::FinError;
+use crate::market::{AccountType, MarketProfile};
+use crate::markets::market_profile;
+use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Youngest age a player can start at
+pub const MIN_PLAYER_AGE: u8 = 18;
+
+/// Oldest age a save is considered plausible
+pub const MAX_PLAYER_AGE: u8 = 120;
+
+/// Markets that have been removed or renamed, with the market their saves continue in
+pub const MARKET_MIGRATIONS: &[(&str, &str)] = &[];
 
 /// Complete game state
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -37,11 +75,67 @@ pub struct GameState {
     /// Months at current housing (for tracking moves)
     pub months_at_housing: u32,
 
+    /// Security deposit held by the current landlord
+    #[serde(default)]
+    pub housing_deposit: Decimal,
+
     /// Financial state
     pub finances: FinancialState,
 
     /// Game starting year (for calculations)
     pub start_year: u32,
+
+    /// Seeded random generator for events and projections
+    #[serde(default)]
+    pub rng: GameRng,
+
+    /// Monthly portfolio withdrawal, fixed when the player retires
+    #[serde(default)]
+    pub retirement_withdrawal: Decimal,
+
+    /// Events that happened during the current month
+    #[serde(default)]
+    pub month_events: Vec<Event>,
+
+    /// Player's partner (if married)
+    #[serde(default)]
+    pub partner: Option<Partner>,
+
+    /// Snapshot of every settled month, oldest first
+    #[serde(default)]
+    pub history: Vec<MonthlySnapshot>,
+
+    /// Total price growth since the game started (0.10 = 10%)
+    #[serde(default)]
+    pub cumulative_inflation: Decimal,
+
+    /// Current phase of the business cycle
+    #[serde(default)]
+    pub economy: EconomyState,
+
+    /// Achievements unlocked so far (never removed)
+    #[serde(default)]
+    pub achievements: HashSet<Achievement>,
+
+    /// Difficulty chosen at game start
+    #[serde(default)]
+    pub difficulty: Difficulty,
+
+    /// Consecutive months settled deeply overdrawn (see `BANKRUPTCY_AFTER_MONTHS`)
+    #[serde(default)]
+    pub months_insolvent: u32,
+
+    /// Cash already paid in or out on earlier days of the current month
+    #[serde(default)]
+    pub month_cash_accrued: Decimal,
+
+    /// Happiness and burnout changes since the last settlement, by factor
+    #[serde(default)]
+    pub month_wellbeing: WellbeingDelta,
+
+    /// Whether guided-mode hints are shown (chosen at game start)
+    #[serde(default)]
+    pub tutorial_enabled: bool,
 }
 
 impl GameState {
@@ -54,34 +148,58 @@ impl GameState {
         start_year: u32,
     ) -> Result<Self, String> {
         Ok(GameState {
+            rng: GameRng::from_seed_str(&save_id),
             save_id,
             market_id,
             time: GameTime::new(start_year, 1)?,
             phase: GamePhase::Planning,
-            player: PlayerStats::new(player_age, player_name),
+            player: PlayerStats::new(player_age, player_name, None),
             career: Career::new(),
             housing: None,
             months_at_housing: 0,
+            housing_deposit: Decimal::ZERO,
             finances: FinancialState::new(),
             start_year,
+            retirement_withdrawal: Decimal::ZERO,
+            month_events: Vec::new(),
+            partner: None,
+            history: Vec::new(),
+            cumulative_inflation: Decimal::ZERO,
+            economy: EconomyState::default(),
+            achievements: HashSet::new(),
+            difficulty: Difficulty::default(),
+            months_insolvent: 0,
+            month_cash_accrued: Decimal::ZERO,
+            month_wellbeing: WellbeingDelta::default(),
+            tutorial_enabled: false,
         })
     }
 
     /// Changes housing and handles moving costs
-    /// First month at new place incurs moving costs
-    pub fn change_housing(&mut self, new_housing: Housing) -> Result<(), String> {
-        let moving_cost = new_housing.moving_cost();
+    /// First month at new place incurs moving costs; the previous deposit is
+    /// returned minus `DEPOSIT_WEAR_AND_TEAR` and counts toward them.
+    pub fn change_housing(
+        &mut self,
+        new_housing: Housing,
+        market: &dyn MarketProfile,
+    ) -> Result<(), String> {
+        let moving_cost = new_housing.moving_cost(market);
+        let deposit_refund = self.deposit_refund();
 
         // Check if player can afford moving costs
-        if self.finances.cash < moving_cost {
+        if self.finances.cash + deposit_refund < moving_cost {
+            let currency = market.currency();
             return Err(format!(
-                "Cannot afford moving costs of {:.0} Kč (you have {:.0} Kč)",
-                moving_cost, self.finances.cash
+                "Cannot afford moving costs of {} (you have {})",
+                currency.format(moving_cost),
+                currency.format(self.finances.cash + deposit_refund)
             ));
         }
 
-        // Deduct moving costs
+        // Get the old deposit back, then pay the new one and the moving fee
+        self.finances.cash += deposit_refund;
         self.finances.cash -= moving_cost;
+        self.housing_deposit = new_housing.security_deposit(market);
 
         // Remove old housing expense
         self.finances
@@ -89,59 +207,624 @@ impl GameState {
             .retain(|e| !e.id.starts_with("housing_"));
 
         // Add new housing expense (rent + utilities)
-        let housing_expense = super::expenses::Expense::new(
+        let housing_expense = Expense::new(
             format!("housing_{}", new_housing.id),
             format!("Housing: {}", new_housing.address),
-            super::expenses::ExpenseCategory::Essential,
+            ExpenseCategory::Essential,
             new_housing.total_monthly_cost(),
         );
-        self.finances.expenses.push(housing_expense);
+        self.finances.add_expense(housing_expense);
 
         // Update housing and reset counter
         self.housing = Some(new_housing);
         self.months_at_housing = 0;
+        self.update_commute_expense(market);
+
+        Ok(())
+    }
+
+    /// Returns the part of the current deposit the landlord gives back on moving out
+    pub fn deposit_refund(&self) -> Decimal {
+        self.housing_deposit * (Decimal::ONE - DEPOSIT_WEAR_AND_TEAR)
+    }
+
+    /// Applies for a job and returns whether the employer made an offer
+    ///
+    /// Jobs the player qualifies for always succeed; stretch roles are rolled
+    /// against `JobMarket::application_chance`. A rejection costs happiness and
+    /// the opening can't be reapplied for until next month. Jobs paying less than
+    /// the market's minimum wage are refused. Accepting the offer is left to the caller.
+    pub fn apply_for_job(&mut self, job: &Job, market: &dyn MarketProfile) -> Result<bool, String> {
+        if self.career.rejected_applications.contains(&job.id) {
+            return Err(format!(
+                "{} already turned you down this month",
+                job.company.as_deref().unwrap_or(&job.title)
+            ));
+        }
+
+        market.check_minimum_wage(job)?;
+
+        let chance =
+            JobMarket::application_chance(&self.career, &self.player.skills, job, &self.economy);
+        if chance >= Decimal::ONE || self.rng.chance(chance.to_f64().unwrap_or(0.0)) {
+            return Ok(true);
+        }
+
+        self.track_wellbeing(WellbeingFactor::LifeEvents, |player| {
+            player.adjust_happiness(-REJECTION_HAPPINESS_PENALTY)
+        });
+        self.career.rejected_applications.push(job.id.clone());
+        Ok(false)
+    }
+
+    /// Returns true if the player has never held a job
+    pub fn is_first_job(&self) -> bool {
+        self.career.current_job.is_none() && self.career.job_history.is_empty()
+    }
+
+    /// Opens an empty account dated to the current game month (for the time test)
+    pub fn open_account(&mut self, name: String, kind: AccountKind) -> String {
+        let id = self.finances.open_account(name, kind);
+        self.date_account_opening(&id);
+        id
+    }
+
+    /// Opens one of the market's tax-advantaged accounts dated to the current game month
+    pub fn open_market_account(&mut self, account_type: &AccountType) -> Result<String, String> {
+        let id = self.finances.open_market_account(account_type)?;
+        self.date_account_opening(&id);
+        Ok(id)
+    }
+
+    /// Moves money from an account back to cash, selling at the current game month
+    /// Retirement accounts (e.g. DIP, third pillar) stay locked until the
+    /// player reaches retirement age. Returns the amount credited after tax.
+    pub fn withdraw_from_account(
+        &mut self,
+        id: &str,
+        amount: Decimal,
+        market: &dyn MarketProfile,
+    ) -> Result<Decimal, String> {
+        let retirement_age = self.retirement_age(market);
+        if let Some(account) = self.finances.accounts.iter().find(|a| a.id == id) {
+            if matches!(account.kind, AccountKind::Retirement { .. })
+                && self.player.age < retirement_age
+            {
+                return Err(format!(
+                    "{} is locked until retirement at age {}",
+                    account.name, retirement_age
+                ));
+            }
+        }
+        let month = self.months_elapsed();
+        self.finances
+            .withdraw_from_account(id, amount, market, month)
+    }
+
+    fn date_account_opening(&mut self, id: &str) {
+        let month = self.months_elapsed();
+        if let Some(account) = self.finances.accounts.iter_mut().find(|a| a.id == id) {
+            account.opened_month = month;
+        }
+    }
+
+    /// Takes a job and replaces any previous salary income with its own
+    /// A first job also grants starting cash (a share of the salary set by difficulty,
+    /// added to any savings) and the market's minimum food budget.
+    pub fn accept_job(&mut self, job: Job, is_first_job: bool, market: &dyn MarketProfile) {
+        if is_first_job {
+            self.finances.cash += self.difficulty.starting_cash(job.monthly_salary);
+            self.finances
+                .set_budget(ExpenseCategory::Essential, market.essential_minimum());
+        }
+
+        self.finances
+            .income_sources
+            .retain(|inc| !inc.id.starts_with("job_"));
+        self.finances.income_sources.push(Income::new(
+            format!("job_{}", job.id),
+            job.title.clone(),
+            IncomeKind::Employment,
+            job.monthly_salary,
+        ));
+        self.career.accept_job(job);
+        self.update_commute_expense(market);
+    }
+
+    /// Quits the current job and removes its salary income
+    pub fn quit_job(&mut self) {
+        self.career.quit_job();
+        self.finances
+            .income_sources
+            .retain(|inc| !inc.id.starts_with("job_"));
+        self.end_commute();
+    }
 
+    /// Leaves the current job for a planned career break
+    pub fn start_sabbatical(&mut self, months: u8) -> Result<(), String> {
+        self.career.start_sabbatical(months)?;
+        self.finances
+            .income_sources
+            .retain(|inc| !inc.id.starts_with("job_"));
+        self.end_commute();
         Ok(())
     }
 
+    /// Keeps the commute expense in line with the housing location
+    /// Only charged while employed and housed; call after changing either.
+    pub fn update_commute_expense(&mut self, market: &dyn MarketProfile) {
+        self.end_commute();
+        if self.career.current_job.is_none() {
+            return;
+        }
+        if let Some(housing) = &self.housing {
+            self.finances.add_expense(Expense::new(
+                "commute".to_string(),
+                format!("Commute from {}", housing.location.name()),
+                ExpenseCategory::Transportation,
+                housing.location.commute_cost(market),
+            ));
+        }
+    }
+
+    /// Drops the commute expense, e.g. once the player stops working
+    fn end_commute(&mut self) {
+        self.finances.expenses.retain(|e| e.id != "commute");
+        self.finances.invalidate_cache();
+    }
+
     /// Advances housing counter when month advances
-    pub fn advance_housing_month(&mut self) {
-        if self.housing.is_some() {
-            self.months_at_housing += 1;
+    /// Every full year of tenancy the landlord raises the rent by inflation
+    /// plus `LANDLORD_RENT_PREMIUM`.
+    pub fn advance_housing_month(&mut self, market: &dyn MarketProfile) {
+        let Some(privacy_impact) = self
+            .housing
+            .as_ref()
+            .map(|h| h.housing_type.privacy_impact())
+        else {
+            return;
+        };
+        self.months_at_housing += 1;
+        self.track_wellbeing(WellbeingFactor::Housing, |player| {
+            player.adjust_happiness(privacy_impact)
+        });
+        let Some(housing) = self.housing.as_mut() else {
+            return;
+        };
+
+        if self.months_at_housing.is_multiple_of(12) {
+            housing.raise_rent(market.inflation_rate() + LANDLORD_RENT_PREMIUM);
+            let expense_id = format!("housing_{}", housing.id);
+            let total = housing.total_monthly_cost();
+            if let Some(expense) = self
+                .finances
+                .expenses
+                .iter_mut()
+                .find(|e| e.id == expense_id)
+            {
+                expense.monthly_amount = total;
+            }
+            self.finances.invalidate_cache();
         }
     }
 
     /// Advances to the next phase
-    pub fn advance_phase(&mut self) {
+    /// In retirement each call settles one drawdown month instead.
+    pub fn advance_phase(&mut self, market: &dyn MarketProfile) -> Result<(), String> {
+        if self.phase.is_retirement() {
+            return self.process_retirement_month(market);
+        }
+        if self.phase.is_bankrupt() {
+            return Err("The game is over: you went bankrupt".to_string());
+        }
+
         let prev_phase = self.phase;
         self.phase = self.phase.next();
 
+        // The first day's bills are due as soon as the month starts
+        if let GamePhase::Execution { current_day } = self.phase {
+            self.accrue_day(current_day, market)?;
+        }
+
         // If we just moved from Review to Planning, advance the month
         if prev_phase.is_review() && self.phase.is_planning() {
-            self.time.advance_month();
-            self.finances.reset_monthly_budget();
-            self.career.advance_month();
-            self.advance_housing_month();
+            self.advance_month(market)?;
+
+            if self.player.age >= self.retirement_age(market) {
+                self.retire(market);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Plays the given number of months without player input
+    /// Jobs, budgets and investments stay as they are; stops early if the
+    /// player goes bankrupt or retires.
+    pub fn fast_forward(&mut self, months: u32, market: &dyn MarketProfile) -> Result<(), String> {
+        if !self.phase.is_planning() {
+            return Err("Can only fast-forward from the planning phase".to_string());
+        }
+        for _ in 0..months {
+            self.advance_phase(market)?;
+            while self.phase.is_execution() {
+                self.advance_execution_day(market)?;
+            }
+            if !self.phase.is_review() {
+                break;
+            }
+            self.advance_phase(market)?;
+            if !self.phase.is_planning() {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// Moves the calendar and all monthly counters forward
+    fn advance_month(&mut self, market: &dyn MarketProfile) -> Result<(), String> {
+        self.month_events.clear();
+        self.time.advance_month();
+        self.finances.reset_monthly_budget();
+        self.career.advance_month();
+        self.advance_housing_month(market);
+        self.advance_economy_month(market);
+
+        // Age player, settle the tax year and apply a year of inflation if year changed
+        if self.time.month.value() == 1 {
+            self.player.age_one_year();
+            self.finances.settle_capital_gains(market)?;
+            // Roční zúčtování: must run before this year's contributions are reset
+            let refund = self.finances.income_tax_refund(market)?;
+            self.finances.tax_year = TaxYear::default();
+            if refund != Decimal::ZERO {
+                self.apply_event(Event::TaxSettlement { refund });
+            }
+            for account in self.finances.accounts.iter_mut() {
+                account.contributions_this_year = Decimal::ZERO;
+            }
+            // Gains from rebalancing fall into the new tax year
+            self.finances.rebalance_accounts(self.months_elapsed());
+            self.cumulative_inflation = (Decimal::ONE + self.cumulative_inflation)
+                * (Decimal::ONE + market.inflation_rate())
+                - Decimal::ONE;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the long-run annual return investments are expected to earn
+    pub fn expected_annual_return(&self, market: &dyn MarketProfile) -> Decimal {
+        market.expected_equity_return() + self.difficulty.return_adjustment()
+    }
+
+    /// Projects net worth over `years` with the market's return and volatility
+    /// Uses a copy of the game's generator, so the game itself is unaffected.
+    pub fn project_net_worth(
+        &self,
+        market: &dyn MarketProfile,
+        years: u32,
+        monthly_contribution: Decimal,
+        iterations: u32,
+    ) -> Result<ProjectionResult, String> {
+        self.finances.project_net_worth(
+            years,
+            self.expected_annual_return(market),
+            market.equity_volatility(),
+            monthly_contribution,
+            iterations,
+            &mut self.rng.clone(),
+        )
+    }
+
+    /// Moves the business cycle forward and applies its effects:
+    /// investment returns, anniversary raises and layoffs
+    fn advance_economy_month(&mut self, market: &dyn MarketProfile) {
+        self.economy.advance_month(&mut self.rng);
+
+        let (equity_return, bond_return) = self.economy.sample_sleeve_returns(
+            self.expected_annual_return(market),
+            market.equity_volatility(),
+            market.expected_bond_return(),
+            &mut self.rng,
+        );
+        let monthly_interest = self.economy.savings_interest_rate() / Decimal::from(12);
+        // Taxable accounts pay their dividends out as cash (see `collect_dividends`),
+        // so only the price return stays invested; retirement accounts reinvest them
+        let price_return = equity_return - market.equity_dividend_yield() / Decimal::from(12);
+        for account in self.finances.accounts.iter_mut() {
+            match account.kind {
+                AccountKind::Taxable => account.apply_sleeve_returns(price_return, bond_return),
+                AccountKind::Retirement { .. } => {
+                    account.apply_sleeve_returns(equity_return, bond_return)
+                }
+                AccountKind::EmergencyFund | AccountKind::SinkingFund { .. } => {
+                    account.apply_return(monthly_interest)
+                }
+            }
+        }
+        self.finances.invalidate_cache();
+
+        if self.rng.chance(MARKET_CRASH_MONTHLY_CHANCE) {
+            self.apply_event(Event::MarketCrash {
+                drop: MARKET_CRASH_DROP,
+            });
+        }
+
+        let Some(job) = self.career.current_job.as_mut() else {
+            return;
+        };
+
+        if self.career.months_in_current_job > 0
+            && self.career.months_in_current_job.is_multiple_of(12)
+        {
+            // Skilled workers earn a bit more on top of the economy-wide raise
+            let mut raise = self.economy.annual_raise();
+            if raise > Decimal::ZERO {
+                raise += self.player.skill_raise_bonus(&job.field);
+            }
+            job.monthly_salary = (job.monthly_salary * (Decimal::ONE + raise)).round_dp(0);
+            let income_id = format!("job_{}", job.id);
+            let salary = job.monthly_salary;
+            if let Some(income) = self
+                .finances
+                .income_sources
+                .iter_mut()
+                .find(|inc| inc.id == income_id)
+            {
+                income.adjust_amount(salary);
+            }
+        }
+
+        if self.rng.chance(self.economy.phase.layoff_chance()) {
+            let job_title = job.title.clone();
+            self.apply_event(Event::Layoff { job_title });
+        }
+    }
+
+    /// Returns the annual rate lenders offer the player for a new loan
+    /// The market rate plus a premium for the credit score; errors when the
+    /// score is too low to borrow at all.
+    pub fn offered_loan_rate(&self, kind: LoanKind) -> Result<Decimal, String> {
+        if !self.player.can_borrow() {
+            return Err(format!(
+                "Lenders refuse a {} with a credit score of {}",
+                kind.name().to_lowercase(),
+                self.player.credit_score
+            ));
+        }
+        Ok(self.economy.loan_rate(kind) + self.player.credit_rate_premium())
+    }
+
+    /// Takes out a loan at the offered rate, which stays locked for its term
+    pub fn take_loan(
+        &mut self,
+        id: String,
+        kind: LoanKind,
+        principal: Decimal,
+        term_months: u32,
+    ) -> Result<(), String> {
+        if self.finances.loans.iter().any(|l| l.id == id) {
+            return Err(format!("Loan '{}' already exists", id));
+        }
+        let loan = Loan::new(
+            id,
+            kind.name().to_string(),
+            kind,
+            principal,
+            self.offered_loan_rate(kind)?,
+            term_months,
+        )?;
+        self.finances.cash += principal;
+        self.finances.add_loan(loan);
+        Ok(())
+    }
+
+    /// Buys one of the offered purchases, in cash or on installments
+    /// Installments put `INSTALLMENT_DOWN_PAYMENT` down and borrow the rest at
+    /// the offered installment rate over `INSTALLMENT_MONTHS`. Returns the new
+    /// asset's ID.
+    pub fn buy_purchase(
+        &mut self,
+        option: &PurchaseOption,
+        financed: bool,
+        market: &dyn MarketProfile,
+    ) -> Result<String, String> {
+        let mut asset = Asset::new(
+            self.finances.new_asset_id(),
+            option.name.to_string(),
+            option.category.clone(),
+            option.price(market),
+            option.monthly_upkeep(market),
+        );
+        asset.acquired_month = self.months_elapsed();
+        let id = asset.id.clone();
+        if financed {
+            let rate = self.offered_loan_rate(LoanKind::Installment)?;
+            self.finances.finance_purchase(
+                asset,
+                option.down_payment(market),
+                rate,
+                INSTALLMENT_MONTHS,
+                market,
+            )?;
+        } else {
+            self.finances.buy_asset(asset, market)?;
+        }
+        Ok(id)
+    }
+
+    /// Starts the game owing a student loan
+    /// The money was spent on studies, so no cash is credited.
+    pub fn add_student_loan(
+        &mut self,
+        balance: Decimal,
+        annual_rate: Decimal,
+        term_months: u32,
+    ) -> Result<(), String> {
+        if self
+            .finances
+            .loans
+            .iter()
+            .any(|l| l.kind == LoanKind::Student)
+        {
+            return Err("Student loan already exists".to_string());
+        }
+        let loan = Loan::new(
+            "student_loan".to_string(),
+            LoanKind::Student.name().to_string(),
+            LoanKind::Student,
+            balance,
+            annual_rate,
+            term_months,
+        )?;
+        self.finances.add_loan(loan);
+        Ok(())
+    }
+
+    /// Returns net worth in start-of-game money
+    pub fn real_net_worth(&self) -> Decimal {
+        self.finances.real_net_worth(self.cumulative_inflation)
+    }
+
+    /// Retires the player: leaves the job, stops employment income, starts
+    /// the state pension and switches the game into the drawdown phase
+    /// The monthly withdrawal is fixed at retirement using the 4% rule.
+    pub fn retire(&mut self, market: &dyn MarketProfile) {
+        self.retirement_withdrawal =
+            self.finances.safe_withdrawal(DEFAULT_WITHDRAWAL_RATE) / Decimal::from(12);
 
-            // Age player if year changed
-            if self.time.month.value() == 1 {
-                self.player.age_one_year();
+        let pension = self.pension_estimate(market);
+        if pension > Decimal::ZERO {
+            self.finances.add_income(Income::new(
+                "state_pension".to_string(),
+                "State Pension".to_string(),
+                IncomeKind::Passive,
+                pension,
+            ));
+        }
+
+        self.career.quit_job();
+        for income in self.finances.income_sources.iter_mut() {
+            if matches!(income.kind, IncomeKind::Employment) {
+                income.deactivate();
             }
         }
+        self.phase = GamePhase::Retirement;
+    }
+
+    /// Returns the player's year of birth
+    pub fn birth_year(&self) -> u32 {
+        self.time.year.saturating_sub(self.player.age as u32)
+    }
+
+    /// Returns the age the player retires at under the market's rules
+    pub fn retirement_age(&self, market: &dyn MarketProfile) -> u8 {
+        market.retirement_age_for(self.player.gender, self.birth_year())
+    }
+
+    /// Estimates the monthly state pension at the player's retirement age
+    /// A working player is assumed to keep contributing until then.
+    pub fn pension_estimate(&self, market: &dyn MarketProfile) -> Decimal {
+        if self.career.current_job.is_none() {
+            return market.state_pension_estimate(&self.career);
+        }
+        let mut career = self.career.clone();
+        let years_left = self.retirement_age(market).saturating_sub(self.player.age);
+        career.years_experience = career.years_experience.saturating_add(years_left);
+        market.state_pension_estimate(&career)
+    }
+
+    /// Scores (0-100) how on track the player is to retire at the player's retirement age
+    ///
+    /// Today's investments and monthly surplus are grown at the expected real
+    /// return until retirement and compared with the portfolio needed to cover
+    /// current spending (at least the survival minimum), less the estimated
+    /// state pension, at `DEFAULT_WITHDRAWAL_RATE`.
+    pub fn retirement_readiness(&self, market: &dyn MarketProfile) -> Result<u8, String> {
+        let finances = &self.finances;
+        let spending = finances.monthly_expenses().max(market.essential_minimum());
+        let gap = spending - self.pension_estimate(market);
+        if gap <= Decimal::ZERO {
+            return Ok(100);
+        }
+        let needed = gap * Decimal::from(12) / DEFAULT_WITHDRAWAL_RATE;
+
+        let gross = finances.monthly_gross_income();
+        let taxes = if gross > Decimal::ZERO {
+            finances.monthly_taxes(market)?.total
+        } else {
+            Decimal::ZERO
+        };
+        let surplus = (gross - taxes - finances.monthly_expenses()).max(Decimal::ZERO);
+        let years_left = self.retirement_age(market).saturating_sub(self.player.age) as u32;
+        let real_return = self.expected_annual_return(market) - market.inflation_rate();
+        let projected = finances.project_balance(surplus, real_return, years_left);
+
+        let score = (projected / needed * Decimal::from(100))
+            .clamp(Decimal::ZERO, Decimal::from(100))
+            .floor();
+        Ok(score.to_u8().unwrap_or(0))
+    }
+
+    /// Settles one month of retirement
+    /// The planned withdrawal is taken first; any remaining shortfall after
+    /// passive income and expenses is drawn from accounts as well
+    fn process_retirement_month(&mut self, market: &dyn MarketProfile) -> Result<(), String> {
+        self.process_monthly_finances(market)?;
+
+        let month = self.months_elapsed();
+        if self.retirement_withdrawal > Decimal::ZERO {
+            self.finances
+                .withdraw_from_accounts(self.retirement_withdrawal, market, month)?;
+        }
+
+        // Cover any remaining shortfall after capital gains tax
+        if self.finances.cash < Decimal::ZERO {
+            let shortfall = -self.finances.cash;
+            self.finances
+                .withdraw_net_from_accounts(shortfall, market, month)?;
+        }
+
+        self.advance_month(market)
     }
 
     /// Advances one day during Execution phase
     pub fn advance_execution_day(&mut self, market: &dyn MarketProfile) -> Result<(), String> {
+        // Weekends restore some happiness
+        if matches!(self.phase, GamePhase::Execution { .. }) && self.time.is_weekend() {
+            self.track_wellbeing(WellbeingFactor::WeekendRest, PlayerStats::weekend_rest);
+        }
+
         match &mut self.phase {
             GamePhase::Execution { current_day } => {
                 if *current_day < 30 {
                     *current_day += 1;
+                    let day = *current_day;
                     self.time.advance_day();
+                    self.accrue_day(day, market)?;
+                    if let Some(event) = Event::roll_daily(
+                        &mut self.rng,
+                        self.player.health,
+                        self.difficulty,
+                        market,
+                    ) {
+                        self.apply_event(event);
+                    }
+                    if let Some((kind, amount)) =
+                        Event::roll_windfall(&mut self.rng, self.difficulty, market)
+                    {
+                        self.receive_windfall(kind, amount, market)?;
+                    }
                     Ok(())
                 } else {
                     // Month complete, process finances and transition to Review
                     self.process_monthly_finances(market)?;
-                    self.phase = GamePhase::Review;
+                    self.phase = if self.months_insolvent >= BANKRUPTCY_AFTER_MONTHS {
+                        GamePhase::Bankrupt
+                    } else {
+                        GamePhase::Review
+                    };
                     Ok(())
                 }
             }
@@ -149,32 +832,469 @@ impl GameState {
         }
     }
 
+    /// Credits a windfall after the tax it owes
+    /// A bonus is pay: it is taxed on top of the salary and reconciled with it
+    /// at the end of the year. Other windfalls are taxed under the market's
+    /// gift and winnings rules.
+    pub fn receive_windfall(
+        &mut self,
+        kind: WindfallKind,
+        amount: Decimal,
+        market: &dyn MarketProfile,
+    ) -> Result<(), String> {
+        let tax = match kind {
+            WindfallKind::Bonus => self.finances.record_annual_bonus(amount, market)?,
+            WindfallKind::Inheritance | WindfallKind::Lottery => {
+                market.windfall_tax(kind, amount)?
+            }
+        };
+        self.apply_event(Event::Windfall { kind, amount, tax });
+        Ok(())
+    }
+
+    /// Pays in or out whatever falls due on the given day of the month
+    /// The month-end settlement then only books the remainder.
+    fn accrue_day(&mut self, day: u8, market: &dyn MarketProfile) -> Result<(), String> {
+        let mut flow = self.finances.cash_flow_on_day(day, market)?;
+        if day == DEFAULT_PAYDAY {
+            flow += self.partner_contribution(market)?;
+        }
+        self.finances.cash += flow;
+        self.month_cash_accrued += flow;
+        Ok(())
+    }
+
+    /// Applies an event's financial effects and records it for the month
+    pub fn apply_event(&mut self, event: Event) {
+        match &event {
+            Event::Emergency { kind, cost } => {
+                let out_of_pocket = self.finances.out_of_pocket(*kind, *cost);
+                self.finances.pay_emergency(out_of_pocket);
+                // Booked as a one-month expense for the ledger; it is already
+                // paid, so settlement must not take it out of cash again
+                if out_of_pocket > Decimal::ZERO {
+                    self.finances.add_expense(Expense {
+                        due_day: self.time.day,
+                        months_remaining: Some(1),
+                        ..Expense::new(
+                            format!("emergency_{}", self.month_events.len()),
+                            kind.name().to_string(),
+                            kind.expense_category(),
+                            out_of_pocket,
+                        )
+                    });
+                    self.month_cash_accrued -= out_of_pocket;
+                }
+            }
+            Event::Layoff { .. } => {
+                self.quit_job();
+                self.track_wellbeing(WellbeingFactor::LifeEvents, |player| {
+                    player.adjust_happiness(-10)
+                });
+            }
+            Event::TaxSettlement { refund } => {
+                self.finances.cash += *refund;
+            }
+            Event::Windfall { amount, tax, .. } => {
+                let net = *amount - *tax;
+                // A big enough lump sum tempts the player to upgrade their lifestyle
+                let monthly_expenses = self.finances.monthly_expenses();
+                if !self.player.frugality_enabled
+                    && net >= monthly_expenses * WINDFALL_CREEP_THRESHOLD_MONTHS
+                {
+                    let lifestyle = self
+                        .finances
+                        .budget
+                        .get(&ExpenseCategory::Lifestyle)
+                        .map(|b| b.allocated)
+                        .unwrap_or(Decimal::ZERO);
+                    self.finances.set_budget(
+                        ExpenseCategory::Lifestyle,
+                        (lifestyle + net * WINDFALL_LIFESTYLE_CREEP).round_dp(0),
+                    );
+                }
+                self.finances.cash += net;
+                self.track_wellbeing(WellbeingFactor::LifeEvents, |player| {
+                    player.adjust_happiness(5)
+                });
+            }
+            Event::MarketCrash { drop } => {
+                // Only stocks fall; bonds and savings are insulated
+                for account in self.finances.accounts.iter_mut().filter(|a| {
+                    matches!(
+                        a.kind,
+                        AccountKind::Taxable | AccountKind::Retirement { .. }
+                    )
+                }) {
+                    account.apply_sleeve_returns(*drop, Decimal::ZERO);
+                }
+                self.finances.invalidate_cache();
+                // Markets tend to bounce back after a crash
+                self.economy.enter_phase(EconomyPhase::Recovery);
+            }
+            Event::ChildBorn { cost, monthly_cost } => {
+                self.finances.cash -= *cost;
+                self.finances.invalidate_cache();
+                self.player.dependents = self.player.dependents.saturating_add(1);
+                self.track_wellbeing(WellbeingFactor::LifeEvents, |player| {
+                    player.adjust_happiness(10)
+                });
+                self.update_dependent_expenses(*monthly_cost);
+            }
+        }
+        self.month_events.push(event);
+    }
+
+    /// Has a child: one-time costs now, higher household expenses after
+    pub fn have_child(&mut self, market: &dyn MarketProfile) {
+        self.apply_event(Event::ChildBorn {
+            cost: market.scale_czk(CHILD_BIRTH_COST),
+            monthly_cost: market.scale_czk(CHILD_MONTHLY_COST),
+        });
+    }
+
+    /// Marries a partner: wedding costs, a happiness boost and shared household costs
+    pub fn marry(&mut self, partner: Partner, market: &dyn MarketProfile) -> Result<(), String> {
+        if self.partner.is_some() {
+            return Err("Already married".to_string());
+        }
+
+        let wedding_cost = market.scale_czk(WEDDING_COST);
+        if self.finances.cash < wedding_cost {
+            let currency = market.currency();
+            return Err(format!(
+                "Cannot afford the wedding costs of {} (you have {})",
+                currency.format(wedding_cost),
+                currency.format(self.finances.cash)
+            ));
+        }
+
+        self.finances.cash -= wedding_cost;
+        self.track_wellbeing(WellbeingFactor::LifeEvents, |player| {
+            player.adjust_happiness(15)
+        });
+        self.finances.add_expense(Expense::new(
+            "partner_shared".to_string(),
+            "Shared Household Costs".to_string(),
+            ExpenseCategory::Essential,
+            market.scale_czk(PARTNER_SHARED_COST),
+        ));
+        self.partner = Some(partner);
+        Ok(())
+    }
+
+    /// Returns the household's gross monthly income (player and partner)
+    pub fn household_gross_income(&self) -> Decimal {
+        let partner_gross = self
+            .partner
+            .as_ref()
+            .map(|p| p.gross_monthly)
+            .unwrap_or(Decimal::ZERO);
+        self.finances.monthly_gross_income() + partner_gross
+    }
+
+    /// Returns the partner's net monthly contribution to the household
+    ///
+    /// With joint finances the partner's whole net income is pooled and the
+    /// couple's taxes are filed jointly (so spouse allowances apply). With
+    /// separate finances the partner only pays half of the essential costs.
+    pub fn partner_contribution(&self, market: &dyn MarketProfile) -> Result<Decimal, String> {
+        let partner = match &self.partner {
+            Some(partner) => partner,
+            None => return Ok(Decimal::ZERO),
+        };
+
+        let months = Decimal::from(12);
+        match partner.finances {
+            HouseholdFinances::Joint => {
+                let player_annual = self.finances.monthly_employment_gross() * months;
+                let partner_annual = partner.gross_monthly * months;
+                let individual = market.calculate_income_tax(player_annual)?;
+                let joint = market.calculate_joint_income_tax(player_annual, partner_annual)?;
+                // Partner's share of the joint bill (can be negative with a spouse credit)
+                let partner_tax = (joint.total - individual.total) / months;
+                Ok(partner.gross_monthly - partner_tax)
+            }
+            HouseholdFinances::Separate => {
+                let partner_net = if partner.gross_monthly > Decimal::ZERO {
+                    partner.gross_monthly
+                        - market
+                            .calculate_monthly_payroll_tax(partner.gross_monthly)?
+                            .total
+                } else {
+                    Decimal::ZERO
+                };
+                let half_essentials = self.finances.monthly_essential_expenses() / Decimal::from(2);
+                Ok(half_essentials.min(partner_net))
+            }
+        }
+    }
+
+    /// Keeps the recurring child-raising expense in line with the number of dependents
+    fn update_dependent_expenses(&mut self, per_child: Decimal) {
+        self.finances.expenses.retain(|e| e.id != "dependents");
+        self.finances.invalidate_cache();
+        if self.player.dependents > 0 {
+            self.finances.add_expense(Expense::new(
+                "dependents".to_string(),
+                format!("Children ({})", self.player.dependents),
+                ExpenseCategory::Essential,
+                per_child * Decimal::from(self.player.dependents),
+            ));
+        }
+    }
+
     /// Processes monthly financial settlement
     /// Calculates income after taxes, subtracts expenses, and updates cash balance
     fn process_monthly_finances(&mut self, market: &dyn MarketProfile) -> Result<(), String> {
+        // Year-end bonus arrives with December's pay
+        let (bonus, bonus_tax) = match &self.career.current_job {
+            Some(job) if self.time.month.value() == 12 && job.annual_bonus() > Decimal::ZERO => {
+                let bonus = job.annual_bonus();
+                (bonus, self.finances.record_annual_bonus(bonus, market)?)
+            }
+            _ => (Decimal::ZERO, Decimal::ZERO),
+        };
+
         // Calculate gross monthly income
-        let gross_income = self.finances.monthly_gross_income();
+        let gross_income = self.finances.monthly_gross_income() + bonus;
 
-        // Calculate net income after taxes
-        let net_income = if gross_income > Decimal::ZERO {
-            let tax_breakdown = market.calculate_income_tax(gross_income)?;
-            gross_income - tax_breakdown.total
+        // Calculate net income after taxes (freelance income uses its own regime)
+        let taxes = if gross_income > bonus {
+            self.finances.monthly_taxes(market)?.total + bonus_tax
         } else {
-            Decimal::ZERO
+            bonus_tax
         };
+        let net_income = gross_income - taxes;
 
         // Calculate total expenses
         let total_expenses = self.finances.monthly_expenses();
 
+        // Partner's share of the household budget
+        let partner_contribution = self.partner_contribution(market)?;
+
         // Calculate net cash flow (income after tax minus expenses)
-        let net_cash_flow = net_income - total_expenses;
+        let net_cash_flow = net_income + partner_contribution - total_expenses;
+
+        // Update cash balance; an overdraft is covered by the emergency fund
+        // first and charged interest on whatever is left. Retirees cover the
+        // gap with their drawdown right after, so they are left alone here.
+        self.finances.cash += net_cash_flow - self.month_cash_accrued;
+        self.month_cash_accrued = Decimal::ZERO;
+        let mut overdraft_interest = Decimal::ZERO;
+        if !self.phase.is_retirement() {
+            self.finances.cover_overdraft();
+            let payments_due = self.finances.monthly_loan_payments()
+                + self.finances.monthly_card_payments()
+                > Decimal::ZERO;
+            self.player
+                .record_credit_month(payments_due, self.finances.cash < Decimal::ZERO);
+            overdraft_interest = self
+                .finances
+                .charge_overdraft_interest(self.economy.overdraft_rate());
+            self.months_insolvent = if self.finances.is_deeply_insolvent() {
+                self.months_insolvent + 1
+            } else {
+                0
+            };
+        }
+        let total_expenses = total_expenses + overdraft_interest;
+        let net_cash_flow = net_cash_flow - overdraft_interest;
+        self.finances.record_payroll_month(market)?;
+        self.finances.collect_dividends(market);
+        self.finances.amortize_loans();
+        self.finances.settle_credit_cards();
+        self.finances.settle_temporary_expenses();
+
+        // Share of the household's take-home pay left over after spending
+        let household_income = net_income + partner_contribution;
+        let savings_rate = if household_income > Decimal::ZERO {
+            (net_cash_flow / household_income * Decimal::from(100)).round_dp(2)
+        } else {
+            Decimal::ZERO
+        };
+
+        self.history.push(MonthlySnapshot {
+            time: self.time,
+            gross_income,
+            net_income: household_income,
+            expenses: total_expenses,
+            cash_flow: net_cash_flow,
+            net_worth: self.finances.net_worth(),
+            taxes,
+            cash: self.finances.cash,
+            happiness: self.player.happiness,
+            burnout: self.player.burnout,
+            savings_rate,
+            wellbeing: WellbeingDelta::default(),
+        });
+
+        self.unlock_achievements(market);
+
+        // Raising children is tiring
+        if self.player.dependents > 0 {
+            let strain = self.player.dependents.min(5) as i8;
+            self.track_wellbeing(WellbeingFactor::Children, |player| {
+                player.adjust_burnout(strain)
+            });
+        }
 
-        // Update cash balance
-        self.finances.cash += net_cash_flow;
+        // Food quality affects happiness
+        if let Some(food_budget) = self.finances.food_budget() {
+            let survival_minimum = market.essential_minimum();
+            self.track_wellbeing(WellbeingFactor::FoodQuality, |player| {
+                player.apply_food_quality(food_budget, survival_minimum)
+            });
+        }
+
+        // Discretionary spending buys some happiness, most of all on Lifestyle
+        let spending: Vec<_> = ExpenseCategory::all()
+            .into_iter()
+            .filter(ExpenseCategory::is_discretionary)
+            .map(|category| {
+                let spent = self.finances.monthly_category_spending(&category);
+                (category, spent)
+            })
+            .collect();
+        let happiness_unit = market.scale_czk(PURCHASE_HAPPINESS_UNIT);
+        self.track_wellbeing(WellbeingFactor::Purchases, |player| {
+            player.apply_purchases(&spending, happiness_unit)
+        });
+
+        // Work wears the player down; self-care helps
+        let health_spending = self
+            .finances
+            .monthly_category_spending(&ExpenseCategory::Health);
+        let lifestyle_spending = self
+            .finances
+            .monthly_category_spending(&ExpenseCategory::Lifestyle);
+        let healthy_budget = market.scale_czk(HEALTHY_LIVING_BUDGET);
+        let leisure_budget = market.scale_czk(LEISURE_BUDGET);
+        let job = self.career.current_job.clone();
+        self.track_wellbeing(WellbeingFactor::WorkStress, |player| {
+            player.apply_work_month(
+                job.as_ref(),
+                health_spending,
+                lifestyle_spending,
+                healthy_budget,
+                leisure_budget,
+            )
+        });
+
+        // Health follows Health spending, burnout and diet
+        self.player
+            .apply_health_month(health_spending, healthy_budget);
+
+        // Education and time on the job build skill in the career field
+        let field = self
+            .career
+            .current_job
+            .as_ref()
+            .or(self.career.job_history.last())
+            .map(|j| j.field.clone());
+        let education_spending = self
+            .finances
+            .monthly_category_spending(&ExpenseCategory::Education);
+        if education_spending > Decimal::ZERO {
+            self.player.invest_human_capital(
+                education_spending,
+                field.as_ref(),
+                market.scale_czk(EDUCATION_COST_PER_SKILL_POINT),
+            );
+        }
+        if self.career.is_on_sabbatical() {
+            self.track_wellbeing(WellbeingFactor::Sabbatical, |player| {
+                player.adjust_burnout(-SABBATICAL_BURNOUT_RECOVERY)
+            });
+            if let Some(field) = field.filter(|_| education_spending > Decimal::ZERO) {
+                self.player.gain_skill(&field, SABBATICAL_STUDY_SKILL);
+            }
+        }
+        if let Some(job) = &self.career.current_job {
+            self.player
+                .gain_skill(&job.field, ON_THE_JOB_SKILL_PER_MONTH);
+        }
+
+        self.player.update_peace_trend();
+        if let Some(snapshot) = self.history.last_mut() {
+            snapshot.wellbeing = std::mem::take(&mut self.month_wellbeing);
+        }
 
         Ok(())
     }
 
+    /// Applies a change to the player's well-being and records its net effect
+    /// (after clamping) under the given factor for this month's ledger
+    fn track_wellbeing(&mut self, factor: WellbeingFactor, effect: impl FnOnce(&mut PlayerStats)) {
+        let (happiness, burnout) = (self.player.happiness, self.player.burnout);
+        effect(&mut self.player);
+        self.month_wellbeing.record(
+            factor,
+            self.player.happiness as i16 - happiness as i16,
+            self.player.burnout as i16 - burnout as i16,
+        );
+    }
+
+    /// Records any achievements whose condition is reached for the first time
+    fn unlock_achievements(&mut self, market: &dyn MarketProfile) {
+        let reached: Vec<Achievement> = Achievement::all()
+            .into_iter()
+            .filter(|a| !self.achievements.contains(a) && a.is_reached(self, market))
+            .collect();
+        self.achievements.extend(reached);
+    }
+
+    /// Returns the first tutorial step the player hasn't done yet
+    /// None when guided mode is off or every step is complete.
+    pub fn tutorial_hint(&self) -> Option<TutorialStep> {
+        if !self.tutorial_enabled {
+            return None;
+        }
+        TutorialStep::all()
+            .into_iter()
+            .find(|step| !step.is_complete(self))
+    }
+
+    /// Returns the default goals and whether each is currently met
+    pub fn goal_progress(&self, market: &dyn MarketProfile) -> Vec<(Goal, bool)> {
+        Goal::default_set(market)
+            .into_iter()
+            .map(|goal| {
+                let met = goal.is_met(self);
+                (goal, met)
+            })
+            .collect()
+    }
+
+    /// Returns net cash flow for every settled month, for plotting
+    pub fn cash_flow_series(&self) -> Vec<(GameTime, Decimal)> {
+        self.history.iter().map(|s| (s.time, s.cash_flow)).collect()
+    }
+
+    /// Returns the savings rate (percent) of every settled month, for plotting
+    pub fn savings_rate_series(&self) -> Vec<(GameTime, Decimal)> {
+        self.history
+            .iter()
+            .map(|s| (s.time, s.savings_rate))
+            .collect()
+    }
+
+    /// Returns net worth at the end of every settled month, for plotting
+    pub fn net_worth_series(&self) -> Vec<(GameTime, Decimal)> {
+        self.history.iter().map(|s| (s.time, s.net_worth)).collect()
+    }
+
+    /// Exports the monthly history as CSV, one row per settled month
+    pub fn history_to_csv(&self) -> String {
+        let mut csv = String::from(MonthlySnapshot::CSV_HEADER);
+        csv.push('\n');
+        for snapshot in &self.history {
+            csv.push_str(&snapshot.to_csv_row());
+            csv.push('\n');
+        }
+        csv
+    }
+
     /// Returns months elapsed since game start
     pub fn months_elapsed(&self) -> u32 {
         self.time.total_months(self.start_year)
@@ -191,15 +1311,100 @@ impl GameState {
     }
 
     /// Imports game state from JSON
+    /// Saves from retired markets are moved per `MARKET_MIGRATIONS`; saves
+    /// for a market that isn't compiled in are rejected rather than played
+    /// under another market's rules.
     pub fn from_json(json: &str) -> Result<Self, String> {
-        serde_json::from_str(json).map_err(|e| e.to_string())
+        let mut state: GameState = serde_json::from_str(json).map_err(|e| e.to_string())?;
+        state.migrate_market(MARKET_MIGRATIONS);
+        state.validate().map_err(|errors| {
+            errors
+                .iter()
+                .map(|e| e.to_string())
+                .collect::<Vec<String>>()
+                .join("; ")
+        })?;
+        Ok(state)
     }
-}
 
-#[cfg(test)]
+    /// Checks the state for inconsistencies a corrupt save could contain
+    /// Returns every problem found, not just the first.
+    pub fn validate(&self) -> Result<(), Vec<FinError>> {
+        let mut errors = Vec::new();
+
+        if market_profile(&self.market_id).is_none() {
+            errors.push(FinError::UnknownMarket(self.market_id.clone()));
+        }
+        if !(MIN_PLAYER_AGE..=MAX_PLAYER_AGE).contains(&self.player.age) {
+            errors.push(FinError::InvalidAge(self.player.age));
+        }
+        if let GamePhase::Execution { current_day } = self.phase {
+            if !(1..=LAST_DAY_OF_MONTH).contains(&current_day) {
+                errors.push(FinError::InvalidDay(current_day));
+            }
+        }
+        for allocation in self.finances.budget.values() {
+            if allocation.allocated < Decimal::ZERO {
+                errors.push(FinError::NegativeBudget(
+                    allocation.category.clone(),
+                    allocation.allocated,
+                ));
+            }
+        }
+        for expense in &self.finances.expenses {
+            if expense.monthly_amount < Decimal::ZERO {
+                errors.push(FinError::NegativeExpense(expense.id.clone()));
+            }
+        }
+        let job_income = self
+            .career
+            .current_job
+            .as_ref()
+            .map(|job| format!("job_{}", job.id));
+        for income in &self.finances.income_sources {
+            if income.active
+                && income.id.starts_with("job_")
+                && Some(&income.id) != job_income.as_ref()
+            {
+                errors.push(FinError::OrphanedJobIncome(income.id.clone()));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Moves a save off a retired market according to the migration table
+    /// Returns true if the market changed.
+    pub fn migrate_market(&mut self, migrations: &[(&str, &str)]) -> bool {
+        match migrations.iter().find(|(from, _)| *from == self.market_id) {
+            Some((_, to)) => {
+                self.market_id = to.to_string();
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::accounts::{Account, AccountKind, Allocation, Asset, AssetCategory};
+    use crate::core::career::{CareerField, Job, JobLevel};
+    use crate::core::economy::EQUITY_DIVIDEND_YIELD;
+    use crate::core::events::{EmergencyKind, WindfallKind};
+    use crate::core::housing::{HousingType, LocationQuality};
+    use crate::core::insurance::{Insurance, InsuranceKind};
+    use crate::core::loans::STUDENT_LOAN_TERM_MONTHS;
+    use crate::core::player::Gender;
+    use crate::core::purchases::PURCHASE_OPTIONS;
+    use crate::core::time::Month;
     use crate::markets::czech::CzechMarket;
+    use rust_decimal_macros::dec;
 
     #[test]
     fn test_game_state_creation() {
@@ -224,20 +1429,21 @@ mod tests {
     fn test_phase_transitions() {
         let mut state =
             GameState::new("save1".to_string(), "czech".to_string(), None, 25, 2024).unwrap();
+        let market = CzechMarket;
 
         assert!(state.phase.is_planning());
         assert_eq!(state.time.month.value(), 1);
 
         // Planning -> Execution
-        state.advance_phase();
+        state.advance_phase(&market).unwrap();
         assert!(state.phase.is_execution());
 
         // Execution -> Review
-        state.advance_phase();
+        state.advance_phase(&market).unwrap();
         assert!(state.phase.is_review());
 
         // Review -> Planning (should advance month)
-        state.advance_phase();
+        state.advance_phase(&market).unwrap();
         assert!(state.phase.is_planning());
         assert_eq!(state.time.month.value(), 2); // Advanced to February
     }
@@ -268,42 +1474,2047 @@ mod tests {
         assert!(state.phase.is_review());
     }
 
+    #[test]
+    fn test_weekends_restore_happiness() {
+        let market = CzechMarket;
+        let mut state =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 25, 2024).unwrap();
+        state.phase = GamePhase::Execution { current_day: 1 };
+
+        // January 2024 days 1-30 contain 8 weekend days
+        while state.phase.is_execution() {
+            state.advance_execution_day(&market).unwrap();
+        }
+        assert_eq!(state.player.happiness, 70 + 8 * 2);
+    }
+
+    /// Plays one month day by day, including weekends
+    fn play_full_month(state: &mut GameState, market: &dyn MarketProfile) {
+        state.advance_phase(market).unwrap();
+        while state.phase.is_execution() {
+            state.advance_execution_day(market).unwrap();
+        }
+        state.advance_phase(market).unwrap();
+    }
+
+    fn employ(state: &mut GameState, level: JobLevel) {
+        state.career.accept_job(Job::new(
+            "job".to_string(),
+            "Analyst".to_string(),
+            CareerField::Finance,
+            level,
+            dec!(50000),
+            None,
+        ));
+    }
+
+    #[test]
+    fn test_quit_job_stops_salary() {
+        let mut state =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 30, 2024).unwrap();
+        employ(&mut state, JobLevel::Mid);
+        state.finances.add_income(Income::new(
+            "job_job".to_string(),
+            "Analyst".to_string(),
+            IncomeKind::Employment,
+            dec!(50000),
+        ));
+
+        state.quit_job();
+        assert!(!state.career.is_employed());
+        assert_eq!(state.career.job_history.len(), 1);
+        assert_eq!(state.finances.monthly_gross_income(), Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_projection_uses_market_assumptions() {
+        let mut state =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 30, 2024).unwrap();
+        state.finances.cash = dec!(100000);
+        let rng_before = state.rng.clone();
+
+        let projection = state
+            .project_net_worth(&CzechMarket, 10, dec!(5000), 100)
+            .unwrap();
+        assert_eq!(state.rng, rng_before);
+        let direct = state
+            .finances
+            .project_net_worth(
+                10,
+                CzechMarket.expected_equity_return(),
+                CzechMarket.equity_volatility(),
+                dec!(5000),
+                100,
+                &mut state.rng.clone(),
+            )
+            .unwrap();
+        assert_eq!(projection, direct);
+    }
+
+    #[test]
+    fn test_accept_first_job_and_switch() {
+        let mut state =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 25, 2024).unwrap();
+        let analyst = Job::new(
+            "analyst".to_string(),
+            "Analyst".to_string(),
+            CareerField::Finance,
+            JobLevel::Junior,
+            dec!(40000),
+            None,
+        );
+        assert!(state.is_first_job());
+        state.accept_job(analyst, true, &CzechMarket);
+        assert_eq!(state.finances.cash, dec!(20000));
+        assert_eq!(
+            state.finances.budget[&ExpenseCategory::Essential].allocated,
+            CzechMarket.essential_minimum()
+        );
+        assert_eq!(state.finances.monthly_gross_income(), dec!(40000));
+
+        // Switching keeps savings and replaces the old salary
+        state.finances.cash = dec!(75000);
+        let manager = Job::new(
+            "manager".to_string(),
+            "Manager".to_string(),
+            CareerField::Finance,
+            JobLevel::Senior,
+            dec!(70000),
+            None,
+        );
+        assert!(!state.is_first_job());
+        state.accept_job(manager, false, &CzechMarket);
+        assert_eq!(state.finances.cash, dec!(75000));
+        assert_eq!(state.finances.income_sources.len(), 1);
+        assert_eq!(state.finances.income_sources[0].id, "job_manager");
+        assert_eq!(state.finances.monthly_gross_income(), dec!(70000));
+        assert_eq!(state.career.job_history.len(), 1);
+    }
+
+    #[test]
+    fn test_burnout_depends_on_job_and_self_care() {
+        let market = CzechMarket;
+        let mut lead =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 35, 2024).unwrap();
+        let mut entry = lead.clone();
+        employ(&mut lead, JobLevel::Lead);
+        employ(&mut entry, JobLevel::Entry);
+        entry
+            .finances
+            .set_budget(ExpenseCategory::Health, dec!(1500));
+
+        for _ in 0..12 {
+            play_full_month(&mut lead, &market);
+            play_full_month(&mut entry, &market);
+        }
+
+        assert!(lead.player.burnout > 50);
+        assert!(entry.player.burnout <= 20);
+    }
+
     #[test]
     fn test_year_progression() {
         let mut state =
             GameState::new("save1".to_string(), "czech".to_string(), None, 25, 2024).unwrap();
+        let market = CzechMarket;
 
         assert_eq!(state.player.age, 25);
 
         // Advance through 12 months
         for _ in 0..12 {
             state.phase = GamePhase::Review; // Set to review
-            state.advance_phase(); // Back to planning, advances month
+            state.advance_phase(&market).unwrap(); // Back to planning, advances month
         }
 
         // Should be in 2025, player should be 26
         assert_eq!(state.time.year, 2025);
         assert_eq!(state.player.age, 26);
+
+        // One year of Czech inflation applied
+        assert_eq!(state.cumulative_inflation, dec!(0.025));
+        state.finances.cash = dec!(102500);
+        assert_eq!(state.real_net_worth(), dec!(100000));
+    }
+
+    /// Plays one full month (execution settlement, then back to planning)
+    fn play_month(state: &mut GameState, market: &dyn MarketProfile) {
+        state.phase = GamePhase::Execution { current_day: 30 };
+        state.advance_execution_day(market).unwrap();
+        state.advance_phase(market).unwrap();
     }
 
     #[test]
-    fn test_serialization() {
-        let state = GameState::new(
-            "save1".to_string(),
-            "czech".to_string(),
-            Some("Bob".to_string()),
-            30,
-            2024,
-        )
-        .unwrap();
+    fn test_food_quality_happiness() {
+        let market = CzechMarket;
+        let mut frugal =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 25, 2024).unwrap();
+        let mut foodie = frugal.clone();
 
-        let json = state.to_json().unwrap();
-        assert!(json.contains("Bob"));
-        assert!(json.contains("czech"));
+        frugal
+            .finances
+            .set_budget(ExpenseCategory::Essential, dec!(3500));
+        foodie
+            .finances
+            .set_budget(ExpenseCategory::Essential, dec!(8000));
 
-        let restored = GameState::from_json(&json).unwrap();
-        assert_eq!(restored.player.name, Some("Bob".to_string()));
-        assert_eq!(restored.market_id, "czech");
-        assert_eq!(restored.player.age, 30);
+        for _ in 0..6 {
+            play_month(&mut frugal, &market);
+            play_month(&mut foodie, &market);
+        }
+
+        assert!(foodie.player.happiness > frugal.player.happiness);
+        assert!(frugal.player.happiness < 70);
+    }
+
+    #[test]
+    fn test_retirement_stops_employment_and_draws_down() {
+        let market = CzechMarket;
+        let mut state =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 64, 2024).unwrap();
+
+        state.finances.add_income(Income::new(
+            "job1".to_string(),
+            "Developer".to_string(),
+            IncomeKind::Employment,
+            dec!(60000),
+        ));
+        state.finances.add_expense(Expense::new(
+            "rent".to_string(),
+            "Rent".to_string(),
+            ExpenseCategory::Essential,
+            dec!(20000),
+        ));
+        let mut account = Account::new(
+            "dip".to_string(),
+            "DIP".to_string(),
+            AccountKind::Retirement {
+                account_type_id: "dip".to_string(),
+            },
+        );
+        account.deposit(dec!(1000000)).unwrap();
+        state.finances.add_account(account);
+
+        // Work until the 65th birthday (January of next year)
+        for _ in 0..12 {
+            play_month(&mut state, &market);
+        }
+        assert_eq!(state.player.age, 65);
+        assert!(state.phase.is_retirement());
+        assert!(!state.career.is_employed());
+        // No insured years in this game, so only the pension base amount is paid
+        assert_eq!(state.finances.monthly_gross_income(), dec!(4400));
+        let pension_net = dec!(4400) - state.finances.monthly_taxes(&market).unwrap().total;
+
+        // With no cash left, living costs the pension doesn't cover come out of the portfolio
+        state.finances.cash = Decimal::ZERO;
+        let before = state.finances.accounts[0].balance;
+        state.advance_phase(&market).unwrap();
+        assert!(state.phase.is_retirement());
+        // Withdrawn first, then a month of returns on the remainder
+        let growth = Decimal::ONE + state.economy.last_equity_return;
+        let expected = (before - (dec!(20000) - pension_net)) * growth;
+        assert_eq!(
+            state.finances.accounts[0].balance.round_dp(2),
+            expected.round_dp(2)
+        );
+        assert_eq!(state.finances.cash, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_health_budget_improves_health() {
+        let market = CzechMarket;
+        let mut active =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 25, 2024).unwrap();
+        let mut idle = active.clone();
+        active
+            .finances
+            .set_budget(ExpenseCategory::Health, dec!(1500));
+
+        for _ in 0..6 {
+            play_month(&mut active, &market);
+            play_month(&mut idle, &market);
+        }
+
+        assert_eq!(active.player.health, 82);
+        assert_eq!(idle.player.health, 64);
+
+        #[cfg(feature = "usa")]
+        {
+            // The same lifestyle costs far fewer dollars
+            let usa = crate::markets::usa::UsaMarket;
+            let mut active =
+                GameState::new("save2".to_string(), "usa".to_string(), None, 25, 2024).unwrap();
+            active
+                .finances
+                .set_budget(ExpenseCategory::Health, usa.scale_czk(dec!(1500)));
+
+            for _ in 0..6 {
+                play_month(&mut active, &usa);
+            }
+
+            assert_eq!(active.player.health, 82);
+        }
+    }
+
+    #[test]
+    fn test_emergency_event_recorded_for_month() {
+        let market = CzechMarket;
+        let mut state =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 25, 2024).unwrap();
+        let mut fund = Account::new(
+            "ef".to_string(),
+            "Emergency".to_string(),
+            AccountKind::EmergencyFund,
+        );
+        fund.deposit(dec!(50000)).unwrap();
+        state.finances.add_account(fund);
+        let mut calm = state.clone();
+
+        state.apply_event(Event::Emergency {
+            kind: EmergencyKind::CarRepair,
+            cost: dec!(18000),
+        });
+        assert_eq!(state.month_events.len(), 1);
+        assert_eq!(state.finances.accounts[0].balance, dec!(32000));
+        assert_eq!(state.finances.liabilities, Decimal::ZERO);
+        assert_eq!(
+            state.finances.monthly_expenses(),
+            calm.finances.monthly_expenses() + dec!(18000)
+        );
+
+        play_month(&mut state, &market);
+        play_month(&mut calm, &market);
+        assert!(state.month_events.is_empty());
+        // The repair shows in the month's expenses once, paid from the fund rather than cash
+        assert_eq!(
+            state.history[0].expenses,
+            calm.history[0].expenses + dec!(18000)
+        );
+        assert_eq!(state.finances.cash, calm.finances.cash);
+        assert_eq!(state.finances.expenses, calm.finances.expenses);
+    }
+
+    /// Total out-of-pocket cost of a year of emergencies plus premiums
+    fn year_of_emergency_costs(state: &mut GameState) -> Decimal {
+        let start = state.finances.net_worth();
+        for _ in 0..12 {
+            for _ in 0..30 {
+                if let Some(event) = Event::roll_daily(
+                    &mut state.rng,
+                    state.player.health,
+                    state.difficulty,
+                    &CzechMarket,
+                ) {
+                    state.apply_event(event);
+                }
+            }
+            state.finances.cash -= state.finances.monthly_premiums();
+        }
+        start - state.finances.net_worth()
+    }
+
+    #[test]
+    fn test_insurance_reduces_emergency_costs() {
+        let mut uninsured =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 25, 2024).unwrap();
+        uninsured.rng = GameRng::new(2024);
+        uninsured.finances.cash = dec!(500000);
+        let mut insured = uninsured.clone();
+        for (id, kind) in [
+            ("car", InsuranceKind::Car),
+            ("health", InsuranceKind::Health),
+            ("home", InsuranceKind::Household),
+        ] {
+            insured.finances.add_insurance(Insurance::new(
+                id.to_string(),
+                kind,
+                dec!(300),
+                dec!(0.8),
+            ));
+        }
+
+        // Both face the same emergencies from the same seed
+        let uninsured_cost = year_of_emergency_costs(&mut uninsured);
+        let insured_cost = year_of_emergency_costs(&mut insured);
+        assert_eq!(uninsured.month_events, insured.month_events);
+        assert!(!uninsured.month_events.is_empty());
+
+        let claims: Decimal = uninsured
+            .month_events
+            .iter()
+            .map(|event| event.cost())
+            .sum();
+        assert_eq!(uninsured_cost, claims);
+        assert_eq!(insured_cost, claims * dec!(0.2) + dec!(900) * dec!(12));
+    }
+
+    #[test]
+    fn test_children_increase_expenses() {
+        let market = CzechMarket;
+        let mut state =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 30, 2024).unwrap();
+        state.finances.cash = dec!(100000);
+
+        state.have_child(&market);
+        assert_eq!(state.player.dependents, 1);
+        assert_eq!(state.player.happiness, 80);
+        assert_eq!(state.finances.cash, dec!(70000));
+        assert_eq!(state.finances.monthly_essential_expenses(), dec!(8000));
+
+        state.have_child(&market);
+        assert_eq!(state.finances.monthly_essential_expenses(), dec!(16000));
+
+        // Settlement charges the child-raising costs and adds burnout
+        let cash_before = state.finances.cash;
+        let burnout_before = state.player.burnout;
+        play_month(&mut state, &market);
+        assert_eq!(state.finances.cash, cash_before - dec!(16000));
+        assert_eq!(state.player.burnout, burnout_before + 2);
+
+        #[cfg(feature = "usa")]
+        {
+            let usa = crate::markets::usa::UsaMarket;
+            let mut state =
+                GameState::new("save2".to_string(), "usa".to_string(), None, 30, 2024).unwrap();
+            state.finances.cash = dec!(10000);
+
+            state.have_child(&usa);
+            let birth_cost = usa.scale_czk(CHILD_BIRTH_COST);
+            assert!(birth_cost < CHILD_BIRTH_COST);
+            assert_eq!(state.finances.cash, dec!(10000) - birth_cost);
+            assert_eq!(
+                state.finances.monthly_essential_expenses(),
+                usa.scale_czk(CHILD_MONTHLY_COST)
+            );
+        }
+    }
+
+    #[test]
+    fn test_partner_income_joins_household() {
+        let market = CzechMarket;
+        let mut state =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 30, 2024).unwrap();
+        state.finances.add_income(Income::new(
+            "job1".to_string(),
+            "Developer".to_string(),
+            IncomeKind::Employment,
+            dec!(50000),
+        ));
+
+        // The wedding must be paid for up front
+        state.finances.cash = dec!(100000);
+        assert!(state
+            .marry(
+                Partner::new(None, dec!(40000), HouseholdFinances::Joint),
+                &market
+            )
+            .is_err());
+        assert_eq!(state.finances.cash, dec!(100000));
+        assert!(state.partner.is_none());
+
+        state.finances.cash = dec!(200000);
+        state
+            .marry(
+                Partner::new(None, dec!(40000), HouseholdFinances::Joint),
+                &market,
+            )
+            .unwrap();
+        assert!(state
+            .marry(
+                Partner::new(None, dec!(1), HouseholdFinances::Joint),
+                &market
+            )
+            .is_err());
+        assert_eq!(state.finances.cash, dec!(50000));
+        assert_eq!(state.household_gross_income(), dec!(90000));
+
+        // Partner's net: 40,000 minus 15% tax, 7.1% social and 4.5% health
+        assert_eq!(state.partner_contribution(&market).unwrap(), dec!(29360));
+
+        let cash_before = state.finances.cash;
+        play_month(&mut state, &market);
+        // Player's 36,700 net + partner's 29,360 - 6,000 shared costs
+        assert_eq!(state.finances.cash, cash_before + dec!(60060));
+    }
+
+    #[test]
+    fn test_joint_filing_spouse_credit() {
+        let market = CzechMarket;
+        let mut state =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 30, 2024).unwrap();
+        state.finances.add_income(Income::new(
+            "job1".to_string(),
+            "Developer".to_string(),
+            IncomeKind::Employment,
+            dec!(50000),
+        ));
+        state.finances.cash = WEDDING_COST;
+        state
+            .marry(
+                Partner::new(None, Decimal::ZERO, HouseholdFinances::Joint),
+                &market,
+            )
+            .unwrap();
+
+        // A non-earning partner brings the monthly share of the spouse credit
+        assert_eq!(state.partner_contribution(&market).unwrap(), dec!(2070));
+
+        // Separately, nothing to contribute
+        state.partner.as_mut().unwrap().finances = HouseholdFinances::Separate;
+        assert_eq!(state.partner_contribution(&market).unwrap(), Decimal::ZERO);
+    }
+
+    /// Creates a freshly retired player with the given pension savings and expenses
+    fn retiree(portfolio: Decimal, monthly_expenses: Decimal) -> GameState {
+        let mut state =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 65, 2024).unwrap();
+        state.finances.add_expense(Expense::new(
+            "living".to_string(),
+            "Living costs".to_string(),
+            ExpenseCategory::Essential,
+            monthly_expenses,
+        ));
+        let mut account = Account::new(
+            "dip".to_string(),
+            "DIP".to_string(),
+            AccountKind::Retirement {
+                account_type_id: "dip".to_string(),
+            },
+        );
+        account.deposit(portfolio).unwrap();
+        // Saved up over a career, not deducted from this year's taxes
+        account.contributions_this_year = Decimal::ZERO;
+        state.finances.add_account(account);
+        state.retire(&CzechMarket);
+        state
+    }
+
+    /// Replays a retiree's month on copies of their cash and pension account
+    /// The planned withdrawal is taken first, then any shortfall, then the
+    /// returns (and crash) the game drew for the month.
+    fn replay_retirement_month(
+        state: &GameState,
+        account: &mut Account,
+        cash: &mut Decimal,
+        net_cash_flow: Decimal,
+    ) {
+        *cash += net_cash_flow;
+        let planned = state.retirement_withdrawal.min(account.balance);
+        let shortfall = (-(*cash + planned)).max(Decimal::ZERO);
+        let taken = (planned + shortfall).min(account.balance);
+        if taken > Decimal::ZERO {
+            account.withdraw(taken).unwrap();
+            *cash += taken;
+        }
+        account.apply_sleeve_returns(
+            state.economy.last_equity_return,
+            state.economy.last_bond_return,
+        );
+        if state
+            .month_events
+            .iter()
+            .any(|e| matches!(e, Event::MarketCrash { .. }))
+        {
+            account.apply_sleeve_returns(MARKET_CRASH_DROP, Decimal::ZERO);
+        }
+    }
+
+    #[test]
+    fn test_large_portfolio_sustains_withdrawals() {
+        let market = CzechMarket;
+        // 4% of 12M = 40k/month against 20k of expenses
+        let mut state = retiree(dec!(12000000), dec!(20000));
+        assert_eq!(state.retirement_withdrawal, dec!(40000));
+        let pension_net = dec!(4400) - state.finances.monthly_taxes(&market).unwrap().total;
+
+        let mut account = state.finances.accounts[0].clone();
+        let mut cash = state.finances.cash;
+        for _ in 0..120 {
+            state.advance_phase(&market).unwrap();
+            replay_retirement_month(&state, &mut account, &mut cash, pension_net - dec!(20000));
+        }
+
+        assert_eq!(state.finances.accounts[0].balance, account.balance);
+        // Surplus of 20k/month plus the net state pension accumulates as cash
+        assert_eq!(cash, (dec!(20000) + pension_net) * dec!(120));
+        assert_eq!(state.finances.cash, cash);
+    }
+
+    #[test]
+    fn test_small_portfolio_depletes() {
+        let market = CzechMarket;
+        // 4% of 600k is 2k/month, far below 20k of expenses
+        let mut state = retiree(dec!(600000), dec!(20000));
+        let pension_net = dec!(4400) - state.finances.monthly_taxes(&market).unwrap().total;
+
+        let mut account = state.finances.accounts[0].clone();
+        let mut cash = state.finances.cash;
+        for _ in 0..60 {
+            state.advance_phase(&market).unwrap();
+            replay_retirement_month(&state, &mut account, &mut cash, pension_net - dec!(20000));
+        }
+
+        assert_eq!(state.finances.accounts[0].balance, Decimal::ZERO);
+        assert_eq!(account.balance, Decimal::ZERO);
+        assert!(cash < Decimal::ZERO);
+        assert_eq!(state.finances.cash, cash);
+    }
+
+    #[test]
+    fn test_recession_lowers_portfolio_returns() {
+        let market = CzechMarket;
+        let invested = |phase: EconomyPhase| {
+            let mut state =
+                GameState::new("save1".to_string(), "czech".to_string(), None, 30, 2024).unwrap();
+            let mut account = Account::new(
+                "broker".to_string(),
+                "Brokerage".to_string(),
+                AccountKind::Taxable,
+            );
+            account.deposit(dec!(100000)).unwrap();
+            state.finances.add_account(account);
+            state.economy = EconomyState::new(phase);
+            state.phase = GamePhase::Review;
+            state.advance_phase(&market).unwrap();
+            state.finances.accounts[0].balance
+        };
+
+        // Same seed, same market shock: only the phase differs
+        let boom = invested(EconomyPhase::Expansion);
+        let bust = invested(EconomyPhase::Recession);
+        assert!(boom > bust);
+    }
+
+    #[test]
+    fn test_taxable_accounts_pay_out_dividends_instead_of_reinvesting() {
+        let market = CzechMarket;
+        let mut state =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 30, 2024).unwrap();
+        for kind in [
+            AccountKind::Taxable,
+            AccountKind::Retirement {
+                account_type_id: "dip".to_string(),
+            },
+        ] {
+            let mut account = Account::new("inv".to_string(), "Investments".to_string(), kind);
+            account.deposit(dec!(120000)).unwrap();
+            state.finances.add_account(account);
+        }
+        state.phase = GamePhase::Review;
+        state.advance_phase(&market).unwrap();
+        assert!(state.month_events.is_empty());
+
+        // The 2% yield is paid out of the taxable account's return, kept in the other
+        let growth = Decimal::ONE + state.economy.last_equity_return;
+        let monthly_yield = EQUITY_DIVIDEND_YIELD / dec!(12);
+        assert_eq!(
+            state.finances.accounts[0].balance,
+            dec!(120000) * (growth - monthly_yield)
+        );
+        assert_eq!(state.finances.accounts[1].balance, dec!(120000) * growth);
+    }
+
+    #[test]
+    fn test_higher_rates_help_savers_and_hurt_borrowers() {
+        let market = CzechMarket;
+        let saved_after_month = |base_rate: Decimal| {
+            let mut state =
+                GameState::new("save1".to_string(), "czech".to_string(), None, 30, 2024).unwrap();
+            let mut fund = Account::new(
+                "ef".to_string(),
+                "Emergency Fund".to_string(),
+                AccountKind::EmergencyFund,
+            );
+            fund.deposit(dec!(100000)).unwrap();
+            state.finances.add_account(fund);
+            state.economy.base_interest_rate = base_rate;
+            state.phase = GamePhase::Review;
+            state.advance_phase(&market).unwrap();
+            state.finances.accounts[0].balance
+        };
+        assert!(saved_after_month(dec!(0.06)) > saved_after_month(dec!(0.02)));
+
+        let payment_at = |base_rate: Decimal| {
+            let mut state =
+                GameState::new("save1".to_string(), "czech".to_string(), None, 30, 2024).unwrap();
+            state.economy.base_interest_rate = base_rate;
+            state
+                .take_loan("home".to_string(), LoanKind::Mortgage, dec!(3000000), 360)
+                .unwrap();
+            state.finances.loans[0].monthly_payment
+        };
+        assert!(payment_at(dec!(0.06)) > payment_at(dec!(0.02)));
+    }
+
+    #[test]
+    fn test_student_debt_at_start() {
+        let debt_free =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 23, 2024).unwrap();
+        let mut graduate = debt_free.clone();
+        graduate
+            .add_student_loan(dec!(300000), dec!(0.05), STUDENT_LOAN_TERM_MONTHS)
+            .unwrap();
+
+        assert_eq!(graduate.finances.net_worth(), dec!(-300000));
+        assert!(graduate.finances.net_worth() < debt_free.finances.net_worth());
+        assert!(graduate.finances.monthly_loan_payments() > Decimal::ZERO);
+        assert_eq!(
+            graduate.finances.monthly_expenses(),
+            graduate.finances.monthly_loan_payments()
+        );
+        assert!(graduate
+            .add_student_loan(dec!(1000), dec!(0.05), 12)
+            .is_err());
+    }
+
+    #[test]
+    fn test_mortgage_rate_locked_at_origination() {
+        let market = CzechMarket;
+        let mut state =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 30, 2024).unwrap();
+        state
+            .take_loan("home".to_string(), LoanKind::Mortgage, dec!(3000000), 360)
+            .unwrap();
+        let locked = state.finances.loans[0].annual_rate;
+        let payment = state.finances.loans[0].monthly_payment;
+
+        state.economy.base_interest_rate = dec!(0.10);
+        play_month(&mut state, &market);
+
+        assert_eq!(state.finances.loans[0].annual_rate, locked);
+        assert_eq!(state.finances.loans[0].monthly_payment, payment);
+        assert!(state.finances.loans[0].balance < dec!(3000000));
+        assert!(state
+            .take_loan("home".to_string(), LoanKind::Mortgage, dec!(1000), 12)
+            .is_err());
+    }
+
+    #[test]
+    fn test_missed_payments_worsen_mortgage_rate() {
+        let market = CzechMarket;
+        let mut reliable =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 30, 2024).unwrap();
+        let mut missed = reliable.clone();
+        let fresh_premium = reliable.player.credit_rate_premium();
+
+        for state in [&mut reliable, &mut missed] {
+            state
+                .take_loan("car".to_string(), LoanKind::Personal, dec!(100000), 24)
+                .unwrap();
+        }
+        reliable.finances.add_income(Income::new(
+            "job1".to_string(),
+            "Developer".to_string(),
+            IncomeKind::Employment,
+            dec!(60000),
+        ));
+        // Without income the loan money runs out and installments go unpaid
+        missed.finances.cash = Decimal::ZERO;
+        for _ in 0..3 {
+            play_month(&mut missed, &market);
+        }
+        for _ in 0..8 {
+            play_month(&mut reliable, &market);
+        }
+
+        assert!(reliable.player.credit_score > missed.player.credit_score);
+        assert!(missed.player.credit_score < crate::core::player::STARTING_CREDIT_SCORE);
+        assert!(reliable.player.credit_rate_premium() <= fresh_premium);
+        assert!(missed.offered_loan_rate(LoanKind::Mortgage).is_err());
+
+        // A milder slip still borrows, but at a worse rate in the same economy
+        missed.economy = reliable.economy.clone();
+        missed.player.credit_score = 600;
+        let reliable_rate = reliable.offered_loan_rate(LoanKind::Mortgage).unwrap();
+        assert!(missed.offered_loan_rate(LoanKind::Mortgage).unwrap() > reliable_rate);
+    }
+
+    #[test]
+    fn test_history_series() {
+        let market = CzechMarket;
+        let mut state =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 30, 2024).unwrap();
+        state.finances.add_income(Income::new(
+            "job1".to_string(),
+            "Developer".to_string(),
+            IncomeKind::Employment,
+            dec!(50000),
+        ));
+        state.finances.add_expense(Expense::new(
+            "rent".to_string(),
+            "Rent".to_string(),
+            ExpenseCategory::Essential,
+            dec!(20000),
+        ));
+
+        for _ in 0..3 {
+            play_month(&mut state, &market);
+        }
+
+        let cash_flow = state.cash_flow_series();
+        let net_worth = state.net_worth_series();
+        assert_eq!(cash_flow.len(), 3);
+        assert_eq!(net_worth.len(), 3);
+
+        // 50,000 gross - 13,300 taxes - 20,000 rent
+        assert!(cash_flow.iter().all(|(_, flow)| *flow == dec!(16700)));
+        assert_eq!(net_worth[2].1, dec!(50100));
+        assert_eq!(cash_flow[0].0.month.value(), 1);
+        assert_eq!(cash_flow[2].0.month.value(), 3);
+    }
+
+    #[test]
+    fn test_annual_tax_refund_for_maxed_deductions() {
+        let market = CzechMarket;
+        let mut state =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 30, 2024).unwrap();
+        state.finances.add_income(Income::new(
+            "job1".to_string(),
+            "Developer".to_string(),
+            IncomeKind::Employment,
+            dec!(50000),
+        ));
+        let mut dip = Account::new(
+            "dip".to_string(),
+            "DIP".to_string(),
+            AccountKind::Retirement {
+                account_type_id: "dip".to_string(),
+            },
+        );
+        dip.deposit(dec!(48000)).unwrap();
+        state.finances.add_account(dip);
+
+        for _ in 0..11 {
+            play_month(&mut state, &market);
+            assert!(state.month_events.is_empty());
+        }
+        let cash_before = state.finances.cash;
+        play_month(&mut state, &market);
+
+        // 48k of deductions at 15% comes back after the year boundary
+        assert_eq!(state.time.month.value(), 1);
+        assert_eq!(
+            state.month_events,
+            vec![Event::TaxSettlement { refund: dec!(7200) }]
+        );
+        assert!(state.finances.cash > cash_before);
+        assert_eq!(state.finances.tax_year, TaxYear::default());
+    }
+
+    #[test]
+    fn test_no_settlement_without_deductions() {
+        let market = CzechMarket;
+        let mut state =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 30, 2024).unwrap();
+        state.finances.add_income(Income::new(
+            "job1".to_string(),
+            "Developer".to_string(),
+            IncomeKind::Employment,
+            dec!(50000),
+        ));
+
+        for _ in 0..12 {
+            play_month(&mut state, &market);
+        }
+        assert_eq!(state.time.month.value(), 1);
+        assert!(state.month_events.is_empty());
+    }
+
+    #[test]
+    fn test_rent_rises_each_year_and_resets_on_move() {
+        let market = CzechMarket;
+        let mut state =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 30, 2024).unwrap();
+        state.finances.cash = dec!(1000000);
+        let flat = Housing {
+            id: "flat".to_string(),
+            housing_type: HousingType::Studio,
+            location: LocationQuality::Average,
+            address: "Vinohrady".to_string(),
+            monthly_cost: dec!(15000),
+            monthly_utilities: dec!(3000),
+        };
+        state.change_housing(flat.clone(), &market).unwrap();
+        let housing_expense = |state: &GameState| {
+            state
+                .finances
+                .expenses
+                .iter()
+                .find(|e| e.id == "housing_flat")
+                .map(|e| e.monthly_amount)
+                .unwrap()
+        };
+
+        for _ in 0..11 {
+            play_month(&mut state, &market);
+        }
+        assert_eq!(housing_expense(&state), dec!(18000));
+
+        // 2.5% inflation + 1% premium on the rent only, twice
+        play_month(&mut state, &market);
+        assert_eq!(housing_expense(&state), dec!(18525));
+        for _ in 0..12 {
+            play_month(&mut state, &market);
+        }
+        assert_eq!(state.housing.as_ref().unwrap().monthly_cost, dec!(16068));
+        assert_eq!(housing_expense(&state), dec!(19068));
+
+        // A new lease starts at the listed rent
+        state.change_housing(flat, &market).unwrap();
+        assert_eq!(housing_expense(&state), dec!(18000));
+        assert_eq!(state.months_at_housing, 0);
+    }
+
+    #[test]
+    fn test_commute_cost_follows_location() {
+        let market = CzechMarket;
+        let mut state =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 30, 2024).unwrap();
+        state.finances.cash = dec!(1000000);
+        let home = |location| Housing {
+            id: "home".to_string(),
+            housing_type: HousingType::Studio,
+            location,
+            address: "Prague".to_string(),
+            monthly_cost: dec!(15000),
+            monthly_utilities: dec!(3000),
+        };
+        let transport = |state: &GameState| {
+            state
+                .finances
+                .monthly_category_spending(&ExpenseCategory::Transportation)
+        };
+
+        // No commute without a job
+        state
+            .change_housing(home(LocationQuality::Premium), &market)
+            .unwrap();
+        assert_eq!(transport(&state), Decimal::ZERO);
+
+        state.career.accept_job(Job::new(
+            "dev".to_string(),
+            "Developer".to_string(),
+            CareerField::Technology,
+            JobLevel::Junior,
+            dec!(50000),
+            None,
+        ));
+        state.update_commute_expense(&market);
+        let central = transport(&state);
+        assert!(central > Decimal::ZERO);
+
+        state
+            .change_housing(home(LocationQuality::Poor), &market)
+            .unwrap();
+        assert!(transport(&state) > central);
+
+        state.quit_job();
+        assert_eq!(transport(&state), Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_deposit_refunded_on_next_move() {
+        let market = CzechMarket;
+        let mut state =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 30, 2024).unwrap();
+        state.finances.cash = dec!(100000);
+        let flat = |id: &str, rent| Housing {
+            id: id.to_string(),
+            housing_type: HousingType::Studio,
+            location: LocationQuality::Average,
+            address: "Žižkov".to_string(),
+            monthly_cost: rent,
+            monthly_utilities: dec!(3000),
+        };
+
+        // 2 months deposit (30k) + 1,500 moving fee
+        state
+            .change_housing(flat("a", dec!(15000)), &market)
+            .unwrap();
+        assert_eq!(state.finances.cash, dec!(68500));
+        assert_eq!(state.housing_deposit, dec!(30000));
+
+        // Second move: 27k of the first deposit comes back, 21.5k is paid
+        state
+            .change_housing(flat("b", dec!(10000)), &market)
+            .unwrap();
+        assert_eq!(state.finances.cash, dec!(74000));
+        assert_eq!(state.housing_deposit, dec!(20000));
+    }
+
+    #[test]
+    fn test_market_crash_spares_emergency_fund_and_bonds() {
+        let mut state =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 30, 2024).unwrap();
+        let mut broker = Account::new(
+            "broker".to_string(),
+            "Brokerage".to_string(),
+            AccountKind::Taxable,
+        );
+        broker.deposit(dec!(100000)).unwrap();
+        broker.set_allocation(Allocation::new(dec!(0.5)).unwrap());
+        state.finances.add_account(broker);
+        let mut fund = Account::new(
+            "ef".to_string(),
+            "Emergency Fund".to_string(),
+            AccountKind::EmergencyFund,
+        );
+        fund.deposit(dec!(60000)).unwrap();
+        state.finances.add_account(fund);
+
+        state.apply_event(Event::MarketCrash {
+            drop: MARKET_CRASH_DROP,
+        });
+
+        // 50k of stocks lose 30%, 50k of bonds untouched
+        assert_eq!(state.finances.accounts[0].balance, dec!(85000));
+        assert_eq!(state.finances.accounts[1].balance, dec!(60000));
+        assert_eq!(state.economy.phase, EconomyPhase::Recovery);
+    }
+
+    #[test]
+    fn test_market_crash_follows_seed() {
+        let crashes = |seed: &str| {
+            let mut state =
+                GameState::new(seed.to_string(), "czech".to_string(), None, 30, 2024).unwrap();
+            let mut broker = Account::new(
+                "broker".to_string(),
+                "Brokerage".to_string(),
+                AccountKind::Taxable,
+            );
+            broker.deposit(dec!(100000)).unwrap();
+            state.finances.add_account(broker);
+
+            let mut months = Vec::new();
+            for month in 0..600 {
+                state.month_events.clear();
+                state.advance_economy_month(&CzechMarket);
+                if state
+                    .month_events
+                    .iter()
+                    .any(|e| matches!(e, Event::MarketCrash { .. }))
+                {
+                    months.push(month);
+                }
+            }
+            (months, state.finances.accounts[0].balance)
+        };
+
+        let (months, balance) = crashes("save1");
+        assert!(!months.is_empty());
+        assert_eq!(crashes("save1"), (months, balance));
+    }
+
+    #[test]
+    fn test_history_csv() {
+        let market = CzechMarket;
+        let mut state =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 30, 2024).unwrap();
+        state.finances.add_income(Income::new(
+            "job1".to_string(),
+            "Developer".to_string(),
+            IncomeKind::Employment,
+            dec!(50000),
+        ));
+        assert_eq!(
+            state.history_to_csv().lines().collect::<Vec<_>>(),
+            vec![MonthlySnapshot::CSV_HEADER]
+        );
+
+        for _ in 0..3 {
+            play_month(&mut state, &market);
+        }
+
+        let csv = state.history_to_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(
+            lines[0],
+            "date,gross,tax,expenses,net_cash_flow,cash,net_worth,happiness,burnout"
+        );
+        assert_eq!(lines.len(), state.history.len() + 1);
+        assert!(lines[1].starts_with("2024-01,50000,13300,"));
+        assert!(lines[1..].iter().all(|row| row.split(',').count() == 9));
+    }
+
+    #[test]
+    fn test_serialization() {
+        let state = GameState::new(
+            "save1".to_string(),
+            "czech".to_string(),
+            Some("Bob".to_string()),
+            30,
+            2024,
+        )
+        .unwrap();
+
+        let json = state.to_json().unwrap();
+        assert!(json.contains("Bob"));
+        assert!(json.contains("czech"));
+
+        let restored = GameState::from_json(&json).unwrap();
+        assert_eq!(restored.player.name, Some("Bob".to_string()));
+        assert_eq!(restored.market_id, "czech");
+        assert_eq!(restored.player.age, 30);
+    }
+
+    #[test]
+    fn test_stretch_applications_fail_more_often() {
+        let job = |id: &str, level| {
+            Job::new(
+                id.to_string(),
+                "Job".to_string(),
+                CareerField::Retail,
+                level,
+                dec!(30000),
+                None,
+            )
+        };
+        let market = CzechMarket;
+        let mut state =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 25, 2024).unwrap();
+        state.rng = GameRng::new(7);
+        state.career.years_experience = 3; // Junior
+
+        let mut exact_offers = 0;
+        let mut stretch_offers = 0;
+        for i in 0..50 {
+            if state
+                .apply_for_job(&job(&format!("exact_{i}"), JobLevel::Junior), &market)
+                .unwrap()
+            {
+                exact_offers += 1;
+            }
+            if state
+                .apply_for_job(&job(&format!("stretch_{i}"), JobLevel::Mid), &market)
+                .unwrap()
+            {
+                stretch_offers += 1;
+            }
+        }
+        assert_eq!(exact_offers, 50);
+        assert!(stretch_offers > 0);
+        assert!(stretch_offers < 50);
+    }
+
+    #[test]
+    fn test_below_minimum_wage_jobs_are_refused() {
+        let market = CzechMarket;
+        let mut state =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 25, 2024).unwrap();
+        let cheap = Job::new(
+            "cheap".to_string(),
+            "Intern".to_string(),
+            CareerField::Retail,
+            JobLevel::Entry,
+            dec!(12000),
+            None,
+        );
+        let err = state.apply_for_job(&cheap, &market).unwrap_err();
+        assert!(err.contains("below the minimum wage"));
+        assert!(state.career.rejected_applications.is_empty());
+
+        // The same salary clears a market with a lower minimum wage
+        #[cfg(feature = "usa")]
+        {
+            let usa = crate::markets::usa::UsaMarket;
+            assert!(state.apply_for_job(&cheap, &usa).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_rejection_costs_happiness_and_blocks_reapplying() {
+        let market = CzechMarket;
+        let mut state =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 25, 2024).unwrap();
+        state.economy.phase = EconomyPhase::Recession;
+        let lead = Job::new(
+            "lead".to_string(),
+            "Store Director".to_string(),
+            CareerField::Retail,
+            JobLevel::Lead,
+            dec!(90000),
+            None,
+        );
+        // Four levels above an entry-level player in a recession: 4% chance
+        assert_eq!(
+            JobMarket::application_chance(
+                &state.career,
+                &state.player.skills,
+                &lead,
+                &state.economy
+            ),
+            dec!(0.04)
+        );
+
+        state.rng = GameRng::new(1);
+        let happiness = state.player.happiness;
+        assert!(!state.apply_for_job(&lead, &market).unwrap());
+        assert_eq!(
+            state.player.happiness,
+            happiness - REJECTION_HAPPINESS_PENALTY as u8
+        );
+        assert!(state.apply_for_job(&lead, &market).is_err());
+
+        state.career.advance_month();
+        assert!(state.career.rejected_applications.is_empty());
+    }
+
+    #[test]
+    fn test_education_unlocks_higher_level() {
+        let market = CzechMarket;
+        let mut state =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 25, 2024).unwrap();
+        state.finances.cash = dec!(1000000);
+        state.career.years_experience = 2;
+        state.career.accept_job(Job::new(
+            "dev".to_string(),
+            "Junior Developer".to_string(),
+            CareerField::Technology,
+            JobLevel::Junior,
+            dec!(45000),
+            None,
+        ));
+        let mut studious = state.clone();
+        studious
+            .finances
+            .set_budget(ExpenseCategory::Education, dec!(10000));
+
+        for _ in 0..12 {
+            play_month(&mut state, &market);
+            play_month(&mut studious, &market);
+        }
+
+        let tech = CareerField::Technology;
+        assert_eq!(state.player.skill(&tech), 12);
+        assert_eq!(studious.player.skill(&tech), 72);
+        assert_eq!(
+            state.career.max_qualified_level(&state.player.skills),
+            JobLevel::Junior
+        );
+        assert_eq!(
+            studious.career.max_qualified_level(&studious.player.skills),
+            JobLevel::Mid
+        );
+    }
+
+    #[test]
+    fn test_sabbatical_lowers_burnout() {
+        let market = CzechMarket;
+        let mut state =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 25, 2024).unwrap();
+        state.finances.cash = dec!(1000000);
+        state.player.burnout = 80;
+        state.career.accept_job(Job::new(
+            "dev".to_string(),
+            "Developer".to_string(),
+            CareerField::Technology,
+            JobLevel::Senior,
+            dec!(80000),
+            None,
+        ));
+        state.finances.income_sources.push(Income::new(
+            "job_dev".to_string(),
+            "Developer".to_string(),
+            IncomeKind::Employment,
+            dec!(80000),
+        ));
+        let mut working = state.clone();
+
+        state.start_sabbatical(6).unwrap();
+        assert!(state.finances.income_sources.is_empty());
+        for _ in 0..6 {
+            play_month(&mut state, &market);
+            play_month(&mut working, &market);
+        }
+
+        assert!(state.player.burnout < 40);
+        assert!(state.player.burnout < working.player.burnout);
+        assert_eq!(state.career.years_experience, 0);
+        assert!(!state.career.is_on_sabbatical());
+    }
+
+    #[test]
+    fn test_windfall_adds_post_tax_amount() {
+        let market = CzechMarket;
+        let mut state =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 25, 2024).unwrap();
+        state.finances.cash = dec!(10000);
+        state.player.frugality_enabled = true;
+
+        let amount = dec!(1500000);
+        let tax = market.windfall_tax(WindfallKind::Lottery, amount).unwrap();
+        assert_eq!(tax, dec!(75000));
+        state.apply_event(Event::Windfall {
+            kind: WindfallKind::Lottery,
+            amount,
+            tax,
+        });
+        assert_eq!(state.finances.cash, dec!(1435000));
+        assert!(!state
+            .finances
+            .budget
+            .contains_key(&ExpenseCategory::Lifestyle));
+    }
+
+    #[test]
+    fn test_purchase_ids_are_never_reused() {
+        let market = CzechMarket;
+        let mut state =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 25, 2024).unwrap();
+        state.finances.cash = dec!(1000000);
+        let car = &PURCHASE_OPTIONS[0];
+        let furniture = &PURCHASE_OPTIONS[1];
+
+        let a = state.buy_purchase(furniture, false, &market).unwrap();
+        let b = state.buy_purchase(car, true, &market).unwrap();
+        state.finances.sell_asset(&a, &market, 0).unwrap();
+        let c = state.buy_purchase(furniture, false, &market).unwrap();
+        assert_ne!(b, c);
+        assert_eq!(state.finances.assets.len(), 2);
+
+        // The installment loan still belongs to the financed car
+        let loan_id = format!("installment_{}", b);
+        assert!(state.finances.loans.iter().any(|l| l.id == loan_id));
+        assert_eq!(state.finances.loans.len(), 1);
+        // 80 months of the 3,500 CZK food minimum, 20% down
+        assert_eq!(car.price(&market), dec!(280000));
+        assert_eq!(car.down_payment(&market), dec!(56000));
+    }
+
+    #[test]
+    fn test_bonus_windfall_is_taxed_on_top_of_salary() {
+        let market = CzechMarket;
+        let mut state =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 25, 2024).unwrap();
+        state.player.frugality_enabled = true;
+        state.accept_job(
+            Job::new(
+                "job".to_string(),
+                "Analyst".to_string(),
+                CareerField::Finance,
+                JobLevel::Mid,
+                dec!(50000),
+                None,
+            ),
+            false,
+            &market,
+        );
+        let cash = state.finances.cash;
+
+        state
+            .receive_windfall(WindfallKind::Bonus, dec!(15000), &market)
+            .unwrap();
+        // 15% income tax, 7.1% social and 4.5% health insurance on the extra pay
+        let Some(Event::Windfall { tax, .. }) = state.month_events.last() else {
+            panic!("Expected a windfall");
+        };
+        assert_eq!(*tax, dec!(3990));
+        assert_eq!(state.finances.cash, cash + dec!(11010));
+        assert_eq!(state.finances.tax_year.employment_gross, dec!(15000));
+        assert!(market
+            .windfall_tax(WindfallKind::Bonus, dec!(15000))
+            .is_err());
+    }
+
+    #[test]
+    fn test_large_windfall_tempts_lifestyle_creep() {
+        let mut state =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 25, 2024).unwrap();
+        state
+            .finances
+            .set_budget(ExpenseCategory::Lifestyle, dec!(2000));
+        state.apply_event(Event::Windfall {
+            kind: WindfallKind::Inheritance,
+            amount: dec!(400000),
+            tax: Decimal::ZERO,
+        });
+        assert_eq!(
+            state
+                .finances
+                .monthly_category_spending(&ExpenseCategory::Lifestyle),
+            dec!(4000)
+        );
+    }
+
+    #[test]
+    fn test_windfalls_are_deterministic_per_seed() {
+        let market = CzechMarket;
+        let play = || {
+            let mut state =
+                GameState::new("save1".to_string(), "czech".to_string(), None, 25, 2024).unwrap();
+            state.rng = GameRng::new(4);
+            let mut windfalls = Vec::new();
+            for _ in 0..1000 {
+                state.phase = GamePhase::Execution { current_day: 1 };
+                state.advance_execution_day(&market).unwrap();
+                windfalls.extend(
+                    state
+                        .month_events
+                        .drain(..)
+                        .filter(|e| matches!(e, Event::Windfall { .. })),
+                );
+            }
+            (windfalls, state.finances.cash)
+        };
+        let (windfalls, cash) = play();
+        assert!(!windfalls.is_empty());
+        assert_eq!((windfalls, cash), play());
+    }
+
+    #[test]
+    fn test_annual_bonus_paid_in_december() {
+        let market = CzechMarket;
+        let mut plain =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 25, 2024).unwrap();
+        plain.time.month = Month::new(12).unwrap();
+        let job = Job::new(
+            "acc".to_string(),
+            "Accountant".to_string(),
+            CareerField::Finance,
+            JobLevel::Mid,
+            dec!(40000),
+            None,
+        );
+        plain.career.accept_job(job.clone());
+        plain.finances.income_sources.push(Income::new(
+            "job_acc".to_string(),
+            "Accountant".to_string(),
+            IncomeKind::Employment,
+            dec!(40000),
+        ));
+        let mut bonused = plain.clone();
+        bonused
+            .career
+            .accept_job(job.with_annual_bonus(Decimal::ONE));
+
+        play_month(&mut plain, &market);
+        bonused.phase = GamePhase::Execution { current_day: 30 };
+        bonused.advance_execution_day(&market).unwrap();
+        // The bonus joins the tax year reconciled in January
+        assert_eq!(bonused.finances.tax_year.employment_gross, dec!(80000));
+        bonused.advance_phase(&market).unwrap();
+
+        let december = &bonused.history[0];
+        assert_eq!(december.gross_income, dec!(80000));
+        let bonus_tax = december.taxes - plain.history[0].taxes;
+        assert!(bonus_tax > Decimal::ZERO);
+        assert_eq!(
+            bonused.finances.cash - plain.finances.cash,
+            dec!(40000) - bonus_tax
+        );
+        assert_eq!(bonused.finances.tax_year, TaxYear::default());
+    }
+
+    #[test]
+    fn test_overdraft_draws_emergency_fund_then_charges_interest() {
+        let market = CzechMarket;
+        let mut state =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 25, 2024).unwrap();
+        state.finances.add_expense(Expense::new(
+            "rent".to_string(),
+            "Rent".to_string(),
+            ExpenseCategory::Essential,
+            dec!(20000),
+        ));
+        let mut fund = Account::new(
+            "ef".to_string(),
+            "Emergency Fund".to_string(),
+            AccountKind::EmergencyFund,
+        );
+        fund.deposit(dec!(5000)).unwrap();
+        state.finances.add_account(fund);
+        state.finances.cash = dec!(1000);
+
+        play_month(&mut state, &market);
+
+        // 19,000 short: 5,000 comes from the fund, the rest is overdrawn
+        assert_eq!(state.finances.accounts[0].balance, Decimal::ZERO);
+        let interest = state.history[0].expenses - dec!(20000);
+        assert!(interest > dec!(100) && interest < dec!(300));
+        assert_eq!(state.finances.cash, dec!(-14000) - interest);
+        assert_eq!(state.months_insolvent, 0);
+    }
+
+    #[test]
+    fn test_prolonged_insolvency_ends_in_bankruptcy() {
+        let market = CzechMarket;
+        let mut state =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 25, 2024).unwrap();
+        state.finances.add_expense(Expense::new(
+            "rent".to_string(),
+            "Rent".to_string(),
+            ExpenseCategory::Essential,
+            dec!(20000),
+        ));
+
+        for month in 1..=BANKRUPTCY_AFTER_MONTHS + 3 {
+            state.phase = GamePhase::Execution { current_day: 30 };
+            state.advance_execution_day(&market).unwrap();
+            if state.phase.is_bankrupt() {
+                // The third month of rent (plus interest) overdraws more than
+                // three months of expenses, starting the clock
+                assert_eq!(month, BANKRUPTCY_AFTER_MONTHS + 2);
+                break;
+            }
+            state.advance_phase(&market).unwrap();
+        }
+        assert!(state.phase.is_bankrupt());
+        assert!(state.advance_phase(&market).is_err());
+    }
+
+    #[test]
+    fn test_cash_accrues_through_the_month() {
+        let market = CzechMarket;
+        let mut daily =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 25, 2024).unwrap();
+        daily.rng = GameRng::new(1);
+        daily.finances.cash = dec!(50000);
+        daily.finances.add_income(Income::new(
+            "job1".to_string(),
+            "Developer".to_string(),
+            IncomeKind::Employment,
+            dec!(60000),
+        ));
+        daily.finances.add_expense(Expense::new(
+            "rent".to_string(),
+            "Rent".to_string(),
+            ExpenseCategory::Essential,
+            dec!(20000),
+        ));
+        let mut lump = daily.clone();
+
+        // Rent leaves on the first day, salary arrives on payday
+        daily.advance_phase(&market).unwrap();
+        assert_eq!(daily.finances.cash, dec!(30000));
+        while daily.phase.is_execution() {
+            let before = daily.finances.cash;
+            daily.advance_execution_day(&market).unwrap();
+            if let GamePhase::Execution { current_day } = daily.phase {
+                if current_day == DEFAULT_PAYDAY {
+                    assert!(daily.finances.cash > before);
+                }
+            }
+        }
+        assert!(daily.month_events.is_empty());
+
+        play_month(&mut lump, &market);
+        assert_eq!(daily.finances.cash, lump.finances.cash);
+        assert_eq!(daily.history[0].cash_flow, lump.history[0].cash_flow);
+        assert_eq!(daily.month_cash_accrued, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_early_bill_overdraws_mid_month() {
+        let market = CzechMarket;
+        let mut state =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 25, 2024).unwrap();
+        state.rng = GameRng::new(1);
+        state.finances.cash = dec!(10000);
+        state.finances.add_income(
+            Income::new(
+                "job1".to_string(),
+                "Developer".to_string(),
+                IncomeKind::Employment,
+                dec!(60000),
+            )
+            .with_payday(28)
+            .unwrap(),
+        );
+        state.finances.add_expense(
+            Expense::new(
+                "rent".to_string(),
+                "Rent".to_string(),
+                ExpenseCategory::Essential,
+                dec!(25000),
+            )
+            .with_due_day(3)
+            .unwrap(),
+        );
+
+        state.advance_phase(&market).unwrap();
+        let mut lowest = state.finances.cash;
+        while state.phase.is_execution() {
+            state.advance_execution_day(&market).unwrap();
+            lowest = lowest.min(state.finances.cash);
+        }
+        assert!(lowest < Decimal::ZERO);
+        assert!(state.history[0].cash_flow > Decimal::ZERO);
+        assert!(state.finances.cash > dec!(10000));
+    }
+
+    #[test]
+    fn test_one_time_expense_hits_a_single_month() {
+        let market = CzechMarket;
+        let mut state =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 25, 2024).unwrap();
+        state.rng = GameRng::new(1);
+        state.finances.cash = dec!(100000);
+        state.finances.add_expense(Expense::new(
+            "rent".to_string(),
+            "Rent".to_string(),
+            ExpenseCategory::Essential,
+            dec!(10000),
+        ));
+        state.finances.add_expense(
+            Expense::new(
+                "moving".to_string(),
+                "Moving".to_string(),
+                ExpenseCategory::Other,
+                dec!(15000),
+            )
+            .for_months(1)
+            .unwrap(),
+        );
+
+        play_month(&mut state, &market);
+        play_month(&mut state, &market);
+        assert_eq!(state.history[0].expenses, dec!(25000));
+        assert_eq!(state.history[1].expenses, dec!(10000));
+        assert_eq!(state.finances.expenses.len(), 1);
+    }
+
+    #[test]
+    fn test_savings_rate_series_tracks_surplus_and_deficit() {
+        let market = CzechMarket;
+        let mut state =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 25, 2024).unwrap();
+        state.rng = GameRng::new(1);
+        state.finances.cash = dec!(200000);
+        state.finances.add_income(Income::new(
+            "job1".to_string(),
+            "Developer".to_string(),
+            IncomeKind::Employment,
+            dec!(60000),
+        ));
+        state.finances.add_expense(Expense::new(
+            "rent".to_string(),
+            "Rent".to_string(),
+            ExpenseCategory::Essential,
+            dec!(20000),
+        ));
+        play_month(&mut state, &market);
+
+        // A month of spending beyond take-home pay
+        state.finances.add_expense(
+            Expense::new(
+                "wedding".to_string(),
+                "Wedding".to_string(),
+                ExpenseCategory::Other,
+                dec!(80000),
+            )
+            .for_months(1)
+            .unwrap(),
+        );
+        play_month(&mut state, &market);
+
+        let series = state.savings_rate_series();
+        assert_eq!(series.len(), 2);
+        let surplus = &state.history[0];
+        assert!(series[0].1 > Decimal::ZERO);
+        assert_eq!(
+            series[0].1,
+            (surplus.cash_flow / surplus.net_income * dec!(100)).round_dp(2)
+        );
+        assert!(series[1].1 < Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_retirement_readiness_extremes() {
+        let market = CzechMarket;
+        let mut late =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 64, 2024).unwrap();
+        late.finances.add_expense(Expense::new(
+            "rent".to_string(),
+            "Rent".to_string(),
+            ExpenseCategory::Essential,
+            dec!(30000),
+        ));
+        assert!(late.retirement_readiness(&market).unwrap() < 10);
+
+        let mut funded =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 45, 2024).unwrap();
+        funded.finances.add_expense(Expense::new(
+            "rent".to_string(),
+            "Rent".to_string(),
+            ExpenseCategory::Essential,
+            dec!(30000),
+        ));
+        let mut account = Account::new(
+            "inv".to_string(),
+            "Investments".to_string(),
+            AccountKind::Taxable,
+        );
+        account.deposit(dec!(10000000)).unwrap();
+        funded.finances.add_account(account);
+        assert_eq!(funded.retirement_readiness(&market).unwrap(), 100);
+
+        // Halfway there on the same spending
+        let mut half = late.clone();
+        let pension = market.state_pension_estimate(&half.career);
+        let needed = (dec!(30000) - pension) * dec!(12) / DEFAULT_WITHDRAWAL_RATE;
+        let mut account = Account::new(
+            "inv".to_string(),
+            "Investments".to_string(),
+            AccountKind::Taxable,
+        );
+        account.deposit(needed / dec!(2)).unwrap();
+        half.finances.add_account(account);
+        half.player.age = market.retirement_age();
+        assert_eq!(half.retirement_readiness(&market).unwrap(), 50);
+    }
+
+    #[test]
+    fn test_loading_validates_market() {
+        let state =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 25, 2024).unwrap();
+        assert!(state.validate().is_ok());
+        assert!(GameState::from_json(&state.to_json().unwrap()).is_ok());
+
+        let mut foreign = state.clone();
+        foreign.market_id = "atlantis".to_string();
+        assert!(foreign.validate().is_err());
+        assert!(GameState::from_json(&foreign.to_json().unwrap()).is_err());
+
+        // A retired market's saves continue in its replacement
+        assert!(foreign.migrate_market(&[("atlantis", "czech")]));
+        assert_eq!(foreign.market_id, "czech");
+        assert!(foreign.validate().is_ok());
+        assert!(!foreign.migrate_market(&[("atlantis", "czech")]));
+    }
+
+    #[test]
+    fn test_validate_reports_malformed_states() {
+        let valid =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 25, 2024).unwrap();
+        assert_eq!(valid.validate(), Ok(()));
+
+        let mut child = valid.clone();
+        child.player.age = 5;
+        assert_eq!(child.validate(), Err(vec![FinError::InvalidAge(5)]));
+
+        let mut lost_day = valid.clone();
+        lost_day.phase = GamePhase::Execution { current_day: 31 };
+        assert_eq!(lost_day.validate(), Err(vec![FinError::InvalidDay(31)]));
+
+        let mut overspent = valid.clone();
+        overspent
+            .finances
+            .set_budget(ExpenseCategory::Lifestyle, dec!(-100));
+        overspent.finances.add_expense(Expense::new(
+            "refund".to_string(),
+            "Refund".to_string(),
+            ExpenseCategory::Other,
+            dec!(-50),
+        ));
+        let errors = overspent.validate().unwrap_err();
+        assert!(errors.contains(&FinError::NegativeBudget(
+            ExpenseCategory::Lifestyle,
+            dec!(-100)
+        )));
+        assert!(errors.contains(&FinError::NegativeExpense("refund".to_string())));
+
+        let mut ghost_job = valid.clone();
+        ghost_job.finances.add_income(Income::new(
+            "job_dev".to_string(),
+            "Developer".to_string(),
+            IncomeKind::Employment,
+            dec!(80000),
+        ));
+        ghost_job.player.age = 200;
+        assert_eq!(
+            ghost_job.validate(),
+            Err(vec![
+                FinError::InvalidAge(200),
+                FinError::OrphanedJobIncome("job_dev".to_string())
+            ])
+        );
+        assert!(GameState::from_json(&ghost_job.to_json().unwrap()).is_err());
+
+        // Holding the job makes its income legitimate
+        ghost_job.player.age = 30;
+        ghost_job.career.accept_job(Job::new(
+            "dev".to_string(),
+            "Developer".to_string(),
+            CareerField::Technology,
+            JobLevel::Senior,
+            dec!(80000),
+            None,
+        ));
+        assert_eq!(ghost_job.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_retirement_age_follows_gender() {
+        let market = CzechMarket;
+        let mut state =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 60, 2024).unwrap();
+        assert_eq!(state.birth_year(), 1964);
+        assert_eq!(state.retirement_age(&market), 65);
+
+        state.player.gender = Some(Gender::Female);
+        assert_eq!(state.retirement_age(&market), 64);
+        let loaded = GameState::from_json(&state.to_json().unwrap()).unwrap();
+        assert_eq!(loaded.player.gender, Some(Gender::Female));
+
+        // She retires on her 64th birthday, a man born the same year keeps working
+        state.player.age = 63;
+        state.time = GameTime::new(2027, 1).unwrap();
+        let mut man = state.clone();
+        man.player.gender = Some(Gender::Male);
+        for _ in 0..12 {
+            state.phase = GamePhase::Review;
+            state.advance_phase(&market).unwrap();
+            man.phase = GamePhase::Review;
+            man.advance_phase(&market).unwrap();
+        }
+        assert_eq!(state.player.age, 64);
+        assert!(state.phase.is_retirement());
+        assert!(!man.phase.is_retirement());
+    }
+
+    #[test]
+    fn test_wellbeing_ledger_sums_effects() {
+        let market = CzechMarket;
+        let mut state =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 25, 2024).unwrap();
+        employ(&mut state, JobLevel::Senior);
+        state.player.dependents = 1;
+        state.finances.cash = dec!(100000);
+        let happiness = state.player.happiness as i16;
+        let burnout = state.player.burnout as i16;
+
+        state.advance_phase(&market).unwrap();
+        while state.phase.is_execution() {
+            state.advance_execution_day(&market).unwrap();
+        }
+
+        let ledger = &state.history.last().unwrap().wellbeing;
+        assert_eq!(
+            ledger.happiness(),
+            state.player.happiness as i16 - happiness
+        );
+        assert_eq!(ledger.burnout(), state.player.burnout as i16 - burnout);
+        let factors: Vec<_> = ledger.effects.iter().map(|e| e.factor).collect();
+        assert!(factors.contains(&WellbeingFactor::WeekendRest));
+        assert!(factors.contains(&WellbeingFactor::WorkStress));
+        assert!(factors.contains(&WellbeingFactor::Children));
+        // Weekends were the biggest happiness boost
+        assert_eq!(
+            ledger.top_factors(1)[0].factor,
+            WellbeingFactor::WeekendRest
+        );
+        assert!(state.month_wellbeing.effects.is_empty());
+    }
+
+    #[test]
+    fn test_fast_forward_a_year() {
+        let market = CzechMarket;
+        let mut state =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 25, 2024).unwrap();
+        state.finances.cash = dec!(500000);
+
+        state.fast_forward(12, &market).unwrap();
+        assert!(state.phase.is_planning());
+        assert_eq!(state.time.year, 2025);
+        assert_eq!(state.time.month.value(), 1);
+        assert_eq!(state.player.age, 26);
+        assert_eq!(state.history.len(), 12);
+        assert_eq!(state.months_elapsed(), 13);
+
+        state.phase = GamePhase::Review;
+        assert!(state.fast_forward(1, &market).is_err());
+    }
+
+    #[test]
+    fn test_market_account_is_dated_to_the_game_month() {
+        let market = CzechMarket;
+        let mut state =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 25, 2024).unwrap();
+        state.fast_forward(5, &market).unwrap();
+        let pillar = market
+            .available_accounts()
+            .into_iter()
+            .find(|t| t.id == "third_pillar")
+            .unwrap();
+
+        let id = state.open_market_account(&pillar).unwrap();
+        let account = state.finances.accounts.iter().find(|a| a.id == id).unwrap();
+        assert_eq!(account.opened_month, 6);
+        assert_eq!(account.holding_months(state.months_elapsed() + 12), 12);
+        assert!(state.open_market_account(&pillar).is_err());
+    }
+
+    #[test]
+    fn test_retirement_accounts_are_locked_until_retirement() {
+        let market = CzechMarket;
+        let mut state =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 30, 2024).unwrap();
+        state.finances.cash = dec!(100000);
+        let pillar = market
+            .available_accounts()
+            .into_iter()
+            .find(|t| t.id == "third_pillar")
+            .unwrap();
+        let id = state.open_market_account(&pillar).unwrap();
+        state
+            .finances
+            .deposit_to_account(&id, dec!(40000), &market)
+            .unwrap();
+
+        let err = state
+            .withdraw_from_account(&id, dec!(10000), &market)
+            .unwrap_err();
+        assert!(err.contains("locked until retirement"));
+        assert_eq!(state.finances.cash, dec!(60000));
+
+        // Savings accounts stay open to withdrawals at any age
+        let savings = state.open_account("Savings".to_string(), AccountKind::EmergencyFund);
+        state
+            .finances
+            .deposit_to_account(&savings, dec!(10000), &market)
+            .unwrap();
+        state
+            .withdraw_from_account(&savings, dec!(10000), &market)
+            .unwrap();
+        assert_eq!(state.finances.cash, dec!(60000));
+
+        state.player.age = state.retirement_age(&market);
+        assert_eq!(
+            state
+                .withdraw_from_account(&id, dec!(10000), &market)
+                .unwrap(),
+            dec!(10000)
+        );
+        assert_eq!(state.finances.cash, dec!(70000));
+    }
+
+    #[test]
+    fn test_time_test_counts_game_months() {
+        let market = CzechMarket;
+        let mut state =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 25, 2024).unwrap();
+        state.finances.cash = dec!(500000);
+        state.fast_forward(2, &market).unwrap();
+
+        let id = state.open_account("Brokerage".to_string(), AccountKind::Taxable);
+        let account = |state: &GameState| {
+            state
+                .finances
+                .accounts
+                .iter()
+                .find(|a| a.id == id)
+                .unwrap()
+                .clone()
+        };
+        assert_eq!(account(&state).opened_month, 3);
+        assert_eq!(
+            account(&state).months_until_tax_exempt(state.months_elapsed(), &market),
+            Some(36)
+        );
+
+        state.fast_forward(12, &market).unwrap();
+        assert_eq!(
+            account(&state).months_until_tax_exempt(state.months_elapsed(), &market),
+            Some(24)
+        );
+    }
+
+    #[test]
+    fn test_job_profiles_shape_wellbeing() {
+        let market = CzechMarket;
+        let mut nurse =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 30, 2024).unwrap();
+        let mut teacher = nurse.clone();
+        let job = |field: CareerField| {
+            Job::new(
+                "job".to_string(),
+                "Specialist".to_string(),
+                field,
+                JobLevel::Mid,
+                dec!(45000),
+                None,
+            )
+        };
+        nurse.accept_job(job(CareerField::Healthcare), true, &market);
+        teacher.accept_job(
+            job(CareerField::Education).with_work_life(dec!(0.5), 1),
+            true,
+            &market,
+        );
+
+        for _ in 0..12 {
+            play_full_month(&mut nurse, &market);
+            play_full_month(&mut teacher, &market);
+        }
+
+        assert!(nurse.player.burnout > teacher.player.burnout);
+        assert!(nurse.player.happiness <= teacher.player.happiness);
+    }
+
+    #[test]
+    fn test_rental_income_is_paid_at_settlement() {
+        let market = CzechMarket;
+        let mut owner =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 40, 2024).unwrap();
+        owner.finances.add_asset(Asset::new(
+            "flat".to_string(),
+            "Flat".to_string(),
+            AssetCategory::RealEstate,
+            dec!(4000000),
+            dec!(3000),
+        ));
+        owner.finances.cash = dec!(100000);
+        let mut landlord = owner.clone();
+        landlord
+            .finances
+            .rent_out_asset("flat", dec!(20000))
+            .unwrap();
+
+        play_month(&mut owner, &market);
+        play_month(&mut landlord, &market);
+
+        // 17,000 of rent net of upkeep, less 2,100 tax, plus the upkeep no longer paid
+        let gain = landlord.finances.cash - owner.finances.cash;
+        assert_eq!(gain, dec!(17000) - dec!(2100) + dec!(3000));
+        assert_eq!(landlord.history[0].taxes, dec!(2100));
+    }
+
+    #[test]
+    fn test_lifestyle_budget_raises_happiness_at_settlement() {
+        let market = CzechMarket;
+        let mut lifestyle =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 30, 2024).unwrap();
+        lifestyle.player.happiness = 50;
+        let mut transport = lifestyle.clone();
+        lifestyle
+            .finances
+            .set_budget(ExpenseCategory::Lifestyle, dec!(12000));
+        transport
+            .finances
+            .set_budget(ExpenseCategory::Transportation, dec!(12000));
+
+        play_month(&mut lifestyle, &market);
+        play_month(&mut transport, &market);
+
+        assert!(lifestyle.player.happiness > transport.player.happiness);
+        assert!(lifestyle.history[0]
+            .wellbeing
+            .effects
+            .iter()
+            .any(|e| e.factor == WellbeingFactor::Purchases && e.happiness > 0));
+
+        // Needs like a big Essential budget buy no happiness at all
+        let mut essentials =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 30, 2024).unwrap();
+        essentials.finances.cash = dec!(1000000);
+        essentials
+            .finances
+            .set_budget(ExpenseCategory::Essential, dec!(300000));
+        play_month(&mut essentials, &market);
+        assert!(!essentials.history[0]
+            .wellbeing
+            .effects
+            .iter()
+            .any(|e| e.factor == WellbeingFactor::Purchases));
     }
 }
@@ -0,0 +1,159 @@
+//! Financial goals and milestones
+
+use super::game_state::GameState;
+use crate::market::MarketProfile;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+
+/// Net worth milestone in the default goal set (CZK, see `MarketProfile::scale_czk`)
+pub const NET_WORTH_MILESTONE: Decimal = dec!(1000000);
+
+/// A concrete target the player can work toward
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Goal {
+    /// Emergency fund covering three months of expenses
+    EmergencyFund,
+    /// Net worth at or above the amount
+    NetWorth(Decimal),
+    /// No outstanding debts or loans
+    DebtFree,
+    /// FIRE progress at or above the percentage (50 = halfway)
+    FireProgress(Decimal),
+}
+
+impl Goal {
+    /// Returns the goals every game starts with, priced in the market currency
+    pub fn default_set(market: &dyn MarketProfile) -> Vec<Goal> {
+        vec![
+            Goal::EmergencyFund,
+            Goal::NetWorth(market.scale_czk(NET_WORTH_MILESTONE)),
+            Goal::DebtFree,
+            Goal::FireProgress(dec!(50)),
+        ]
+    }
+
+    /// Returns display name
+    pub fn name(&self) -> String {
+        match self {
+            Goal::EmergencyFund => "Build a 3-month emergency fund".to_string(),
+            Goal::NetWorth(amount) => format!("Reach {} net worth", amount.normalize()),
+            Goal::DebtFree => "Be debt-free".to_string(),
+            Goal::FireProgress(percent) => format!("Reach {}% FIRE", percent.normalize()),
+        }
+    }
+
+    /// Checks whether the goal is currently met
+    pub fn is_met(&self, state: &GameState) -> bool {
+        let finances = &state.finances;
+        match self {
+            Goal::EmergencyFund => finances.has_emergency_fund(),
+            Goal::NetWorth(amount) => finances.net_worth() >= *amount,
+            Goal::DebtFree => {
+                finances.liabilities <= Decimal::ZERO
+                    && finances.loan_balance() <= Decimal::ZERO
+                    && finances.card_balance() <= Decimal::ZERO
+            }
+            Goal::FireProgress(percent) => finances.fire_progress() >= *percent,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::accounts::{Account, AccountKind};
+    use crate::core::expenses::{Expense, ExpenseCategory};
+    use crate::markets::czech::CzechMarket;
+
+    fn state_with_expenses(monthly: Decimal) -> GameState {
+        let mut state =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 30, 2024).unwrap();
+        state.finances.add_expense(Expense::new(
+            "rent".to_string(),
+            "Rent".to_string(),
+            ExpenseCategory::Essential,
+            monthly,
+        ));
+        state
+    }
+
+    #[test]
+    fn test_emergency_fund_goal() {
+        let mut state = state_with_expenses(dec!(10000));
+        let mut fund = Account::new(
+            "ef".to_string(),
+            "Emergency Fund".to_string(),
+            AccountKind::EmergencyFund,
+        );
+        fund.deposit(dec!(29999)).unwrap();
+        state.finances.add_account(fund);
+        assert!(!Goal::EmergencyFund.is_met(&state));
+
+        state.finances.accounts[0].deposit(dec!(1)).unwrap();
+        assert!(Goal::EmergencyFund.is_met(&state));
+    }
+
+    #[test]
+    fn test_net_worth_goal() {
+        let mut state = state_with_expenses(dec!(10000));
+        let goal = Goal::NetWorth(NET_WORTH_MILESTONE);
+
+        state.finances.cash = dec!(999999);
+        assert!(!goal.is_met(&state));
+        state.finances.cash = dec!(1000000);
+        assert!(goal.is_met(&state));
+    }
+
+    #[test]
+    fn test_debt_free_goal() {
+        let mut state = state_with_expenses(dec!(10000));
+        assert!(Goal::DebtFree.is_met(&state));
+
+        state.finances.liabilities = dec!(1);
+        assert!(!Goal::DebtFree.is_met(&state));
+    }
+
+    #[test]
+    fn test_fire_progress_goal() {
+        // FIRE number = 10k * 12 * 25 = 3M, so halfway is 1.5M
+        let mut state = state_with_expenses(dec!(10000));
+        let goal = Goal::FireProgress(dec!(50));
+
+        state.finances.cash = dec!(1499999);
+        assert!(!goal.is_met(&state));
+        state.finances.cash = dec!(1500000);
+        assert!(goal.is_met(&state));
+    }
+
+    #[test]
+    fn test_goal_progress_reports_default_set() {
+        let mut state = state_with_expenses(dec!(10000));
+        state.finances.cash = dec!(1500000);
+
+        let progress = state.goal_progress(&CzechMarket);
+        assert_eq!(progress.len(), 4);
+        let met: Vec<&Goal> = progress
+            .iter()
+            .filter(|(_, met)| *met)
+            .map(|(g, _)| g)
+            .collect();
+        assert_eq!(
+            met,
+            vec![
+                &Goal::NetWorth(NET_WORTH_MILESTONE),
+                &Goal::DebtFree,
+                &Goal::FireProgress(dec!(50)),
+            ]
+        );
+    }
+
+    #[cfg(feature = "usa")]
+    #[test]
+    fn test_default_net_worth_goal_follows_market() {
+        let usa = crate::markets::usa::UsaMarket;
+        let milestone = usa.scale_czk(NET_WORTH_MILESTONE);
+        assert!(milestone < NET_WORTH_MILESTONE);
+        assert!(Goal::default_set(&usa).contains(&Goal::NetWorth(milestone)));
+    }
+}
@@ -0,0 +1,156 @@
+//! Month-by-month history for reports and charts
+
+use super::time::GameTime;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Cause of a change in happiness or burnout
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WellbeingFactor {
+    /// Weekend days off
+    WeekendRest,
+    /// Privacy of the home
+    Housing,
+    /// Food budget above or at the survival minimum
+    FoodQuality,
+    /// Enjoyment of the month's spending
+    Purchases,
+    /// Job stress less the relief from Health and Lifestyle spending
+    WorkStress,
+    /// Raising children
+    Children,
+    /// Time off on a sabbatical
+    Sabbatical,
+    /// Layoffs, windfalls, births, weddings and job rejections
+    LifeEvents,
+}
+
+impl WellbeingFactor {
+    /// Returns display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            WellbeingFactor::WeekendRest => "Weekend rest",
+            WellbeingFactor::Housing => "Housing",
+            WellbeingFactor::FoodQuality => "Food quality",
+            WellbeingFactor::Purchases => "Purchases",
+            WellbeingFactor::WorkStress => "Work stress",
+            WellbeingFactor::Children => "Raising children",
+            WellbeingFactor::Sabbatical => "Sabbatical",
+            WellbeingFactor::LifeEvents => "Life events",
+        }
+    }
+}
+
+/// Net happiness and burnout change from one factor
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WellbeingEffect {
+    /// What caused the change
+    pub factor: WellbeingFactor,
+    /// Happiness points gained (negative when lost)
+    pub happiness: i16,
+    /// Burnout points gained (negative when recovered)
+    pub burnout: i16,
+}
+
+/// Happiness and burnout changes over one month, by factor
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WellbeingDelta {
+    /// One entry per factor that changed anything, in order of first effect
+    pub effects: Vec<WellbeingEffect>,
+}
+
+impl WellbeingDelta {
+    /// Adds a change to its factor's running total
+    pub fn record(&mut self, factor: WellbeingFactor, happiness: i16, burnout: i16) {
+        if happiness == 0 && burnout == 0 {
+            return;
+        }
+        match self.effects.iter_mut().find(|e| e.factor == factor) {
+            Some(effect) => {
+                effect.happiness += happiness;
+                effect.burnout += burnout;
+            }
+            None => self.effects.push(WellbeingEffect {
+                factor,
+                happiness,
+                burnout,
+            }),
+        }
+    }
+
+    /// Returns the net happiness change
+    pub fn happiness(&self) -> i16 {
+        self.effects.iter().map(|e| e.happiness).sum()
+    }
+
+    /// Returns the net burnout change
+    pub fn burnout(&self) -> i16 {
+        self.effects.iter().map(|e| e.burnout).sum()
+    }
+
+    /// Returns up to `count` factors with the largest combined effect, largest first
+    pub fn top_factors(&self, count: usize) -> Vec<WellbeingEffect> {
+        let mut effects = self.effects.clone();
+        effects.sort_by_key(|e| std::cmp::Reverse(e.happiness.abs() + e.burnout.abs()));
+        effects.truncate(count);
+        effects
+    }
+}
+
+/// Financial summary of one settled month
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MonthlySnapshot {
+    /// Month the snapshot was taken (at settlement)
+    pub time: GameTime,
+    /// Gross income for the month
+    pub gross_income: Decimal,
+    /// Income after taxes (including the partner's contribution)
+    pub net_income: Decimal,
+    /// Total expenses for the month
+    pub expenses: Decimal,
+    /// Net cash flow (net income - expenses)
+    pub cash_flow: Decimal,
+    /// Net worth after settlement
+    pub net_worth: Decimal,
+    /// Taxes and social contributions withheld
+    #[serde(default)]
+    pub taxes: Decimal,
+    /// Cash on hand after settlement
+    #[serde(default)]
+    pub cash: Decimal,
+    /// Player happiness at settlement
+    #[serde(default)]
+    pub happiness: u8,
+    /// Player burnout at settlement
+    #[serde(default)]
+    pub burnout: u8,
+    /// Share of net income saved (percent, negative when spending exceeded income)
+    #[serde(default)]
+    pub savings_rate: Decimal,
+    /// Happiness and burnout changes since the previous settlement
+    #[serde(default)]
+    pub wellbeing: WellbeingDelta,
+}
+
+impl MonthlySnapshot {
+    /// Column names of the CSV export, in row order
+    pub const CSV_HEADER: &'static str =
+        "date,gross,tax,expenses,net_cash_flow,cash,net_worth,happiness,burnout";
+
+    /// Formats the snapshot as one CSV row
+    pub fn to_csv_row(&self) -> String {
+        format!(
+            "{}-{:02},{},{},{},{},{},{},{},{}",
+            self.time.year,
+            self.time.month.value(),
+            self.gross_income.normalize(),
+            self.taxes.normalize(),
+            self.expenses.normalize(),
+            self.cash_flow.normalize(),
+            self.cash.normalize(),
+            self.net_worth.normalize(),
+            self.happiness,
+            self.burnout
+        )
+    }
+}
@@ -1,9 +1,19 @@
 //! Housing and accommodation system
 
+use crate::market::MarketProfile;
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 
+/// Yearly rent increase on top of inflation
+pub const LANDLORD_RENT_PREMIUM: Decimal = dec!(0.01);
+
+/// Share of the security deposit kept by the landlord for wear and tear
+pub const DEPOSIT_WEAR_AND_TEAR: Decimal = dec!(0.1);
+
+/// Largest share of net income housing should take (the "30% rule")
+pub const AFFORDABLE_HOUSING_SHARE: Decimal = dec!(0.30);
+
 /// Type of housing
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum HousingType {
@@ -32,6 +42,22 @@ impl HousingType {
             HousingType::House => "House",
         }
     }
+
+    /// Share of the rent paid by roommates
+    pub fn roommate_share(&self) -> Decimal {
+        match self {
+            HousingType::Shared => dec!(0.5),
+            _ => Decimal::ZERO,
+        }
+    }
+
+    /// Happiness modifier per month for the (lack of) privacy
+    pub fn privacy_impact(&self) -> i8 {
+        match self {
+            HousingType::Shared => -1,
+            _ => 0,
+        }
+    }
 }
 
 /// Location quality affects price and happiness
@@ -57,6 +83,16 @@ impl LocationQuality {
         }
     }
 
+    /// Monthly cost of commuting to work from here, in the market currency
+    pub fn commute_cost(&self, market: &dyn MarketProfile) -> Decimal {
+        market.scale_czk(match self {
+            LocationQuality::Poor => dec!(3000),
+            LocationQuality::Average => dec!(1500),
+            LocationQuality::Good => dec!(800),
+            LocationQuality::Premium => dec!(400),
+        })
+    }
+
     /// Happiness modifier per month for living here
     pub fn happiness_impact(&self) -> i8 {
         match self {
@@ -86,118 +122,43 @@ pub struct Housing {
 }
 
 impl Housing {
-    /// Total monthly housing cost
+    /// Rent covered by roommates each month
+    pub fn roommate_contribution(&self) -> Decimal {
+        self.monthly_cost * self.housing_type.roommate_share()
+    }
+
+    /// Total monthly housing cost to the player (after roommates' share)
     pub fn total_monthly_cost(&self) -> Decimal {
-        self.monthly_cost + self.monthly_utilities
+        self.monthly_cost - self.roommate_contribution() + self.monthly_utilities
     }
 
-    /// Calculate moving cost (security deposit + first month + moving expenses)
-    pub fn moving_cost(&self) -> Decimal {
-        // Security deposit (2 months) + moving expenses (1500 CZK)
-        self.monthly_cost * dec!(2) + dec!(1500)
+    /// Returns true if the monthly cost stays within `AFFORDABLE_HOUSING_SHARE` of net income
+    pub fn is_affordable(&self, net_income: Decimal) -> bool {
+        self.total_monthly_cost() <= net_income * AFFORDABLE_HOUSING_SHARE
+    }
+
+    /// Raises the rent by `rate` (e.g. 0.04 for 4%)
+    pub fn raise_rent(&mut self, rate: Decimal) {
+        self.monthly_cost = (self.monthly_cost * (Decimal::ONE + rate)).round_dp(0);
     }
-}
 
-/// Generates housing options
-pub struct HousingMarket;
-
-impl HousingMarket {
-    /// Generate Czech housing market options
-    /// Returns options ranging from cheap shared apartments to expensive houses
-    pub fn generate_czech_housing() -> Vec<Housing> {
-        vec![
-            // Cheap options - below reasonable budget
-            Housing {
-                id: "cz_shared_poor_1".to_string(),
-                housing_type: HousingType::Shared,
-                location: LocationQuality::Poor,
-                address: "Shared room, Černý Most".to_string(),
-                monthly_cost: dec!(4000),
-                monthly_utilities: dec!(1000),
-            },
-            Housing {
-                id: "cz_studio_poor_1".to_string(),
-                housing_type: HousingType::Studio,
-                location: LocationQuality::Poor,
-                address: "Small studio, Hostivař".to_string(),
-                monthly_cost: dec!(7000),
-                monthly_utilities: dec!(2000),
-            },
-            // Reasonable options - good value
-            Housing {
-                id: "cz_shared_avg_1".to_string(),
-                housing_type: HousingType::Shared,
-                location: LocationQuality::Average,
-                address: "Shared apartment, Háje".to_string(),
-                monthly_cost: dec!(6000),
-                monthly_utilities: dec!(1200),
-            },
-            Housing {
-                id: "cz_studio_avg_1".to_string(),
-                housing_type: HousingType::Studio,
-                location: LocationQuality::Average,
-                address: "Studio, Chodov".to_string(),
-                monthly_cost: dec!(10000),
-                monthly_utilities: dec!(2500),
-            },
-            Housing {
-                id: "cz_1bed_avg_1".to_string(),
-                housing_type: HousingType::OneBedroom,
-                location: LocationQuality::Average,
-                address: "1+kk, Nové Butovice".to_string(),
-                monthly_cost: dec!(13000),
-                monthly_utilities: dec!(3000),
-            },
-            // Good options - comfortable
-            Housing {
-                id: "cz_1bed_good_1".to_string(),
-                housing_type: HousingType::OneBedroom,
-                location: LocationQuality::Good,
-                address: "1+1, Karlín".to_string(),
-                monthly_cost: dec!(18000),
-                monthly_utilities: dec!(3500),
-            },
-            Housing {
-                id: "cz_2bed_good_1".to_string(),
-                housing_type: HousingType::TwoBedroom,
-                location: LocationQuality::Good,
-                address: "2+kk, Smíchov".to_string(),
-                monthly_cost: dec!(22000),
-                monthly_utilities: dec!(4000),
-            },
-            // Premium options - expensive
-            Housing {
-                id: "cz_2bed_prem_1".to_string(),
-                housing_type: HousingType::TwoBedroom,
-                location: LocationQuality::Premium,
-                address: "2+1, Vinohrady".to_string(),
-                monthly_cost: dec!(28000),
-                monthly_utilities: dec!(4500),
-            },
-            Housing {
-                id: "cz_3bed_prem_1".to_string(),
-                housing_type: HousingType::ThreeBedroom,
-                location: LocationQuality::Premium,
-                address: "3+1, Nové Město".to_string(),
-                monthly_cost: dec!(35000),
-                monthly_utilities: dec!(5000),
-            },
-            // Very expensive - above reasonable budget
-            Housing {
-                id: "cz_house_prem_1".to_string(),
-                housing_type: HousingType::House,
-                location: LocationQuality::Premium,
-                address: "House, Dejvice".to_string(),
-                monthly_cost: dec!(50000),
-                monthly_utilities: dec!(7000),
-            },
-        ]
+    /// Security deposit paid on moving in, in months of rent set by the market
+    pub fn security_deposit(&self, market: &dyn MarketProfile) -> Decimal {
+        self.monthly_cost * market.security_deposit_months()
+    }
+
+    /// Calculate moving cost (security deposit + moving expenses)
+    /// Deposit size and moving fee are defined by the market
+    pub fn moving_cost(&self, market: &dyn MarketProfile) -> Decimal {
+        self.security_deposit(market) + market.moving_fee()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::markets::czech::CzechMarket;
+    use rust_decimal_macros::dec;
 
     #[test]
     fn test_housing_costs() {
@@ -211,7 +172,41 @@ mod tests {
         };
 
         assert_eq!(housing.total_monthly_cost(), dec!(18000));
-        assert_eq!(housing.moving_cost(), dec!(31500)); // 2 months deposit + 1500 moving
+        assert_eq!(housing.moving_cost(&CzechMarket), dec!(31500)); // 2 months deposit + 1500 moving
+    }
+
+    #[test]
+    fn test_roommates_split_shared_rent() {
+        let shared = Housing {
+            id: "test2".to_string(),
+            housing_type: HousingType::Shared,
+            location: LocationQuality::Average,
+            address: "Test Street".to_string(),
+            monthly_cost: dec!(12000),
+            monthly_utilities: dec!(2000),
+        };
+
+        assert_eq!(shared.roommate_contribution(), dec!(6000));
+        assert_eq!(shared.total_monthly_cost(), dec!(8000));
+        assert!(shared.total_monthly_cost() < shared.monthly_cost + shared.monthly_utilities);
+        assert!(HousingType::Shared.privacy_impact() < 0);
+    }
+
+    #[test]
+    fn test_thirty_percent_rule() {
+        let flat = |monthly_cost| Housing {
+            id: "flat".to_string(),
+            housing_type: HousingType::OneBedroom,
+            location: LocationQuality::Average,
+            address: "Test Street".to_string(),
+            monthly_cost,
+            monthly_utilities: dec!(3000),
+        };
+
+        // 30% of 40,000 is 12,000
+        assert!(flat(dec!(9000)).is_affordable(dec!(40000)));
+        assert!(!flat(dec!(15000)).is_affordable(dec!(40000)));
+        assert!(!flat(dec!(9000)).is_affordable(Decimal::ZERO));
     }
 
     #[test]
@@ -221,23 +216,18 @@ mod tests {
     }
 
     #[test]
-    fn test_generate_czech_housing() {
-        let options = HousingMarket::generate_czech_housing();
-        assert!(!options.is_empty());
-
-        // Should have a range of prices
-        let min_cost = options
-            .iter()
-            .map(|h| h.total_monthly_cost())
-            .min()
-            .unwrap();
-        let max_cost = options
-            .iter()
-            .map(|h| h.total_monthly_cost())
-            .max()
-            .unwrap();
-
-        assert!(min_cost < dec!(10000)); // Cheap options available
-        assert!(max_cost > dec!(40000)); // Expensive options available
+    fn test_commute_cost_in_market_currency() {
+        assert_eq!(LocationQuality::Poor.commute_cost(&CzechMarket), dec!(3000));
+        assert_eq!(
+            LocationQuality::Premium.commute_cost(&CzechMarket),
+            dec!(400)
+        );
+
+        #[cfg(feature = "usa")]
+        {
+            // 3,000 CZK against a 3,500 CZK food minimum, on a 300 USD one
+            let usa = crate::markets::usa::UsaMarket;
+            assert_eq!(LocationQuality::Poor.commute_cost(&usa), dec!(257));
+        }
     }
 }
@@ -1,21 +1,64 @@
 //! Income sources and tracking
 
 use rust_decimal::Decimal;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// Day of the month on which income is paid out unless set otherwise
+pub const DEFAULT_PAYDAY: u8 = 25;
+
+/// Last day of the simulated month
+pub const LAST_DAY_OF_MONTH: u8 = 30;
+
+fn default_payday() -> u8 {
+    DEFAULT_PAYDAY
+}
+
+/// Tax regime chosen by a self-employed player
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum SelfEmployedRegime {
+    /// Expenses claimed as a fixed share of revenue (e.g., Czech 60% "výdajový paušál")
+    #[default]
+    ExpenseLumpSum,
+    /// Single flat monthly payment covering tax and insurance (e.g., Czech "paušální daň")
+    FlatTax,
+}
 
 /// Type of income source
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum IncomeKind {
     /// Regular employment salary
     Employment,
-    /// Freelance/contract work
-    Freelance,
+    /// Freelance/contract work, taxed under the chosen self-employment regime
+    Freelance { regime: SelfEmployedRegime },
     /// Passive income (dividends, rental income, etc.)
     Passive,
     /// One-time income (bonus, gift, etc.)
     OneTime,
 }
 
+/// Income kind as stored in saves made before self-employment regimes
+#[derive(Deserialize)]
+enum LegacyIncomeKind {
+    Freelance,
+}
+
+/// Reads an income kind, treating a bare `Freelance` as the default regime
+fn deserialize_kind<'de, D: Deserializer<'de>>(deserializer: D) -> Result<IncomeKind, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StoredKind {
+        Current(IncomeKind),
+        Legacy(LegacyIncomeKind),
+    }
+
+    Ok(match StoredKind::deserialize(deserializer)? {
+        StoredKind::Current(kind) => kind,
+        StoredKind::Legacy(LegacyIncomeKind::Freelance) => IncomeKind::Freelance {
+            regime: SelfEmployedRegime::default(),
+        },
+    })
+}
+
 /// A source of income
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Income {
@@ -24,11 +67,15 @@ pub struct Income {
     /// Display name (e.g., "Software Engineer at TechCorp")
     pub name: String,
     /// Income type
+    #[serde(deserialize_with = "deserialize_kind")]
     pub kind: IncomeKind,
     /// Gross monthly amount (before taxes)
     pub gross_monthly: Decimal,
     /// Whether this income source is currently active
     pub active: bool,
+    /// Day of the month the money arrives (1-30)
+    #[serde(default = "default_payday")]
+    pub payday_day: u8,
 }
 
 impl Income {
@@ -40,9 +87,22 @@ impl Income {
             kind,
             gross_monthly,
             active: true,
+            payday_day: DEFAULT_PAYDAY,
         }
     }
 
+    /// Sets the day of the month the income is paid
+    pub fn with_payday(mut self, day: u8) -> Result<Self, String> {
+        if !(1..=LAST_DAY_OF_MONTH).contains(&day) {
+            return Err(format!(
+                "Payday must be between 1 and {}",
+                LAST_DAY_OF_MONTH
+            ));
+        }
+        self.payday_day = day;
+        Ok(self)
+    }
+
     /// Deactivates this income source
     pub fn deactivate(&mut self) {
         self.active = false;
@@ -127,4 +187,46 @@ mod tests {
         assert_eq!(income.gross_monthly, dec!(60000));
         assert_eq!(income.annual_gross(), dec!(720000));
     }
+
+    #[test]
+    fn test_payday_must_fall_in_month() {
+        let income = Income::new(
+            "job".to_string(),
+            "Job".to_string(),
+            IncomeKind::Employment,
+            dec!(1000),
+        );
+        assert_eq!(income.payday_day, DEFAULT_PAYDAY);
+        assert_eq!(income.clone().with_payday(10).unwrap().payday_day, 10);
+        assert!(income.clone().with_payday(0).is_err());
+        assert!(income.with_payday(31).is_err());
+    }
+
+    #[test]
+    fn test_loads_freelance_income_saved_without_regime() {
+        let json =
+            r#"{"id":"gig","name":"Gig","kind":"Freelance","gross_monthly":"30000","active":true}"#;
+        let income: Income = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            income.kind,
+            IncomeKind::Freelance {
+                regime: SelfEmployedRegime::ExpenseLumpSum
+            }
+        );
+
+        let flat = Income::new(
+            "gig".to_string(),
+            "Gig".to_string(),
+            IncomeKind::Freelance {
+                regime: SelfEmployedRegime::FlatTax,
+            },
+            dec!(30000),
+        );
+        let reloaded: Income =
+            serde_json::from_str(&serde_json::to_string(&flat).unwrap()).unwrap();
+        assert_eq!(reloaded, flat);
+        let employed: Income =
+            serde_json::from_str(&json.replace("Freelance", "Employment")).unwrap();
+        assert_eq!(employed.kind, IncomeKind::Employment);
+    }
 }
@@ -0,0 +1,105 @@
+//! Insurance products that cover emergency costs
+
+use super::events::EmergencyKind;
+use super::expenses::ExpenseCategory;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Type of insurance policy
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InsuranceKind {
+    /// Car insurance (covers repairs)
+    Car,
+    /// Supplementary health insurance (covers medical bills)
+    Health,
+    /// Household insurance (covers appliances and home damage)
+    Household,
+}
+
+impl InsuranceKind {
+    /// Returns display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            InsuranceKind::Car => "Car Insurance",
+            InsuranceKind::Health => "Health Insurance",
+            InsuranceKind::Household => "Household Insurance",
+        }
+    }
+
+    /// Returns the expense category its premium is counted under
+    pub fn expense_category(&self) -> ExpenseCategory {
+        match self {
+            InsuranceKind::Car => ExpenseCategory::Transportation,
+            InsuranceKind::Health => ExpenseCategory::Health,
+            InsuranceKind::Household => ExpenseCategory::Essential,
+        }
+    }
+
+    /// Returns true if this kind of policy pays out for the emergency
+    pub fn covers(&self, emergency: EmergencyKind) -> bool {
+        matches!(
+            (self, emergency),
+            (InsuranceKind::Car, EmergencyKind::CarRepair)
+                | (InsuranceKind::Health, EmergencyKind::MedicalBill)
+                | (InsuranceKind::Household, EmergencyKind::ApplianceFailure)
+        )
+    }
+}
+
+/// An active insurance policy
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Insurance {
+    /// Unique identifier
+    pub id: String,
+    /// Policy type
+    pub kind: InsuranceKind,
+    /// Monthly premium
+    pub monthly_premium: Decimal,
+    /// Share of a covered cost paid by the insurer (0.0 - 1.0)
+    pub coverage: Decimal,
+}
+
+impl Insurance {
+    /// Creates a new insurance policy
+    pub fn new(
+        id: String,
+        kind: InsuranceKind,
+        monthly_premium: Decimal,
+        coverage: Decimal,
+    ) -> Self {
+        Insurance {
+            id,
+            kind,
+            monthly_premium,
+            coverage: coverage.clamp(Decimal::ZERO, Decimal::ONE),
+        }
+    }
+
+    /// Returns the part of an emergency cost the player still pays
+    pub fn out_of_pocket(&self, emergency: EmergencyKind, cost: Decimal) -> Decimal {
+        if self.kind.covers(emergency) {
+            cost * (Decimal::ONE - self.coverage)
+        } else {
+            cost
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_coverage_applies_to_matching_emergency() {
+        let policy = Insurance::new("car".to_string(), InsuranceKind::Car, dec!(800), dec!(0.8));
+        assert_eq!(
+            policy.out_of_pocket(EmergencyKind::CarRepair, dec!(20000)),
+            dec!(4000)
+        );
+        assert_eq!(
+            policy.out_of_pocket(EmergencyKind::MedicalBill, dec!(20000)),
+            dec!(20000)
+        );
+    }
+}
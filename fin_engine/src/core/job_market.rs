@@ -1,38 +1,48 @@
 //! Job market generation and management
 
 use super::career::{Career, CareerField, Job, JobLevel};
+use super::economy::EconomyState;
+use crate::market::MarketProfile;
+use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
+use std::collections::HashMap;
+
+/// Chance of landing a job one level above the player's qualification,
+/// before experience and the economy are taken into account
+pub const STRETCH_BASE_CHANCE: Decimal = dec!(0.4);
+
+/// Extra chance for a stretch application when the player is about to reach
+/// the next level anyway (scaled by progress toward it)
+pub const STRETCH_EXPERIENCE_BONUS: Decimal = dec!(0.4);
+
+/// Happiness lost when a job application is rejected
+pub const REJECTION_HAPPINESS_PENALTY: i8 = 5;
 
 /// Generates job offers based on market and player qualifications
 pub struct JobMarket;
 
 impl JobMarket {
-    /// Generates available jobs for Czech market
+    /// Generates the jobs advertised in the market
     /// Returns jobs that match or are slightly above player's qualifications
-    pub fn generate_czech_jobs(career: &Career) -> Vec<Job> {
-        let mut jobs = Vec::new();
-        let max_level = career.max_qualified_level();
-        let experience = career.years_experience;
-
-        // Always include entry level jobs
-        jobs.extend(Self::czech_entry_jobs());
-
-        // Add jobs up to one level above current qualification (stretch opportunities)
-        if experience >= 1 {
-            jobs.extend(Self::czech_junior_jobs());
-        }
+    /// Fewer openings are advertised at each level during a downturn.
+    pub fn generate_jobs(
+        market: &dyn MarketProfile,
+        career: &Career,
+        skills: &HashMap<CareerField, u8>,
+        economy: &EconomyState,
+    ) -> Vec<Job> {
+        let max_level = career.max_qualified_level(skills);
+        let experience = career.best_qualifying_experience(skills);
 
-        if experience >= 3 {
-            jobs.extend(Self::czech_mid_jobs());
-        }
-
-        if experience >= 6 {
-            jobs.extend(Self::czech_senior_jobs());
-        }
-
-        if experience >= 9 {
-            jobs.extend(Self::czech_lead_jobs());
-        }
+        // Entry level jobs are always listed, higher levels open up with
+        // experience (stretch opportunities)
+        let listed = |level: JobLevel| match level {
+            JobLevel::Entry => true,
+            JobLevel::Junior => experience >= 1,
+            JobLevel::Mid => experience >= 3,
+            JobLevel::Senior => experience >= 6,
+            JobLevel::Lead => experience >= 9,
+        };
 
         // Filter to show relevant jobs (current level and one above)
         let min_level_to_show = if experience >= 2 {
@@ -41,187 +51,147 @@ impl JobMarket {
             JobLevel::Entry
         };
 
-        jobs.into_iter()
+        let relevant: Vec<Job> = market
+            .job_listings()
+            .into_iter()
             .filter(|job| {
-                job.level as u8 >= min_level_to_show as u8 && job.level as u8 <= max_level as u8 + 1
+                listed(job.level)
+                    && job.level as u8 >= min_level_to_show as u8
+                    && job.level as u8 <= max_level as u8 + 1
             })
-            .collect()
-    }
+            .collect();
 
-    fn czech_entry_jobs() -> Vec<Job> {
-        vec![
-            Job::new(
-                "cz_retail_entry".to_string(),
-                "Sales Associate".to_string(),
-                CareerField::Retail,
-                JobLevel::Entry,
-                dec!(25000), // 25k CZK/month
-                Some("Local Store".to_string()),
-            ),
-            Job::new(
-                "cz_admin_entry".to_string(),
-                "Administrative Assistant".to_string(),
-                CareerField::Other("Administration".to_string()),
-                JobLevel::Entry,
-                dec!(28000),
-                Some("Office Corp".to_string()),
-            ),
-            Job::new(
-                "cz_tech_entry".to_string(),
-                "Junior IT Support".to_string(),
-                CareerField::Technology,
-                JobLevel::Entry,
-                dec!(32000),
-                Some("Tech Solutions s.r.o.".to_string()),
-            ),
-        ]
+        JobLevel::all()
+            .into_iter()
+            .flat_map(|level| {
+                let at_level: Vec<&Job> = relevant.iter().filter(|j| j.level == level).collect();
+                let openings = economy.available_openings(at_level.len());
+                at_level
+                    .into_iter()
+                    .take(openings)
+                    .cloned()
+                    .collect::<Vec<Job>>()
+            })
+            .collect()
     }
 
-    fn czech_junior_jobs() -> Vec<Job> {
-        vec![
-            Job::new(
-                "cz_dev_junior".to_string(),
-                "Junior Software Developer".to_string(),
-                CareerField::Technology,
-                JobLevel::Junior,
-                dec!(45000),
-                Some("CodeCraft Prague".to_string()),
-            ),
-            Job::new(
-                "cz_accountant_junior".to_string(),
-                "Junior Accountant".to_string(),
-                CareerField::Finance,
-                JobLevel::Junior,
-                dec!(38000),
-                Some("Finance Group".to_string()),
-            ),
-            Job::new(
-                "cz_teacher_junior".to_string(),
-                "Elementary School Teacher".to_string(),
-                CareerField::Education,
-                JobLevel::Junior,
-                dec!(35000),
-                Some("Praha Elementary".to_string()),
-            ),
-        ]
+    /// Generates the jobs open to a player just starting out
+    /// Only positions the player qualifies for without work history are
+    /// offered, so a degree in a field unlocks junior roles in it.
+    pub fn generate_starting_jobs(
+        market: &dyn MarketProfile,
+        skills: &HashMap<CareerField, u8>,
+        economy: &EconomyState,
+    ) -> Vec<Job> {
+        let career = Career::new();
+        Self::generate_jobs(market, &career, skills, economy)
+            .into_iter()
+            .filter(|job| career.qualifies_for(job, skills))
+            .collect()
     }
 
-    fn czech_mid_jobs() -> Vec<Job> {
-        vec![
-            Job::new(
-                "cz_dev_mid".to_string(),
-                "Software Developer".to_string(),
-                CareerField::Technology,
-                JobLevel::Mid,
-                dec!(65000),
-                Some("TechCorp Prague".to_string()),
-            ),
-            Job::new(
-                "cz_accountant_mid".to_string(),
-                "Accountant".to_string(),
-                CareerField::Finance,
-                JobLevel::Mid,
-                dec!(52000),
-                Some("KPMG Czech".to_string()),
-            ),
-            Job::new(
-                "cz_manager_mid".to_string(),
-                "Team Manager".to_string(),
-                CareerField::Manufacturing,
-                JobLevel::Mid,
-                dec!(58000),
-                Some("Škoda Auto".to_string()),
-            ),
-            Job::new(
-                "cz_nurse_mid".to_string(),
-                "Registered Nurse".to_string(),
-                CareerField::Healthcare,
-                JobLevel::Mid,
-                dec!(48000),
-                Some("Motol Hospital".to_string()),
-            ),
-        ]
+    /// Raises any salary below the market's minimum wage (prorated by hours) to it
+    pub fn enforce_minimum_wage(jobs: Vec<Job>, market: &dyn MarketProfile) -> Vec<Job> {
+        jobs.into_iter()
+            .map(|mut job| {
+                job.monthly_salary = job.monthly_salary.max(market.salary_floor(&job));
+                job
+            })
+            .collect()
     }
 
-    fn czech_senior_jobs() -> Vec<Job> {
-        vec![
-            Job::new(
-                "cz_dev_senior".to_string(),
-                "Senior Software Engineer".to_string(),
-                CareerField::Technology,
-                JobLevel::Senior,
-                dec!(90000),
-                Some("Avast Software".to_string()),
-            ),
-            Job::new(
-                "cz_accountant_senior".to_string(),
-                "Senior Financial Analyst".to_string(),
-                CareerField::Finance,
-                JobLevel::Senior,
-                dec!(75000),
-                Some("Česká spořitelna".to_string()),
-            ),
-            Job::new(
-                "cz_doctor_senior".to_string(),
-                "Specialist Physician".to_string(),
-                CareerField::Healthcare,
-                JobLevel::Senior,
-                dec!(85000),
-                Some("General Hospital Prague".to_string()),
-            ),
-        ]
-    }
+    /// Returns the chance (0-1) that an application for the job succeeds
+    ///
+    /// Jobs the player qualifies for are always offered. Each level above the
+    /// player's qualification divides the chance, experience gained toward the
+    /// next level raises it and a weak economy lowers it.
+    pub fn application_chance(
+        career: &Career,
+        skills: &HashMap<CareerField, u8>,
+        job: &Job,
+        economy: &EconomyState,
+    ) -> Decimal {
+        let levels_above = career.levels_above_qualification(job, skills);
+        if levels_above == 0 {
+            return Decimal::ONE;
+        }
 
-    fn czech_lead_jobs() -> Vec<Job> {
-        vec![
-            Job::new(
-                "cz_arch_lead".to_string(),
-                "Lead Software Architect".to_string(),
-                CareerField::Technology,
-                JobLevel::Lead,
-                dec!(120000),
-                Some("O2 Czech Republic".to_string()),
-            ),
-            Job::new(
-                "cz_cfo_lead".to_string(),
-                "Finance Director".to_string(),
-                CareerField::Finance,
-                JobLevel::Lead,
-                dec!(110000),
-                Some("Česká pojišťovna".to_string()),
-            ),
-            Job::new(
-                "cz_director_lead".to_string(),
-                "Operations Director".to_string(),
-                CareerField::Manufacturing,
-                JobLevel::Lead,
-                dec!(100000),
-                Some("ČEZ Group".to_string()),
-            ),
-        ]
+        let experience_factor =
+            career.progress_to_next_level(&job.field, skills) * STRETCH_EXPERIENCE_BONUS;
+        let chance = (STRETCH_BASE_CHANCE + experience_factor) / Decimal::from(levels_above)
+            * economy.phase.job_availability();
+        chance.min(Decimal::ONE)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::economy::EconomyPhase;
+    use crate::core::player::{EducationLevel, PlayerStats};
+    use crate::markets::czech::CzechMarket;
 
     #[test]
     fn test_generate_jobs_for_new_player() {
         let career = Career::new();
-        let jobs = JobMarket::generate_czech_jobs(&career);
+        let jobs = JobMarket::generate_jobs(
+            &CzechMarket,
+            &career,
+            &HashMap::new(),
+            &EconomyState::default(),
+        );
 
         // Should only show entry level jobs
         assert!(!jobs.is_empty());
         assert!(jobs.iter().all(|j| j.level == JobLevel::Entry));
     }
 
+    #[test]
+    fn test_czech_jobs_pay_minimum_wage() {
+        let market = CzechMarket;
+        let mut career = Career::new();
+        career.years_experience = 10;
+        let jobs = JobMarket::generate_jobs(
+            &CzechMarket,
+            &career,
+            &HashMap::new(),
+            &EconomyState::default(),
+        );
+        assert!(jobs
+            .iter()
+            .all(|job| market.check_minimum_wage(job).is_ok()));
+
+        // A below-minimum offer is rejected, and clamped when generated
+        let cheap = Job::new(
+            "cheap".to_string(),
+            "Intern".to_string(),
+            CareerField::Retail,
+            JobLevel::Entry,
+            dec!(12000),
+            None,
+        );
+        assert!(market.check_minimum_wage(&cheap).is_err());
+        let clamped = JobMarket::enforce_minimum_wage(vec![cheap.clone()], &market);
+        assert_eq!(clamped[0].monthly_salary, market.minimum_wage());
+
+        // Part-time work only needs the prorated minimum
+        let half = cheap.part_time(dec!(0.5)).unwrap();
+        assert_eq!(half.monthly_salary, dec!(6000));
+        assert_eq!(market.salary_floor(&half), dec!(9450));
+        assert!(market.check_minimum_wage(&half).is_err());
+    }
+
     #[test]
     fn test_generate_jobs_with_experience() {
         let mut career = Career::new();
         career.years_experience = 5; // Qualifies for Mid level
 
-        let jobs = JobMarket::generate_czech_jobs(&career);
+        let jobs = JobMarket::generate_jobs(
+            &CzechMarket,
+            &career,
+            &HashMap::new(),
+            &EconomyState::default(),
+        );
 
         // Should show Junior and Mid level jobs (one above)
         assert!(jobs.iter().any(|j| j.level == JobLevel::Junior));
@@ -231,21 +201,84 @@ mod tests {
         assert!(!jobs.iter().any(|j| j.level == JobLevel::Entry));
     }
 
+    #[test]
+    fn test_recession_reduces_openings() {
+        let mut career = Career::new();
+        career.years_experience = 5;
+
+        let boom = JobMarket::generate_jobs(
+            &CzechMarket,
+            &career,
+            &HashMap::new(),
+            &EconomyState::default(),
+        );
+        let bust = JobMarket::generate_jobs(
+            &CzechMarket,
+            &career,
+            &HashMap::new(),
+            &EconomyState::new(EconomyPhase::Recession),
+        );
+
+        assert!(bust.len() < boom.len());
+        assert!(!bust.is_empty());
+    }
+
     #[test]
     fn test_salary_progression() {
         let career = Career::new();
 
         // Entry level salary
-        let entry_jobs = JobMarket::generate_czech_jobs(&career);
+        let entry_jobs = JobMarket::generate_jobs(
+            &CzechMarket,
+            &career,
+            &HashMap::new(),
+            &EconomyState::default(),
+        );
         let entry_max = entry_jobs.iter().map(|j| j.monthly_salary).max().unwrap();
 
         // Mid level salary
         let mut mid_career = Career::new();
         mid_career.years_experience = 5;
-        let mid_jobs = JobMarket::generate_czech_jobs(&mid_career);
+        let mid_jobs = JobMarket::generate_jobs(
+            &CzechMarket,
+            &mid_career,
+            &HashMap::new(),
+            &EconomyState::default(),
+        );
         let mid_max = mid_jobs.iter().map(|j| j.monthly_salary).max().unwrap();
 
         // Senior should pay more than entry
         assert!(mid_max > entry_max);
     }
+
+    #[test]
+    fn test_education_unlocks_better_starting_jobs() {
+        let best_salary = |level: EducationLevel| {
+            let mut player = PlayerStats::new(22, None, None);
+            player.graduate(level, &CareerField::Technology);
+            JobMarket::generate_starting_jobs(
+                &CzechMarket,
+                &player.skills,
+                &EconomyState::default(),
+            )
+            .iter()
+            .map(|j| j.monthly_salary)
+            .max()
+            .unwrap()
+        };
+
+        let high_school = best_salary(EducationLevel::HighSchool);
+        let bachelor = best_salary(EducationLevel::Bachelor);
+        let master = best_salary(EducationLevel::Master);
+        assert_eq!(high_school, dec!(32000));
+        assert_eq!(bachelor, dec!(45000));
+        assert_eq!(master, dec!(50000));
+
+        let high_school_jobs = JobMarket::generate_starting_jobs(
+            &CzechMarket,
+            &HashMap::new(),
+            &EconomyState::default(),
+        );
+        assert!(high_school_jobs.iter().all(|j| j.level == JobLevel::Entry));
+    }
 }
@@ -0,0 +1,240 @@
+//! Amortizing loans and mortgages
+
+use super::expenses::ExpenseCategory;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Typical repayment term of a student loan
+pub const STUDENT_LOAN_TERM_MONTHS: u32 = 120;
+
+/// Longest repayment term a lender offers (50 years)
+pub const MAX_LOAN_TERM_MONTHS: u32 = 600;
+
+/// Type of loan
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LoanKind {
+    /// Secured against a home, long term
+    Mortgage,
+    /// Unsecured consumer loan
+    Personal,
+    /// Debt carried over from studies
+    Student,
+    /// Short-term financing of a purchase (car, furniture)
+    Installment,
+}
+
+impl LoanKind {
+    /// Returns display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            LoanKind::Mortgage => "Mortgage",
+            LoanKind::Personal => "Personal Loan",
+            LoanKind::Student => "Student Loan",
+            LoanKind::Installment => "Installment Plan",
+        }
+    }
+
+    /// Returns the expense category its installments are counted under
+    pub fn expense_category(&self) -> ExpenseCategory {
+        match self {
+            LoanKind::Mortgage => ExpenseCategory::Essential,
+            LoanKind::Student => ExpenseCategory::Education,
+            LoanKind::Personal | LoanKind::Installment => ExpenseCategory::Other,
+        }
+    }
+}
+
+/// A fixed-rate loan repaid in equal monthly installments
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Loan {
+    /// Unique identifier
+    pub id: String,
+    /// Display name
+    pub name: String,
+    /// Loan type
+    pub kind: LoanKind,
+    /// Outstanding principal
+    pub balance: Decimal,
+    /// Annual interest rate, locked when the loan is originated
+    pub annual_rate: Decimal,
+    /// Fixed monthly installment
+    pub monthly_payment: Decimal,
+    /// Installments left to pay
+    pub months_remaining: u32,
+}
+
+impl Loan {
+    /// Originates a loan, computing the annuity installment for the term
+    pub fn new(
+        id: String,
+        name: String,
+        kind: LoanKind,
+        principal: Decimal,
+        annual_rate: Decimal,
+        term_months: u32,
+    ) -> Result<Self, String> {
+        if principal <= Decimal::ZERO {
+            return Err("Loan principal must be positive".to_string());
+        }
+        if term_months == 0 {
+            return Err("Loan term must be at least one month".to_string());
+        }
+        if term_months > MAX_LOAN_TERM_MONTHS {
+            return Err(format!(
+                "Loan term cannot exceed {} months",
+                MAX_LOAN_TERM_MONTHS
+            ));
+        }
+        if annual_rate < Decimal::ZERO {
+            return Err("Interest rate cannot be negative".to_string());
+        }
+
+        Ok(Loan {
+            id,
+            name,
+            kind,
+            balance: principal,
+            monthly_payment: Self::annuity_payment(principal, annual_rate, term_months)?,
+            annual_rate,
+            months_remaining: term_months,
+        })
+    }
+
+    /// Monthly installment that repays `principal` over `term_months`
+    /// payment = P * r / (1 - (1 + r)^-n), or P / n without interest;
+    /// errors when the rate and term are too large to compute
+    pub fn annuity_payment(
+        principal: Decimal,
+        annual_rate: Decimal,
+        term_months: u32,
+    ) -> Result<Decimal, String> {
+        if term_months == 0 {
+            return Err("Loan term must be at least one month".to_string());
+        }
+        let monthly_rate = annual_rate / Decimal::from(12);
+        if monthly_rate == Decimal::ZERO {
+            return Ok((principal / Decimal::from(term_months)).round_dp(2));
+        }
+
+        let too_large = || "Loan rate and term are too large to compute".to_string();
+        let mut growth = Decimal::ONE;
+        for _ in 0..term_months {
+            growth = growth
+                .checked_mul(Decimal::ONE + monthly_rate)
+                .ok_or_else(too_large)?;
+        }
+        principal
+            .checked_mul(monthly_rate)
+            .and_then(|x| x.checked_mul(growth))
+            .and_then(|x| x.checked_div(growth - Decimal::ONE))
+            .map(|payment| payment.round_dp(2))
+            .ok_or_else(too_large)
+    }
+
+    /// Interest accrued on the outstanding balance this month
+    pub fn monthly_interest(&self) -> Decimal {
+        (self.balance * self.annual_rate / Decimal::from(12)).round_dp(2)
+    }
+
+    /// Installment due this month (the last one only clears what is left)
+    pub fn payment_due(&self) -> Decimal {
+        if self.is_paid_off() {
+            return Decimal::ZERO;
+        }
+        self.monthly_payment
+            .min(self.balance + self.monthly_interest())
+    }
+
+    /// Applies one installment: interest first, the rest reduces principal
+    /// Returns the amount paid.
+    pub fn make_payment(&mut self) -> Decimal {
+        let payment = self.payment_due();
+        if payment == Decimal::ZERO {
+            return Decimal::ZERO;
+        }
+        let principal = payment - self.monthly_interest();
+        self.balance = (self.balance - principal).max(Decimal::ZERO);
+        self.months_remaining = self.months_remaining.saturating_sub(1);
+        if self.months_remaining == 0 {
+            self.balance = Decimal::ZERO;
+        }
+        payment
+    }
+
+    /// Returns true once the loan is fully repaid
+    pub fn is_paid_off(&self) -> bool {
+        self.balance <= Decimal::ZERO
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn mortgage(rate: Decimal) -> Loan {
+        Loan::new(
+            "home".to_string(),
+            "Mortgage".to_string(),
+            LoanKind::Mortgage,
+            dec!(3000000),
+            rate,
+            360,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_annuity_payment() {
+        // 3M over 30 years at 6% is about 17,987 per month
+        assert_eq!(mortgage(dec!(0.06)).monthly_payment, dec!(17986.52));
+        // Without interest the principal is simply split
+        assert_eq!(mortgage(Decimal::ZERO).monthly_payment, dec!(8333.33));
+    }
+
+    #[test]
+    fn test_loan_is_repaid_over_term() {
+        let mut loan = Loan::new(
+            "car".to_string(),
+            "Car Loan".to_string(),
+            LoanKind::Personal,
+            dec!(120000),
+            dec!(0.08),
+            24,
+        )
+        .unwrap();
+
+        let mut total_paid = Decimal::ZERO;
+        for _ in 0..24 {
+            total_paid += loan.make_payment();
+        }
+
+        assert!(loan.is_paid_off());
+        assert!(total_paid > dec!(120000));
+        assert_eq!(loan.make_payment(), Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_invalid_loans_rejected() {
+        let loan = |principal, term| {
+            Loan::new(
+                "x".to_string(),
+                "X".to_string(),
+                LoanKind::Personal,
+                principal,
+                dec!(0.05),
+                term,
+            )
+        };
+        assert!(loan(Decimal::ZERO, 12).is_err());
+        assert!(loan(dec!(1000), 0).is_err());
+        assert!(loan(dec!(1000), MAX_LOAN_TERM_MONTHS).is_ok());
+        assert!(loan(dec!(1000), MAX_LOAN_TERM_MONTHS + 1).is_err());
+    }
+
+    #[test]
+    fn test_annuity_payment_overflow_is_an_error() {
+        assert!(Loan::annuity_payment(dec!(1000), dec!(1000), MAX_LOAN_TERM_MONTHS).is_err());
+        assert!(Loan::annuity_payment(dec!(1000), dec!(0.05), 0).is_err());
+    }
+}
@@ -1,26 +1,78 @@
 //! Core game state structures
 
 pub mod accounts;
+pub mod achievements;
+pub mod builder;
+pub mod capital_gains;
 pub mod career;
+pub mod contribution_advice;
+pub mod credit_card;
+pub mod debt_advice;
+pub mod difficulty;
+pub mod economy;
+pub mod events;
 pub mod expenses;
 pub mod financial_state;
 pub mod game_state;
+pub mod goals;
+pub mod history;
 pub mod housing;
 pub mod income;
+pub mod insurance;
 pub mod job_market;
+pub mod loans;
+pub mod partner;
 pub mod phase;
 pub mod player;
+pub mod projection;
+pub mod purchases;
+pub mod rent_vs_buy;
+pub mod rng;
+pub mod simulation;
+pub mod tax_year;
 pub mod time;
+pub mod tutorial;
 
 // Re-export commonly used types
-pub use accounts::{Account, AccountKind, Asset, AssetCategory};
-pub use career::{Career, CareerField, Job, JobLevel};
-pub use expenses::{BudgetAllocation, Expense, ExpenseCategory};
-pub use financial_state::FinancialState;
-pub use game_state::GameState;
-pub use housing::{Housing, HousingMarket, HousingType, LocationQuality};
-pub use income::{Income, IncomeKind};
+pub use accounts::{Account, AccountKind, Allocation, Asset, AssetCategory, GROSS_RENTAL_YIELD};
+pub use achievements::{Achievement, SAVINGS_MILESTONE};
+pub use builder::{GameStateBuilder, DEFAULT_MARKET_ID};
+pub use capital_gains::{GainKind, RealizedGain};
+pub use career::{Career, CareerField, Job, JobLevel, PART_TIME_HOURS};
+pub use contribution_advice::{suggest_contributions, Suggestion, SuggestionReason};
+pub use credit_card::{CreditCard, CREDIT_CARD_ANNUAL_RATE, CREDIT_CARD_MIN_PAYMENT_FLOOR};
+pub use debt_advice::{debt_payoff_advice, Advice, AdviceAction};
+pub use difficulty::Difficulty;
+pub use economy::{EconomyPhase, EconomyState, DEFAULT_ANNUAL_RETURN};
+pub use events::{EmergencyKind, Event, WindfallKind, CHILD_BIRTH_COST, CHILD_MONTHLY_COST};
+pub use expenses::{
+    BudgetAllocation, BudgetPreset, Expense, ExpenseCategory, DEFAULT_DUE_DAY,
+    HEALTHY_LIVING_BUDGET, LEISURE_BUDGET, MAX_PURCHASE_HAPPINESS, PURCHASE_HAPPINESS_UNIT,
+};
+pub use financial_state::{
+    FinancialState, NetWorthBreakdown, DEFAULT_WITHDRAWAL_RATE, EMERGENCY_FUND_SCHEDULE_MONTHS,
+};
+pub use game_state::{GameState, MARKET_MIGRATIONS, MAX_PLAYER_AGE, MIN_PLAYER_AGE};
+pub use goals::{Goal, NET_WORTH_MILESTONE};
+pub use history::{MonthlySnapshot, WellbeingDelta, WellbeingEffect, WellbeingFactor};
+pub use housing::{
+    Housing, HousingType, LocationQuality, AFFORDABLE_HOUSING_SHARE, DEPOSIT_WEAR_AND_TEAR,
+    LANDLORD_RENT_PREMIUM,
+};
+pub use income::{Income, IncomeKind, SelfEmployedRegime, DEFAULT_PAYDAY};
+pub use insurance::{Insurance, InsuranceKind};
 pub use job_market::JobMarket;
+pub use loans::{Loan, LoanKind, MAX_LOAN_TERM_MONTHS, STUDENT_LOAN_TERM_MONTHS};
+pub use partner::{HouseholdFinances, Partner, PARTNER_SHARED_COST, WEDDING_COST};
 pub use phase::GamePhase;
-pub use player::PlayerStats;
+pub use player::{EducationLevel, Gender, PlayerStats};
+pub use projection::{PercentileBand, ProjectionResult};
+pub use purchases::{
+    PurchaseOption, INSTALLMENT_DOWN_PAYMENT, INSTALLMENT_MONTHS, PURCHASE_OPTIONS,
+};
+pub use rent_vs_buy::{compare_rent_vs_buy, RentVsBuyResult};
+pub use rng::GameRng;
+pub use simulation::{simulate, SavingsRateStrategy, Strategy};
+pub use tax_year::TaxYear;
 pub use time::{GameTime, Month};
+pub use tutorial::TutorialStep;
@@ -0,0 +1,53 @@
+//! Partner and household finances
+
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+
+/// One-time cost of a wedding (CZK, see `MarketProfile::scale_czk`)
+pub const WEDDING_COST: Decimal = dec!(150000);
+
+/// Extra monthly household costs when living with a partner (CZK, see `MarketProfile::scale_czk`)
+pub const PARTNER_SHARED_COST: Decimal = dec!(6000);
+
+/// How a couple manages money
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum HouseholdFinances {
+    /// All income pooled, taxes filed jointly where the market allows it
+    #[default]
+    Joint,
+    /// Each keeps their own income; the partner pays half of essential costs
+    Separate,
+}
+
+impl HouseholdFinances {
+    /// Returns display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            HouseholdFinances::Joint => "Joint Finances",
+            HouseholdFinances::Separate => "Separate Finances",
+        }
+    }
+}
+
+/// The player's partner
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Partner {
+    /// Partner's name (optional, for personalization)
+    pub name: Option<String>,
+    /// Partner's gross monthly income
+    pub gross_monthly: Decimal,
+    /// How the couple manages money
+    pub finances: HouseholdFinances,
+}
+
+impl Partner {
+    /// Creates a new partner
+    pub fn new(name: Option<String>, gross_monthly: Decimal, finances: HouseholdFinances) -> Self {
+        Partner {
+            name,
+            gross_monthly,
+            finances,
+        }
+    }
+}
@@ -22,6 +22,15 @@ pub enum GamePhase {
     /// Summary of net worth change, happiness levels, and burnout impact.
     /// Player reviews what happened during the month.
     Review,
+
+    /// Retirement (Drawdown)
+    /// Employment has ended. Each month living costs are covered by passive
+    /// income and withdrawals from accounts.
+    Retirement,
+
+    /// Bankruptcy (Game Over)
+    /// Debts grew beyond any realistic way to repay them for too long.
+    Bankrupt,
 }
 
 impl GamePhase {
@@ -40,12 +49,24 @@ impl GamePhase {
         matches!(self, GamePhase::Review)
     }
 
+    /// Returns true if the player has retired
+    pub fn is_retirement(&self) -> bool {
+        matches!(self, GamePhase::Retirement)
+    }
+
+    /// Returns true if the game ended in bankruptcy
+    pub fn is_bankrupt(&self) -> bool {
+        matches!(self, GamePhase::Bankrupt)
+    }
+
     /// Gets the phase name for display
     pub fn name(&self) -> &'static str {
         match self {
             GamePhase::Planning => "Monthly Planning",
             GamePhase::Execution { .. } => "Execution",
             GamePhase::Review => "Monthly Review",
+            GamePhase::Retirement => "Retirement",
+            GamePhase::Bankrupt => "Bankrupt",
         }
     }
 
@@ -55,6 +76,8 @@ impl GamePhase {
             GamePhase::Planning => GamePhase::Execution { current_day: 1 },
             GamePhase::Execution { .. } => GamePhase::Review,
             GamePhase::Review => GamePhase::Planning,
+            GamePhase::Retirement => GamePhase::Retirement,
+            GamePhase::Bankrupt => GamePhase::Bankrupt,
         }
     }
 }
@@ -80,4 +103,19 @@ mod tests {
         let back_to_planning = review.next();
         assert!(back_to_planning.is_planning());
     }
+
+    #[test]
+    fn test_retirement_is_terminal() {
+        let retirement = GamePhase::Retirement;
+        assert!(retirement.is_retirement());
+        assert_eq!(retirement.next(), GamePhase::Retirement);
+    }
+
+    #[test]
+    fn test_bankruptcy_is_terminal() {
+        let bankrupt = GamePhase::Bankrupt;
+        assert!(bankrupt.is_bankrupt());
+        assert!(!bankrupt.is_retirement());
+        assert_eq!(bankrupt.next(), GamePhase::Bankrupt);
+    }
 }
@@ -1,8 +1,120 @@
 //! Player statistics and behavioral tracking
 
+use super::career::{CareerField, Job};
+use super::expenses::{ExpenseCategory, MAX_PURCHASE_HAPPINESS};
+use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Highest skill level in a career field
+pub const MAX_SKILL: u8 = 100;
+
+/// Education spending needed for one skill point (CZK, see `MarketProfile::scale_czk`)
+pub const EDUCATION_COST_PER_SKILL_POINT: Decimal = dec!(2000);
+
+/// Skill points gained in the job's field for each month worked
+pub const ON_THE_JOB_SKILL_PER_MONTH: u8 = 1;
+
+/// Burnout recovered each month of a sabbatical
+pub const SABBATICAL_BURNOUT_RECOVERY: i8 = 8;
+
+/// Extra skill points for each sabbatical month spent studying
+pub const SABBATICAL_STUDY_SKILL: u8 = 2;
+
+/// Extra annual raise at maximum skill (scaled linearly with skill)
+pub const MAX_SKILL_RAISE_BONUS: Decimal = dec!(0.02);
+
+/// Weight of the latest month in the financial peace trend (exponential moving average)
+pub const PEACE_TREND_WEIGHT: f32 = 0.3;
+
+/// Lowest possible credit score
+pub const MIN_CREDIT_SCORE: u16 = 300;
+
+/// Highest possible credit score
+pub const MAX_CREDIT_SCORE: u16 = 850;
+
+/// Credit score of a player without any credit history
+pub const STARTING_CREDIT_SCORE: u16 = 650;
+
+/// Score below which lenders refuse new loans
+pub const MIN_CREDIT_SCORE_FOR_LOAN: u16 = 580;
+
+/// Score gained for each month all debt payments are made on time
+pub const CREDIT_SCORE_ON_TIME_GAIN: u16 = 3;
+
+/// Score lost for each month ending overdrawn with payments missed
+pub const CREDIT_SCORE_MISSED_PAYMENT_PENALTY: u16 = 40;
+
+/// Highest education completed before the game starts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum EducationLevel {
+    /// Secondary school only
+    #[default]
+    HighSchool,
+    /// Three-year university degree
+    Bachelor,
+    /// Five-year university degree
+    Master,
+}
+
+impl EducationLevel {
+    /// Returns all education levels
+    pub fn all() -> [EducationLevel; 3] {
+        [
+            EducationLevel::HighSchool,
+            EducationLevel::Bachelor,
+            EducationLevel::Master,
+        ]
+    }
+
+    /// Returns display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            EducationLevel::HighSchool => "High School",
+            EducationLevel::Bachelor => "Bachelor's Degree",
+            EducationLevel::Master => "Master's Degree",
+        }
+    }
+
+    /// Returns the skill a graduate starts with in their field of study
+    /// Every `SKILL_POINTS_PER_YEAR` counts as a year of experience when applying.
+    pub fn starting_skill(&self) -> u8 {
+        match self {
+            EducationLevel::HighSchool => 0,
+            EducationLevel::Bachelor => 60,
+            EducationLevel::Master => 90,
+        }
+    }
+
+    /// Returns true if the level comes with a field of study
+    pub fn has_field(&self) -> bool {
+        !matches!(self, EducationLevel::HighSchool)
+    }
+}
+
+/// Player's gender, used where a market's pension rules differ by it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Gender {
+    Female,
+    Male,
+}
+
+impl Gender {
+    /// Returns all genders
+    pub fn all() -> [Gender; 2] {
+        [Gender::Female, Gender::Male]
+    }
+
+    /// Returns display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Gender::Female => "Female",
+            Gender::Male => "Male",
+        }
+    }
+}
 
 /// Player's behavioral and demographic statistics
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -13,6 +125,10 @@ pub struct PlayerStats {
     /// Player's name (optional, for personalization)
     pub name: Option<String>,
 
+    /// Player's gender (optional, markets use their general rules without it)
+    #[serde(default)]
+    pub gender: Option<Gender>,
+
     /// Happiness level (0-100)
     /// High savings rates decrease this; leisure spending increases it
     /// Low happiness triggers "Revenge Spending"
@@ -22,6 +138,11 @@ pub struct PlayerStats {
     /// High burnout affects decision-making and increases revenge spending risk
     pub burnout: u8,
 
+    /// Physical health (0-100)
+    /// Improved by Health spending; poor health raises medical event risk
+    #[serde(default = "default_health")]
+    pub health: u8,
+
     /// Frugality trait enabled
     /// When true, prevents automatic lifestyle creep with income increases
     pub frugality_enabled: bool,
@@ -29,18 +150,70 @@ pub struct PlayerStats {
     /// Human capital investment total
     /// Tracks total spent on education/skills that increase earning potential
     pub human_capital_invested: Decimal,
+
+    /// Skill level (0-100) per career field
+    /// Grows with Education spending and time on the job
+    #[serde(default)]
+    pub skills: HashMap<CareerField, u8>,
+
+    /// Number of children/dependents in the household
+    #[serde(default)]
+    pub dependents: u8,
+
+    /// Highest education completed before the game started
+    #[serde(default)]
+    pub education: EducationLevel,
+
+    /// Consecutive months spent eating on the survival-minimum food budget
+    #[serde(default)]
+    pub months_at_food_minimum: u32,
+
+    /// Moving average of the financial peace score, updated at each settlement
+    /// None until the first month is settled
+    #[serde(default)]
+    pub peace_trend: Option<f32>,
+
+    /// Credit score (300-850)
+    /// Rises with on-time debt payments, falls with missed payments and overdrafts
+    #[serde(default = "default_credit_score")]
+    pub credit_score: u16,
+}
+
+fn default_health() -> u8 {
+    70
+}
+
+fn default_credit_score() -> u16 {
+    STARTING_CREDIT_SCORE
 }
 
 impl PlayerStats {
     /// Creates new player stats with default values
-    pub fn new(age: u8, name: Option<String>) -> Self {
+    pub fn new(age: u8, name: Option<String>, gender: Option<Gender>) -> Self {
         PlayerStats {
             age,
             name,
+            gender,
             happiness: 70, // Start at moderate happiness
             burnout: 20,   // Start with low burnout
+            health: default_health(),
             frugality_enabled: false,
             human_capital_invested: Decimal::ZERO,
+            skills: HashMap::new(),
+            dependents: 0,
+            months_at_food_minimum: 0,
+            education: EducationLevel::default(),
+            peace_trend: None,
+            credit_score: STARTING_CREDIT_SCORE,
+        }
+    }
+
+    /// Records the player's education, seeding skill in the field of study
+    pub fn graduate(&mut self, level: EducationLevel, field: &CareerField) {
+        self.education = level;
+        if level.has_field() {
+            let skill = self.skill(field).max(level.starting_skill());
+            self.skills.insert(field.clone(), skill.min(MAX_SKILL));
         }
     }
 
@@ -50,6 +223,74 @@ impl PlayerStats {
         self.happiness = new_happiness.clamp(0, 100) as u8;
     }
 
+    /// Rests over a weekend day: +2 happiness (only +1 when badly burned out)
+    /// and 1 point of burnout recovered
+    pub fn weekend_rest(&mut self) {
+        let bonus = if self.burnout > 70 { 1 } else { 2 };
+        self.adjust_happiness(bonus);
+        self.adjust_burnout(-1);
+    }
+
+    /// Applies a month of work stress and self-care
+    ///
+    /// The job adds its monthly burnout (see `Job::monthly_burnout`) and its happiness impact.
+    /// Health and Lifestyle spending each relieve 2 points at their target amounts (1 point for any spending).
+    pub fn apply_work_month(
+        &mut self,
+        job: Option<&Job>,
+        health_spending: Decimal,
+        lifestyle_spending: Decimal,
+        healthy_budget: Decimal,
+        leisure_budget: Decimal,
+    ) {
+        let relief = |spent: Decimal, target: Decimal| -> i8 {
+            if spent >= target {
+                2
+            } else if spent > Decimal::ZERO {
+                1
+            } else {
+                0
+            }
+        };
+
+        let stress = job.map(|j| j.monthly_burnout() as i8).unwrap_or(0);
+        let delta = stress
+            - relief(health_spending, healthy_budget)
+            - relief(lifestyle_spending, leisure_budget);
+        self.adjust_burnout(delta);
+        if let Some(job) = job {
+            self.adjust_happiness(job.happiness_impact);
+        }
+    }
+
+    /// Adjusts health (clamped to 0-100)
+    pub fn adjust_health(&mut self, delta: i8) {
+        let new_health = (self.health as i16) + (delta as i16);
+        self.health = new_health.clamp(0, 100) as u8;
+    }
+
+    /// Applies the monthly change in health
+    ///
+    /// Spending the healthy-living amount on Health gives +2 (any Health
+    /// spending +1), while no Health spending lets health decay by 1.
+    /// Sustained high burnout and months of survival-level food cost extra.
+    pub fn apply_health_month(&mut self, health_spending: Decimal, healthy_budget: Decimal) {
+        let mut delta: i8 = if health_spending >= healthy_budget {
+            2
+        } else if health_spending > Decimal::ZERO {
+            1
+        } else {
+            -1
+        };
+        if self.burnout > 70 {
+            delta -= 2;
+        }
+        if self.months_at_food_minimum > 3 {
+            delta -= 1;
+        }
+        self.adjust_health(delta);
+    }
+
     /// Adjusts burnout (clamped to 0-100)
     pub fn adjust_burnout(&mut self, delta: i8) {
         let new_burnout = (self.burnout as i16) + (delta as i16);
@@ -57,9 +298,58 @@ impl PlayerStats {
     }
 
     /// Returns the "Financial Peace Score" (combination of happiness and low burnout)
+    /// Poor health caps the score: it can't exceed health + 30
     pub fn financial_peace_score(&self) -> u8 {
         let inverted_burnout = 100 - self.burnout;
-        ((self.happiness as u16 + inverted_burnout as u16) / 2) as u8
+        let score = (self.happiness as u16 + inverted_burnout as u16) / 2;
+        score.min(self.health as u16 + 30) as u8
+    }
+
+    /// Folds this month's financial peace score into the trend
+    pub fn update_peace_trend(&mut self) {
+        let score = self.financial_peace_score() as f32;
+        self.peace_trend = Some(match self.peace_trend {
+            Some(trend) => trend + PEACE_TREND_WEIGHT * (score - trend),
+            None => score,
+        });
+    }
+
+    /// Returns the smoothed financial peace score
+    /// Falls back to the current score before the first settlement.
+    pub fn financial_peace_trend(&self) -> u8 {
+        self.peace_trend
+            .map(|trend| trend.round().clamp(0.0, 100.0) as u8)
+            .unwrap_or_else(|| self.financial_peace_score())
+    }
+
+    /// Updates the credit score after a month of payments
+    /// Ending the month overdrawn counts as missed payments; otherwise every
+    /// month with debt payments due builds the score.
+    pub fn record_credit_month(&mut self, payments_due: bool, overdrawn: bool) {
+        self.credit_score = if overdrawn {
+            self.credit_score
+                .saturating_sub(CREDIT_SCORE_MISSED_PAYMENT_PENALTY)
+        } else if payments_due {
+            self.credit_score + CREDIT_SCORE_ON_TIME_GAIN
+        } else {
+            self.credit_score
+        }
+        .clamp(MIN_CREDIT_SCORE, MAX_CREDIT_SCORE);
+    }
+
+    /// Returns true if lenders will offer new loans
+    pub fn can_borrow(&self) -> bool {
+        self.credit_score >= MIN_CREDIT_SCORE_FOR_LOAN
+    }
+
+    /// Extra annual interest lenders charge for the credit score
+    pub fn credit_rate_premium(&self) -> Decimal {
+        match self.credit_score {
+            740.. => Decimal::ZERO,
+            670..=739 => dec!(0.005),
+            580..=669 => dec!(0.015),
+            _ => dec!(0.03),
+        }
     }
 
     /// Returns true if player is at risk of revenge spending
@@ -67,14 +357,97 @@ impl PlayerStats {
         self.happiness < 40 || self.burnout > 70
     }
 
+    /// Applies the monthly happiness effect of food quality
+    ///
+    /// Budgeting above the survival minimum gives a small bonus with
+    /// diminishing returns (+1 at 20% extra, +2 at 50%, +3 at double).
+    /// Staying at the minimum is fine for a while, but after three months
+    /// in a row it costs 1 happiness per month.
+    pub fn apply_food_quality(&mut self, food_budget: Decimal, survival_minimum: Decimal) {
+        if food_budget <= survival_minimum || survival_minimum <= Decimal::ZERO {
+            self.months_at_food_minimum += 1;
+            if self.months_at_food_minimum > 3 {
+                self.adjust_happiness(-1);
+            }
+            return;
+        }
+
+        self.months_at_food_minimum = 0;
+        let extra_ratio = (food_budget - survival_minimum) / survival_minimum;
+        let bonus = if extra_ratio >= dec!(1.0) {
+            3
+        } else if extra_ratio >= dec!(0.5) {
+            2
+        } else if extra_ratio >= dec!(0.2) {
+            1
+        } else {
+            0
+        };
+        self.adjust_happiness(bonus);
+    }
+
+    /// Applies the monthly happiness bought by spending
+    ///
+    /// Each category adds its `happiness_multiplier` times the square root of
+    /// its spending in units of `happiness_unit` (`PURCHASE_HAPPINESS_UNIT` in the
+    /// market currency), so every extra unit buys less than the one before.
+    /// Whole points count, up to `MAX_PURCHASE_HAPPINESS`.
+    pub fn apply_purchases(
+        &mut self,
+        spending: &[(ExpenseCategory, Decimal)],
+        happiness_unit: Decimal,
+    ) {
+        let points: f64 = spending
+            .iter()
+            .filter(|(_, spent)| *spent > Decimal::ZERO)
+            .map(|(category, spent)| {
+                let units = (spent / happiness_unit).to_f64().unwrap_or(0.0);
+                category.happiness_multiplier() as f64 * units.sqrt()
+            })
+            .sum();
+        let bonus = (points.floor() as i8).min(MAX_PURCHASE_HAPPINESS);
+        self.adjust_happiness(bonus);
+    }
+
     /// Ages the player by one year
     pub fn age_one_year(&mut self) {
         self.age += 1;
     }
 
     /// Invests in human capital (education, courses, etc.)
-    pub fn invest_human_capital(&mut self, amount: Decimal) {
+    /// Every `cost_per_point` spent (see `EDUCATION_COST_PER_SKILL_POINT`) adds a
+    /// skill point in the given field; spending without a field only counts
+    /// toward the total.
+    pub fn invest_human_capital(
+        &mut self,
+        amount: Decimal,
+        field: Option<&CareerField>,
+        cost_per_point: Decimal,
+    ) {
+        let points_before = (self.human_capital_invested / cost_per_point).floor();
         self.human_capital_invested += amount;
+        let points_after = (self.human_capital_invested / cost_per_point).floor();
+
+        if let Some(field) = field {
+            let points = (points_after - points_before).to_u8().unwrap_or(MAX_SKILL);
+            self.gain_skill(field, points);
+        }
+    }
+
+    /// Raises skill in a field (capped at `MAX_SKILL`)
+    pub fn gain_skill(&mut self, field: &CareerField, points: u8) {
+        let skill = self.skills.entry(field.clone()).or_insert(0);
+        *skill = skill.saturating_add(points).min(MAX_SKILL);
+    }
+
+    /// Returns skill level in a field (0 if never trained)
+    pub fn skill(&self, field: &CareerField) -> u8 {
+        self.skills.get(field).copied().unwrap_or(0)
+    }
+
+    /// Returns the extra annual raise earned by skill in the job's field
+    pub fn skill_raise_bonus(&self, field: &CareerField) -> Decimal {
+        MAX_SKILL_RAISE_BONUS * Decimal::from(self.skill(field)) / Decimal::from(MAX_SKILL)
     }
 
     /// Calculates income multiplier based on human capital investment
@@ -90,10 +463,12 @@ impl PlayerStats {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::career::JobLevel;
+    use crate::core::expenses::{HEALTHY_LIVING_BUDGET, LEISURE_BUDGET, PURCHASE_HAPPINESS_UNIT};
 
     #[test]
     fn test_player_creation() {
-        let player = PlayerStats::new(25, Some("Alice".to_string()));
+        let player = PlayerStats::new(25, Some("Alice".to_string()), None);
         assert_eq!(player.age, 25);
         assert_eq!(player.name, Some("Alice".to_string()));
         assert_eq!(player.happiness, 70);
@@ -102,7 +477,7 @@ mod tests {
 
     #[test]
     fn test_happiness_adjustment() {
-        let mut player = PlayerStats::new(25, None);
+        let mut player = PlayerStats::new(25, None, None);
         player.adjust_happiness(20);
         assert_eq!(player.happiness, 90);
 
@@ -115,16 +490,25 @@ mod tests {
 
     #[test]
     fn test_financial_peace_score() {
-        let mut player = PlayerStats::new(25, None);
+        let mut player = PlayerStats::new(25, None, None);
         player.happiness = 80;
         player.burnout = 20;
         // (80 + (100-20)) / 2 = 160 / 2 = 80
         assert_eq!(player.financial_peace_score(), 80);
     }
 
+    #[test]
+    fn test_low_health_caps_peace_score() {
+        let mut player = PlayerStats::new(25, None, None);
+        player.happiness = 80;
+        player.burnout = 20;
+        player.health = 30;
+        assert_eq!(player.financial_peace_score(), 60);
+    }
+
     #[test]
     fn test_revenge_spending_risk() {
-        let mut player = PlayerStats::new(25, None);
+        let mut player = PlayerStats::new(25, None, None);
         player.happiness = 50;
         player.burnout = 30;
         assert!(!player.is_revenge_spending_risk());
@@ -137,13 +521,222 @@ mod tests {
         assert!(player.is_revenge_spending_risk());
     }
 
+    #[test]
+    fn test_food_quality() {
+        let mut player = PlayerStats::new(25, None, None);
+        player.apply_food_quality(dec!(3800), dec!(3500));
+        assert_eq!(player.happiness, 70); // Under 20% extra, no bonus
+
+        player.apply_food_quality(dec!(7000), dec!(3500));
+        assert_eq!(player.happiness, 73);
+
+        player.apply_food_quality(dec!(20000), dec!(3500));
+        assert_eq!(player.happiness, 76); // Capped at +3
+
+        // Three months at the minimum are tolerated, the fourth hurts
+        for _ in 0..3 {
+            player.apply_food_quality(dec!(3500), dec!(3500));
+        }
+        assert_eq!(player.happiness, 76);
+        player.apply_food_quality(dec!(3500), dec!(3500));
+        assert_eq!(player.happiness, 75);
+    }
+
     #[test]
     fn test_human_capital() {
-        let mut player = PlayerStats::new(25, None);
-        player.invest_human_capital(dec!(50000));
+        let mut player = PlayerStats::new(25, None, None);
+        player.invest_human_capital(dec!(50000), None, EDUCATION_COST_PER_SKILL_POINT);
         assert_eq!(player.human_capital_invested, dec!(50000));
+        assert!(player.skills.is_empty());
 
         let multiplier = player.human_capital_income_multiplier();
         assert_eq!(multiplier, dec!(1.05)); // 50k = 0.5 units = 5% increase
     }
+
+    #[test]
+    fn test_education_raises_field_skill() {
+        let mut player = PlayerStats::new(25, None, None);
+        player.invest_human_capital(
+            dec!(3000),
+            Some(&CareerField::Technology),
+            EDUCATION_COST_PER_SKILL_POINT,
+        );
+        assert_eq!(player.skill(&CareerField::Technology), 1);
+
+        // Leftover spending carries over to the next point
+        player.invest_human_capital(
+            dec!(1000),
+            Some(&CareerField::Technology),
+            EDUCATION_COST_PER_SKILL_POINT,
+        );
+        assert_eq!(player.skill(&CareerField::Technology), 2);
+        assert_eq!(player.skill(&CareerField::Retail), 0);
+
+        player.invest_human_capital(
+            dec!(1000000),
+            Some(&CareerField::Technology),
+            EDUCATION_COST_PER_SKILL_POINT,
+        );
+        assert_eq!(player.skill(&CareerField::Technology), MAX_SKILL);
+        assert_eq!(
+            player.skill_raise_bonus(&CareerField::Technology),
+            MAX_SKILL_RAISE_BONUS
+        );
+
+        // Cheaper markets pass a smaller cost per point
+        let mut player = PlayerStats::new(25, None, None);
+        player.invest_human_capital(dec!(600), Some(&CareerField::Technology), dec!(300));
+        assert_eq!(player.skill(&CareerField::Technology), 2);
+    }
+
+    #[test]
+    fn test_part_time_work_burns_out_slower() {
+        let job = Job::new(
+            "dev".to_string(),
+            "Developer".to_string(),
+            CareerField::Technology,
+            JobLevel::Senior,
+            dec!(80000),
+            None,
+        );
+        let part_time = job.clone().part_time(dec!(0.5)).unwrap();
+
+        let mut full = PlayerStats::new(30, None, None);
+        let mut half = PlayerStats::new(30, None, None);
+        for _ in 0..6 {
+            full.apply_work_month(
+                Some(&job),
+                Decimal::ZERO,
+                Decimal::ZERO,
+                HEALTHY_LIVING_BUDGET,
+                LEISURE_BUDGET,
+            );
+            half.apply_work_month(
+                Some(&part_time),
+                Decimal::ZERO,
+                Decimal::ZERO,
+                HEALTHY_LIVING_BUDGET,
+                LEISURE_BUDGET,
+            );
+        }
+        assert!(half.burnout < full.burnout);
+        assert!(half.burnout > 20);
+    }
+
+    #[test]
+    fn test_leisure_relief_follows_market_budget() {
+        let mut cheap = PlayerStats::new(30, None, None);
+        let mut dear = PlayerStats::new(30, None, None);
+        cheap.burnout = 50;
+        dear.burnout = 50;
+
+        // The same spending meets a small budget but not the Czech one
+        cheap.apply_work_month(None, Decimal::ZERO, dec!(100), dec!(75), dec!(100));
+        dear.apply_work_month(None, Decimal::ZERO, dec!(100), dec!(75), LEISURE_BUDGET);
+
+        assert_eq!(cheap.burnout, 48);
+        assert_eq!(dear.burnout, 49);
+    }
+
+    #[test]
+    fn test_graduate_seeds_field_skill() {
+        let mut player = PlayerStats::new(24, None, None);
+        player.graduate(EducationLevel::Master, &CareerField::Finance);
+        assert_eq!(player.education, EducationLevel::Master);
+        assert_eq!(
+            player.skill(&CareerField::Finance),
+            EducationLevel::Master.starting_skill()
+        );
+        assert_eq!(player.skill(&CareerField::Technology), 0);
+
+        let mut dropout = PlayerStats::new(18, None, None);
+        dropout.graduate(EducationLevel::HighSchool, &CareerField::Finance);
+        assert!(dropout.skills.is_empty());
+    }
+
+    #[test]
+    fn test_peace_trend_smooths_spikes() {
+        let mut player = PlayerStats::new(30, None, None);
+        player.happiness = 50;
+        player.burnout = 50;
+        for _ in 0..6 {
+            player.update_peace_trend();
+        }
+        assert_eq!(player.financial_peace_trend(), 50);
+
+        // One great month moves the trend far less than the raw score
+        player.happiness = 100;
+        player.burnout = 0;
+        player.update_peace_trend();
+        let raw_jump = player.financial_peace_score() - 50;
+        let trend_jump = player.financial_peace_trend() - 50;
+        assert!(trend_jump > 0);
+        assert!(trend_jump < raw_jump);
+    }
+
+    #[test]
+    fn test_credit_score_follows_payments() {
+        let mut player = PlayerStats::new(30, None, None);
+        assert_eq!(player.credit_score, STARTING_CREDIT_SCORE);
+
+        // No debt, nothing to report
+        player.record_credit_month(false, false);
+        assert_eq!(player.credit_score, STARTING_CREDIT_SCORE);
+
+        for _ in 0..40 {
+            player.record_credit_month(true, false);
+        }
+        assert_eq!(player.credit_score, 770);
+        assert_eq!(player.credit_rate_premium(), Decimal::ZERO);
+
+        for _ in 0..5 {
+            player.record_credit_month(true, true);
+        }
+        assert_eq!(player.credit_score, 570);
+        assert!(!player.can_borrow());
+
+        for _ in 0..20 {
+            player.record_credit_month(true, true);
+        }
+        assert_eq!(player.credit_score, MIN_CREDIT_SCORE);
+    }
+
+    #[test]
+    fn test_lifestyle_spending_buys_more_happiness() {
+        let mut lifestyle = PlayerStats::new(30, None, None);
+        lifestyle.happiness = 50;
+        let mut transport = lifestyle.clone();
+
+        lifestyle.apply_purchases(
+            &[(ExpenseCategory::Lifestyle, dec!(12000))],
+            PURCHASE_HAPPINESS_UNIT,
+        );
+        transport.apply_purchases(
+            &[(ExpenseCategory::Transportation, dec!(12000))],
+            PURCHASE_HAPPINESS_UNIT,
+        );
+        assert_eq!(lifestyle.happiness, 52);
+        assert_eq!(transport.happiness, 50);
+        assert!(lifestyle.happiness > transport.happiness);
+
+        // Four times the spending only doubles the effect, and the total is capped
+        let mut splurge = PlayerStats::new(30, None, None);
+        splurge.happiness = 50;
+        splurge.apply_purchases(
+            &[(ExpenseCategory::Lifestyle, dec!(3000))],
+            PURCHASE_HAPPINESS_UNIT,
+        );
+        assert_eq!(splurge.happiness, 51);
+        splurge.apply_purchases(
+            &[(ExpenseCategory::Lifestyle, dec!(300000))],
+            PURCHASE_HAPPINESS_UNIT,
+        );
+        assert_eq!(splurge.happiness, 51 + MAX_PURCHASE_HAPPINESS as u8);
+
+        // Cheaper markets buy the same happiness with a smaller unit
+        let mut frugal = PlayerStats::new(30, None, None);
+        frugal.happiness = 50;
+        frugal.apply_purchases(&[(ExpenseCategory::Lifestyle, dec!(257))], dec!(257));
+        assert_eq!(frugal.happiness, 51);
+    }
 }
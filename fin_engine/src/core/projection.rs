@@ -0,0 +1,267 @@
+//! Net worth projections (Monte Carlo)
+
+use super::financial_state::FinancialState;
+use super::rng::GameRng;
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Projected net worth percentiles at the end of a year
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PercentileBand {
+    /// Years from now (1 = end of first year)
+    pub year: u32,
+    /// Pessimistic outcome (10th percentile)
+    pub p10: Decimal,
+    /// Median outcome
+    pub p50: Decimal,
+    /// Optimistic outcome (90th percentile)
+    pub p90: Decimal,
+}
+
+/// Result of a Monte Carlo net worth projection
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProjectionResult {
+    /// One band per projected year
+    pub bands: Vec<PercentileBand>,
+}
+
+impl ProjectionResult {
+    /// Returns the band for the final projected year
+    pub fn final_band(&self) -> Option<&PercentileBand> {
+        self.bands.last()
+    }
+}
+
+impl FinancialState {
+    /// Projects net worth with randomized monthly returns
+    ///
+    /// # Arguments
+    /// * `years` - Number of years to project
+    /// * `return_mean` - Expected annual return (e.g., 0.07 for 7%)
+    /// * `return_stddev` - Annual volatility of returns (e.g., 0.15)
+    /// * `monthly_contribution` - Amount added at the end of every month
+    /// * `iterations` - Number of simulated paths
+    /// * `rng` - Seeded generator (usually the game's own)
+    pub fn project_net_worth(
+        &self,
+        years: u32,
+        return_mean: Decimal,
+        return_stddev: Decimal,
+        monthly_contribution: Decimal,
+        iterations: u32,
+        rng: &mut GameRng,
+    ) -> Result<ProjectionResult, String> {
+        if iterations == 0 {
+            return Err("Projection needs at least one iteration".to_string());
+        }
+        if return_stddev < Decimal::ZERO {
+            return Err("Return volatility cannot be negative".to_string());
+        }
+
+        let months_per_year = Decimal::from(12);
+        let monthly_mean = return_mean / months_per_year;
+        let monthly_stddev = return_stddev
+            .to_f64()
+            .ok_or("Return volatility out of range")?
+            / 12f64.sqrt();
+
+        // outcomes[year][path]
+        let mut outcomes = vec![Vec::with_capacity(iterations as usize); years as usize];
+        let start = self.net_worth();
+
+        for _ in 0..iterations {
+            let mut value = start;
+            for year_outcomes in outcomes.iter_mut() {
+                for _ in 0..12 {
+                    let shock = if monthly_stddev == 0.0 {
+                        Decimal::ZERO
+                    } else {
+                        Decimal::from_f64(monthly_stddev * rng.next_normal())
+                            .ok_or("Random return out of range")?
+                    };
+                    value = value * (Decimal::ONE + monthly_mean + shock) + monthly_contribution;
+                }
+                year_outcomes.push(value);
+            }
+        }
+
+        let bands = outcomes
+            .into_iter()
+            .enumerate()
+            .map(|(i, mut values)| {
+                values.sort();
+                PercentileBand {
+                    year: i as u32 + 1,
+                    p10: percentile(&values, 10),
+                    p50: percentile(&values, 50),
+                    p90: percentile(&values, 90),
+                }
+            })
+            .collect();
+
+        Ok(ProjectionResult { bands })
+    }
+}
+
+/// Longest horizon considered when solving for the FIRE date (100 years)
+const MAX_FIRE_MONTHS: u32 = 1200;
+
+impl FinancialState {
+    /// Estimates how many months until net worth reaches the FIRE number
+    ///
+    /// Compounds current net worth monthly at `annual_return / 12` and adds
+    /// `monthly_contribution` each month. Expenses (and therefore the FIRE
+    /// number) are held constant. Returns `None` if the target is never
+    /// reached within 100 years.
+    pub fn months_to_fire(
+        &self,
+        monthly_contribution: Decimal,
+        annual_return: Decimal,
+    ) -> Option<u32> {
+        let target = self.fire_number();
+        let monthly_rate = annual_return / Decimal::from(12);
+        let mut value = self.net_worth();
+
+        for month in 0..=MAX_FIRE_MONTHS {
+            if value >= target {
+                return Some(month);
+            }
+            let next = value * (Decimal::ONE + monthly_rate) + monthly_contribution;
+            if next <= value {
+                // Not growing, will never converge
+                return None;
+            }
+            value = next;
+        }
+
+        None
+    }
+}
+
+impl FinancialState {
+    /// Projects the invested balance with steady compound growth
+    ///
+    /// Compounds the current invested balance monthly at `annual_rate / 12`
+    /// and adds `monthly` at the end of every month for `years` years.
+    pub fn project_balance(&self, monthly: Decimal, annual_rate: Decimal, years: u32) -> Decimal {
+        let monthly_rate = annual_rate / Decimal::from(12);
+        (0..years * 12).fold(self.invested_balance(), |value, _| {
+            value * (Decimal::ONE + monthly_rate) + monthly
+        })
+    }
+}
+
+/// Nearest-rank percentile of sorted, non-empty values
+fn percentile(sorted: &[Decimal], pct: usize) -> Decimal {
+    sorted[(sorted.len() - 1) * pct / 100]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::expenses::{Expense, ExpenseCategory};
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_zero_volatility_is_deterministic() {
+        let mut state = FinancialState::new();
+        state.cash = dec!(100000);
+        let mut rng = GameRng::new(7);
+
+        let result = state
+            .project_net_worth(5, dec!(0.06), Decimal::ZERO, dec!(5000), 50, &mut rng)
+            .unwrap();
+        assert_eq!(result.bands.len(), 5);
+
+        // Plain monthly compounding with contributions
+        let mut expected = dec!(100000);
+        for _ in 0..60 {
+            expected = expected * (Decimal::ONE + dec!(0.06) / dec!(12)) + dec!(5000);
+        }
+
+        let last = result.final_band().unwrap();
+        assert_eq!(last.p10, expected);
+        assert_eq!(last.p50, expected);
+        assert_eq!(last.p90, expected);
+    }
+
+    #[test]
+    fn test_volatility_spreads_percentiles() {
+        let mut state = FinancialState::new();
+        state.cash = dec!(100000);
+        let mut rng = GameRng::new(7);
+
+        let result = state
+            .project_net_worth(10, dec!(0.07), dec!(0.15), dec!(5000), 200, &mut rng)
+            .unwrap();
+
+        let last = result.final_band().unwrap();
+        assert!(last.p10 < last.p50);
+        assert!(last.p50 < last.p90);
+    }
+
+    #[test]
+    fn test_months_to_fire_reachable() {
+        let mut state = FinancialState::new();
+        state.add_expense(Expense::new(
+            "rent".to_string(),
+            "Rent".to_string(),
+            ExpenseCategory::Essential,
+            dec!(1000),
+        ));
+        // FIRE number = 1,000 * 12 * 25 = 300,000
+        state.cash = dec!(290000);
+
+        // Without returns: 10k short at 2,500/month = 4 months
+        assert_eq!(state.months_to_fire(dec!(2500), Decimal::ZERO), Some(4));
+        // Returns only shorten the wait
+        assert!(state.months_to_fire(dec!(2500), dec!(0.07)).unwrap() < 4);
+
+        state.cash = dec!(300000);
+        assert_eq!(state.months_to_fire(Decimal::ZERO, Decimal::ZERO), Some(0));
+    }
+
+    #[test]
+    fn test_months_to_fire_unreachable() {
+        let mut state = FinancialState::new();
+        state.add_expense(Expense::new(
+            "rent".to_string(),
+            "Rent".to_string(),
+            ExpenseCategory::Essential,
+            dec!(10000),
+        ));
+
+        // Nothing saved, nothing contributed
+        assert_eq!(state.months_to_fire(Decimal::ZERO, dec!(0.07)), None);
+        // Contributing far too little to get there within 100 years
+        assert_eq!(state.months_to_fire(dec!(10), Decimal::ZERO), None);
+    }
+
+    #[test]
+    fn test_requires_iterations() {
+        let state = FinancialState::new();
+        let mut rng = GameRng::new(1);
+        assert!(state
+            .project_net_worth(1, dec!(0.07), dec!(0.15), dec!(0), 0, &mut rng)
+            .is_err());
+    }
+
+    #[test]
+    fn test_project_balance_matches_future_value() {
+        let state = FinancialState::new();
+        // FV of 1,000 a month at 1% a month for a year:
+        // 1,000 * (1.01^12 - 1) / 0.01 = 12,682.50
+        let projected = state.project_balance(dec!(1000), dec!(0.12), 1);
+        assert_eq!(projected.round_dp(2), dec!(12682.50));
+
+        assert_eq!(
+            state.project_balance(dec!(1000), Decimal::ZERO, 10),
+            dec!(120000)
+        );
+        assert_eq!(
+            state.project_balance(Decimal::ZERO, dec!(0.07), 20),
+            Decimal::ZERO
+        );
+    }
+}
@@ -0,0 +1,58 @@
+//! Big purchases offered to the player
+
+use super::accounts::AssetCategory;
+use crate::market::MarketProfile;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+/// Share of the price paid up front when buying on installments
+pub const INSTALLMENT_DOWN_PAYMENT: Decimal = dec!(0.20);
+
+/// Length of an installment plan
+pub const INSTALLMENT_MONTHS: u32 = 36;
+
+/// Something the player can buy outright or on installments
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PurchaseOption {
+    /// Display name, also given to the asset bought
+    pub name: &'static str,
+    /// Category of the asset bought
+    pub category: AssetCategory,
+    /// Price in months of the market's food minimum
+    price_months: u32,
+    /// Monthly upkeep in months of the market's food minimum
+    upkeep_months: u32,
+}
+
+/// The purchases on offer, priced against the food minimum so they scale with the market
+pub const PURCHASE_OPTIONS: [PurchaseOption; 2] = [
+    PurchaseOption {
+        name: "Used Car",
+        category: AssetCategory::Vehicle,
+        price_months: 80,
+        upkeep_months: 1,
+    },
+    PurchaseOption {
+        name: "Furniture",
+        category: AssetCategory::Other,
+        price_months: 15,
+        upkeep_months: 0,
+    },
+];
+
+impl PurchaseOption {
+    /// Returns the purchase price in the market currency
+    pub fn price(&self, market: &dyn MarketProfile) -> Decimal {
+        market.essential_minimum() * Decimal::from(self.price_months)
+    }
+
+    /// Returns the monthly upkeep in the market currency
+    pub fn monthly_upkeep(&self, market: &dyn MarketProfile) -> Decimal {
+        market.essential_minimum() * Decimal::from(self.upkeep_months)
+    }
+
+    /// Returns the cash paid up front when buying on installments
+    pub fn down_payment(&self, market: &dyn MarketProfile) -> Decimal {
+        self.price(market) * INSTALLMENT_DOWN_PAYMENT
+    }
+}
@@ -0,0 +1,163 @@
+//! Rent versus buy comparison
+
+use super::economy::DEFAULT_ANNUAL_RETURN;
+use super::loans::{Loan, LoanKind};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+
+/// Mortgage term assumed for the buying path
+pub const RENT_VS_BUY_MORTGAGE_MONTHS: u32 = 360;
+
+/// Yearly maintenance, insurance and property tax as a share of the price
+pub const OWNERSHIP_COST_RATE: Decimal = dec!(0.01);
+
+/// Agent fees and taxes paid when selling, as a share of the sale price
+pub const SELLING_COST_RATE: Decimal = dec!(0.04);
+
+/// Outcome of renting versus buying over the same horizon
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RentVsBuyResult {
+    /// Rent paid over the horizon
+    pub rent_total_cost: Decimal,
+    /// Renter's investments at the end (down payment and savings invested)
+    pub rent_net_worth: Decimal,
+    /// Down payment, installments and ownership costs paid
+    pub buy_total_cost: Decimal,
+    /// Sale proceeds after selling costs, minus the mortgage still owed
+    pub buy_net_worth: Decimal,
+}
+
+impl RentVsBuyResult {
+    /// Returns true when buying ends with the higher net worth
+    pub fn buying_wins(&self) -> bool {
+        self.buy_net_worth > self.rent_net_worth
+    }
+}
+
+/// Compares renting a home with buying an equivalent one
+///
+/// Rent grows with house prices. The renter invests the down payment and,
+/// each month the owner pays more, the difference, at `DEFAULT_ANNUAL_RETURN`.
+/// The owner pays a fixed-rate mortgage plus `OWNERSHIP_COST_RATE` upkeep and
+/// sells at the end of the horizon.
+///
+/// # Arguments
+/// * `rent` - Monthly rent of the rented home
+/// * `purchase_price` - Price of the equivalent home
+/// * `down_payment` - Cash paid up front when buying
+/// * `mortgage_rate` - Annual mortgage interest rate
+/// * `appreciation` - Annual growth of house prices and rents
+/// * `years` - Comparison horizon
+pub fn compare_rent_vs_buy(
+    rent: Decimal,
+    purchase_price: Decimal,
+    down_payment: Decimal,
+    mortgage_rate: Decimal,
+    appreciation: Decimal,
+    years: u32,
+) -> Result<RentVsBuyResult, String> {
+    if years == 0 {
+        return Err("Comparison needs at least one year".to_string());
+    }
+    if down_payment < Decimal::ZERO || down_payment > purchase_price {
+        return Err("Down payment must be between zero and the purchase price".to_string());
+    }
+
+    let mut mortgage = if down_payment < purchase_price {
+        Some(Loan::new(
+            "rent_vs_buy".to_string(),
+            "Mortgage".to_string(),
+            LoanKind::Mortgage,
+            purchase_price - down_payment,
+            mortgage_rate,
+            RENT_VS_BUY_MORTGAGE_MONTHS,
+        )?)
+    } else {
+        None
+    };
+
+    let months = Decimal::from(12);
+    let monthly_return = DEFAULT_ANNUAL_RETURN / months;
+    let mut home_value = purchase_price;
+    let mut monthly_rent = rent;
+    let mut portfolio = down_payment;
+    let mut rent_total_cost = Decimal::ZERO;
+    let mut buy_total_cost = down_payment;
+
+    for _ in 0..years {
+        for _ in 0..12 {
+            let installment = mortgage.as_mut().map_or(Decimal::ZERO, Loan::make_payment);
+            let owner_cost = installment + home_value * OWNERSHIP_COST_RATE / months;
+            buy_total_cost += owner_cost;
+            rent_total_cost += monthly_rent;
+
+            portfolio *= Decimal::ONE + monthly_return;
+            portfolio += (owner_cost - monthly_rent).max(Decimal::ZERO);
+        }
+        home_value *= Decimal::ONE + appreciation;
+        monthly_rent *= Decimal::ONE + appreciation;
+    }
+
+    let owed = mortgage.map_or(Decimal::ZERO, |m| m.balance);
+    Ok(RentVsBuyResult {
+        rent_total_cost: rent_total_cost.round_dp(2),
+        rent_net_worth: portfolio.round_dp(2),
+        buy_total_cost: buy_total_cost.round_dp(2),
+        buy_net_worth: (home_value * (Decimal::ONE - SELLING_COST_RATE) - owed).round_dp(2),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_buying_wins_with_strong_appreciation() {
+        let result = compare_rent_vs_buy(
+            dec!(18000),
+            dec!(5000000),
+            dec!(1000000),
+            dec!(0.03),
+            dec!(0.06),
+            20,
+        )
+        .unwrap();
+
+        assert!(result.buying_wins());
+        assert!(result.buy_net_worth > dec!(10000000));
+    }
+
+    #[test]
+    fn test_renting_wins_short_horizon_high_rates() {
+        let result = compare_rent_vs_buy(
+            dec!(18000),
+            dec!(5000000),
+            dec!(1000000),
+            dec!(0.08),
+            dec!(0.01),
+            3,
+        )
+        .unwrap();
+
+        assert!(!result.buying_wins());
+        assert!(result.buy_total_cost > result.rent_total_cost);
+    }
+
+    #[test]
+    fn test_invalid_inputs_rejected() {
+        let compare = |down, years| {
+            compare_rent_vs_buy(
+                dec!(18000),
+                dec!(5000000),
+                down,
+                dec!(0.05),
+                dec!(0.03),
+                years,
+            )
+        };
+        assert!(compare(dec!(1000000), 0).is_err());
+        assert!(compare(dec!(6000000), 10).is_err());
+        assert!(compare(dec!(5000000), 10).is_ok());
+    }
+}
@@ -0,0 +1,104 @@
+//! Deterministic random number generation
+
+use serde::{Deserialize, Serialize};
+
+/// Seeded pseudo-random number generator (SplitMix64)
+///
+/// Stored in the game state so a save replays identically after loading.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GameRng {
+    state: u64,
+}
+
+impl GameRng {
+    /// Creates a generator from a numeric seed
+    pub fn new(seed: u64) -> Self {
+        GameRng { state: seed }
+    }
+
+    /// Creates a generator seeded from a string (e.g., the save ID)
+    pub fn from_seed_str(seed: &str) -> Self {
+        // FNV-1a hash
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        for byte in seed.bytes() {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+        Self::new(hash)
+    }
+
+    /// Returns the next random 64-bit value
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a uniform value in [0, 1)
+    pub fn next_f64(&mut self) -> f64 {
+        // Use the top 53 bits for full f64 mantissa precision
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Returns a uniform value in [min, max)
+    pub fn next_range(&mut self, min: f64, max: f64) -> f64 {
+        min + (max - min) * self.next_f64()
+    }
+
+    /// Returns a uniform integer in [0, n)
+    pub fn next_below(&mut self, n: u64) -> u64 {
+        if n == 0 {
+            return 0;
+        }
+        self.next_u64() % n
+    }
+
+    /// Returns true with the given probability (0.0 - 1.0)
+    pub fn chance(&mut self, probability: f64) -> bool {
+        self.next_f64() < probability
+    }
+
+    /// Returns a standard normal sample (mean 0, standard deviation 1)
+    pub fn next_normal(&mut self) -> f64 {
+        // Box-Muller transform; 1 - u keeps the log argument above zero
+        let u1 = 1.0 - self.next_f64();
+        let u2 = self.next_f64();
+        (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+    }
+}
+
+impl Default for GameRng {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_seed_same_sequence() {
+        let mut a = GameRng::from_seed_str("save1");
+        let mut b = GameRng::from_seed_str("save1");
+        let mut c = GameRng::from_seed_str("save2");
+
+        let seq_a: Vec<u64> = (0..5).map(|_| a.next_u64()).collect();
+        let seq_b: Vec<u64> = (0..5).map(|_| b.next_u64()).collect();
+        let seq_c: Vec<u64> = (0..5).map(|_| c.next_u64()).collect();
+
+        assert_eq!(seq_a, seq_b);
+        assert_ne!(seq_a, seq_c);
+    }
+
+    #[test]
+    fn test_uniform_range() {
+        let mut rng = GameRng::new(42);
+        for _ in 0..1000 {
+            let x = rng.next_f64();
+            assert!((0.0..1.0).contains(&x));
+        }
+    }
+}
@@ -0,0 +1,152 @@
+//! Headless driver for playing many months without the UI
+
+use super::accounts::AccountKind;
+use super::game_state::GameState;
+use crate::market::MarketProfile;
+use crate::markets::market_profile;
+use rust_decimal::Decimal;
+
+/// Makes the player's decisions in a headless run
+///
+/// Called once at the start of every month, in the planning phase, with full
+/// access to the game: budgets, jobs, accounts and purchases are all fair game.
+pub trait Strategy {
+    /// Makes this month's decisions
+    fn plan_month(
+        &mut self,
+        state: &mut GameState,
+        market: &dyn MarketProfile,
+    ) -> Result<(), String>;
+}
+
+/// Saves a fixed share of take-home pay into one account each month
+#[derive(Debug, Clone, PartialEq)]
+pub struct SavingsRateStrategy {
+    /// Share of monthly net income to save (0.20 = 20%)
+    pub rate: Decimal,
+    /// Kind of account the savings go into, opened on the first deposit
+    pub kind: AccountKind,
+    account_id: Option<String>,
+}
+
+impl SavingsRateStrategy {
+    /// Creates the strategy; the rate must be between 0 and 1
+    pub fn new(rate: Decimal, kind: AccountKind) -> Result<Self, String> {
+        if rate < Decimal::ZERO || rate > Decimal::ONE {
+            return Err("Savings rate must be between 0 and 1".to_string());
+        }
+        Ok(SavingsRateStrategy {
+            rate,
+            kind,
+            account_id: None,
+        })
+    }
+}
+
+impl Strategy for SavingsRateStrategy {
+    fn plan_month(
+        &mut self,
+        state: &mut GameState,
+        market: &dyn MarketProfile,
+    ) -> Result<(), String> {
+        let gross = state.finances.monthly_gross_income();
+        if gross <= Decimal::ZERO {
+            return Ok(());
+        }
+        let net_income = gross - state.finances.monthly_taxes(market)?.total;
+        let amount = (net_income * self.rate)
+            .round_dp(2)
+            .min(state.finances.cash);
+        if amount <= Decimal::ZERO {
+            return Ok(());
+        }
+
+        let id = match &self.account_id {
+            Some(id) => id.clone(),
+            None => {
+                let id = state.open_account("Savings".to_string(), self.kind.clone());
+                self.account_id = Some(id.clone());
+                id
+            }
+        };
+        state.finances.deposit_to_account(&id, amount, market)
+    }
+}
+
+/// Plays up to `months` months, letting the strategy plan each one
+///
+/// Each month runs through execution, settlement and review exactly as in the
+/// UI. Like `GameState::fast_forward`, the run stops early once the player
+/// goes bankrupt or retires. The game must start in the planning phase.
+pub fn simulate(
+    mut state: GameState,
+    strategy: &mut dyn Strategy,
+    months: u32,
+) -> Result<GameState, String> {
+    let market = market_profile(&state.market_id)
+        .ok_or_else(|| format!("Unknown market: {}", state.market_id))?;
+    if !state.phase.is_planning() {
+        return Err("Can only simulate from the planning phase".to_string());
+    }
+    for _ in 0..months {
+        strategy.plan_month(&mut state, market.as_ref())?;
+        state.fast_forward(1, market.as_ref())?;
+        if !state.phase.is_planning() {
+            break;
+        }
+    }
+    Ok(state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::builder::GameStateBuilder;
+    use crate::core::career::{CareerField, Job, JobLevel};
+    use crate::core::phase::GamePhase;
+    use rust_decimal_macros::dec;
+
+    fn employed_game() -> GameState {
+        GameStateBuilder::new("simulation".to_string(), None, 30, 2024)
+            .with_starting_job(Job::new(
+                "job".to_string(),
+                "Analyst".to_string(),
+                CareerField::Finance,
+                JobLevel::Mid,
+                dec!(50000),
+                None,
+            ))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_saving_a_fifth_grows_net_worth_every_month() {
+        let mut strategy =
+            SavingsRateStrategy::new(dec!(0.20), AccountKind::EmergencyFund).unwrap();
+        let state = simulate(employed_game(), &mut strategy, 36).unwrap();
+
+        assert_eq!(state.history.len(), 36);
+        assert!(state.phase.is_planning());
+        // Savings earn interest, so nothing in the run can make net worth shrink
+        for months in state.history.windows(2) {
+            assert!(months[1].net_worth > months[0].net_worth);
+        }
+        let saved = state
+            .finances
+            .accounts
+            .iter()
+            .find(|a| a.kind == AccountKind::EmergencyFund)
+            .unwrap();
+        assert!(saved.total_contributions > dec!(200000));
+    }
+
+    #[test]
+    fn test_simulation_needs_the_planning_phase() {
+        let mut strategy = SavingsRateStrategy::new(dec!(0.20), AccountKind::Taxable).unwrap();
+        let mut state = employed_game();
+        state.phase = GamePhase::Review;
+        assert!(simulate(state, &mut strategy, 1).is_err());
+        assert!(SavingsRateStrategy::new(dec!(1.5), AccountKind::Taxable).is_err());
+    }
+}
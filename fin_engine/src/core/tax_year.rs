@@ -0,0 +1,48 @@
+//! Income tax withheld during the year, reconciled at the year boundary
+
+use crate::market::MarketProfile;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Employment income and the tax withheld from it so far this year
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TaxYear {
+    /// Gross employment income received
+    pub employment_gross: Decimal,
+    /// Tax and insurance withheld by monthly payroll
+    pub withheld: Decimal,
+    /// Part of `withheld` that went to social and health insurance
+    #[serde(default)]
+    pub insurance_withheld: Decimal,
+}
+
+impl TaxYear {
+    /// Adds one month of payroll
+    pub fn record_month(&mut self, gross: Decimal, withheld: Decimal, insurance: Decimal) {
+        self.employment_gross += gross;
+        self.withheld += withheld;
+        self.insurance_withheld += insurance;
+    }
+
+    /// Computes the year's true tax and compares it with what was withheld
+    /// Insurance is final once paid each month, so only income tax is trued up
+    /// (years recorded before insurance was tracked are reassessed in full).
+    /// Returns the refund (negative when tax is still owed).
+    pub fn reconcile(
+        &self,
+        market: &dyn MarketProfile,
+        deductions: Decimal,
+    ) -> Result<Decimal, String> {
+        if self.employment_gross <= Decimal::ZERO {
+            return Ok(Decimal::ZERO);
+        }
+        let owed =
+            market.calculate_income_tax_with_deductions(self.employment_gross, deductions)?;
+        let owed_total = if self.insurance_withheld > Decimal::ZERO {
+            owed.income_tax + self.insurance_withheld
+        } else {
+            owed.total
+        };
+        Ok((self.withheld - owed_total).round_dp(2))
+    }
+}
@@ -90,6 +90,21 @@ impl GameTime {
         }
     }
 
+    /// Returns the day of the week on the real calendar (0 = Sunday, 6 = Saturday)
+    pub fn weekday(&self) -> u8 {
+        // Sakamoto's algorithm
+        const OFFSETS: [u32; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+        let month = self.month.value();
+        let year = if month < 3 { self.year - 1 } else { self.year };
+        let day = self.day as u32;
+        ((year + year / 4 - year / 100 + year / 400 + OFFSETS[month as usize - 1] + day) % 7) as u8
+    }
+
+    /// Returns true if the current day is a Saturday or Sunday
+    pub fn is_weekend(&self) -> bool {
+        matches!(self.weekday(), 0 | 6)
+    }
+
     /// Returns total months elapsed since start (for calculations)
     pub fn total_months(&self, start_year: u32) -> u32 {
         (self.year - start_year) * 12 + (self.month.value() as u32)
@@ -144,6 +159,25 @@ mod tests {
         assert_eq!(time.year, 2025);
     }
 
+    #[test]
+    fn test_weekend_matches_calendar() {
+        // 1 January 2024 was a Monday
+        let mut time = GameTime::new(2024, 1).unwrap();
+        assert_eq!(time.weekday(), 1);
+        assert!(!time.is_weekend());
+
+        time.day = 6; // Saturday
+        assert!(time.is_weekend());
+        time.day = 7; // Sunday
+        assert!(time.is_weekend());
+        time.day = 8;
+        assert!(!time.is_weekend());
+
+        // 1 March 2025 was a Saturday
+        let time = GameTime::new(2025, 3).unwrap();
+        assert_eq!(time.weekday(), 6);
+    }
+
     #[test]
     fn test_day_advancement() {
         let mut time = GameTime::new(2024, 1).unwrap();
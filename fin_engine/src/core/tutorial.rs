@@ -0,0 +1,135 @@
+//! Guided-mode steps that walk a new player through the basics
+
+use super::expenses::ExpenseCategory;
+use super::game_state::GameState;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// A tutorial step, complete once the game state shows the player has done it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TutorialStep {
+    /// Start earning an income
+    FindJob,
+    /// Allocate money to a spending category beyond food
+    SetBudget,
+    /// Save three months of expenses in an emergency fund
+    BuildEmergencyFund,
+    /// Put money into a taxable or retirement account
+    StartInvesting,
+}
+
+impl TutorialStep {
+    /// Returns all steps in the order they are taught
+    pub fn all() -> [TutorialStep; 4] {
+        [
+            TutorialStep::FindJob,
+            TutorialStep::SetBudget,
+            TutorialStep::BuildEmergencyFund,
+            TutorialStep::StartInvesting,
+        ]
+    }
+
+    /// Returns display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            TutorialStep::FindJob => "Find a Job",
+            TutorialStep::SetBudget => "Set a Budget",
+            TutorialStep::BuildEmergencyFund => "Build an Emergency Fund",
+            TutorialStep::StartInvesting => "Start Investing",
+        }
+    }
+
+    /// Returns the hint shown while the step is open
+    pub fn hint(&self) -> &'static str {
+        match self {
+            TutorialStep::FindJob => {
+                "Browse the job market in the Career section and accept an offer to start earning."
+            }
+            TutorialStep::SetBudget => {
+                "Give some money to lifestyle or health in the budget. Spending a little on yourself keeps you happy and healthy."
+            }
+            TutorialStep::BuildEmergencyFund => {
+                "Open an emergency fund and save three months of expenses so a surprise bill doesn't send you into overdraft."
+            }
+            TutorialStep::StartInvesting => {
+                "With a safety net in place, open an investment or retirement account and let compound growth do the work."
+            }
+        }
+    }
+
+    /// Checks whether the player has done what the step asks
+    pub fn is_complete(&self, state: &GameState) -> bool {
+        let finances = &state.finances;
+        match self {
+            TutorialStep::FindJob => finances.monthly_gross_income() > Decimal::ZERO,
+            TutorialStep::SetBudget => finances
+                .budget
+                .values()
+                .any(|b| b.category != ExpenseCategory::Essential && b.allocated > Decimal::ZERO),
+            TutorialStep::BuildEmergencyFund => finances.has_emergency_fund(),
+            TutorialStep::StartInvesting => finances.invested_balance() > Decimal::ZERO,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::accounts::{Account, AccountKind};
+    use crate::core::expenses::Expense;
+    use crate::core::income::{Income, IncomeKind};
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_hints_follow_progress() {
+        let mut state =
+            GameState::new("save1".to_string(), "czech".to_string(), None, 25, 2024).unwrap();
+        assert_eq!(state.tutorial_hint(), None);
+
+        state.tutorial_enabled = true;
+        assert_eq!(state.tutorial_hint(), Some(TutorialStep::FindJob));
+
+        state.finances.add_income(Income::new(
+            "job1".to_string(),
+            "Developer".to_string(),
+            IncomeKind::Employment,
+            dec!(50000),
+        ));
+        state.finances.add_expense(Expense::new(
+            "rent".to_string(),
+            "Rent".to_string(),
+            ExpenseCategory::Essential,
+            dec!(10000),
+        ));
+        state
+            .finances
+            .set_budget(ExpenseCategory::Essential, dec!(4000));
+        assert_eq!(state.tutorial_hint(), Some(TutorialStep::SetBudget));
+
+        state
+            .finances
+            .set_budget(ExpenseCategory::Lifestyle, dec!(2000));
+        assert_eq!(
+            state.tutorial_hint(),
+            Some(TutorialStep::BuildEmergencyFund)
+        );
+
+        let mut fund = Account::new(
+            "ef".to_string(),
+            "Emergency Fund".to_string(),
+            AccountKind::EmergencyFund,
+        );
+        fund.balance = dec!(30000);
+        state.finances.accounts.push(fund);
+        assert_eq!(state.tutorial_hint(), Some(TutorialStep::StartInvesting));
+
+        let mut brokerage = Account::new(
+            "inv".to_string(),
+            "Brokerage".to_string(),
+            AccountKind::Taxable,
+        );
+        brokerage.balance = dec!(1000);
+        state.finances.accounts.push(brokerage);
+        assert_eq!(state.tutorial_hint(), None);
+    }
+}
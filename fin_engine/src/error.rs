@@ -0,0 +1,41 @@
+//! Problems found when checking a game state for consistency
+
+use crate::core::ExpenseCategory;
+use rust_decimal::Decimal;
+use std::fmt;
+
+/// An inconsistency in a loaded or constructed game state
+#[derive(Debug, Clone, PartialEq)]
+pub enum FinError {
+    /// The market isn't compiled into this build
+    UnknownMarket(String),
+    /// Player age outside the playable range
+    InvalidAge(u8),
+    /// Execution phase on a day outside 1-30
+    InvalidDay(u8),
+    /// A budget category allocated a negative amount
+    NegativeBudget(ExpenseCategory, Decimal),
+    /// An expense (by ID) with a negative monthly amount
+    NegativeExpense(String),
+    /// A job income (by ID) whose job the player doesn't hold
+    OrphanedJobIncome(String),
+}
+
+impl fmt::Display for FinError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FinError::UnknownMarket(id) => {
+                write!(f, "Save uses the '{}' market, which is not available", id)
+            }
+            FinError::InvalidAge(age) => write!(f, "Player age {} is out of range", age),
+            FinError::InvalidDay(day) => write!(f, "Day {} is not a day of the month", day),
+            FinError::NegativeBudget(category, amount) => {
+                write!(f, "Budget for {:?} is negative ({})", category, amount)
+            }
+            FinError::NegativeExpense(id) => write!(f, "Expense {} is negative", id),
+            FinError::OrphanedJobIncome(id) => {
+                write!(f, "Income {} belongs to a job the player doesn't have", id)
+            }
+        }
+    }
+}
@@ -4,16 +4,36 @@
 //! It implements market-specific tax rules, retirement accounts, and game mechanics.
 
 pub mod core;
+pub mod error;
 pub mod market;
 pub mod markets;
 
 // Re-export commonly used types
 pub use core::{
-    Account, AccountKind, Asset, AssetCategory, BudgetAllocation, Career, CareerField, Expense,
-    ExpenseCategory, FinancialState, GamePhase, GameState, GameTime, Housing, HousingMarket,
-    HousingType, Income, IncomeKind, Job, JobLevel, JobMarket, LocationQuality, Month, PlayerStats,
+    compare_rent_vs_buy, debt_payoff_advice, simulate, suggest_contributions, Account, AccountKind,
+    Achievement, Advice, AdviceAction, Allocation, Asset, AssetCategory, BudgetAllocation,
+    BudgetPreset, Career, CareerField, CreditCard, Difficulty, EconomyPhase, EconomyState,
+    EducationLevel, EmergencyKind, Event, Expense, ExpenseCategory, FinancialState, GainKind,
+    GamePhase, GameRng, GameState, GameStateBuilder, GameTime, Gender, Goal, HouseholdFinances,
+    Housing, HousingType, Income, IncomeKind, Insurance, InsuranceKind, Job, JobLevel, JobMarket,
+    Loan, LoanKind, LocationQuality, Month, MonthlySnapshot, NetWorthBreakdown, Partner,
+    PercentileBand, PlayerStats, ProjectionResult, PurchaseOption, RealizedGain, RentVsBuyResult,
+    SavingsRateStrategy, SelfEmployedRegime, Strategy, Suggestion, SuggestionReason, TaxYear,
+    TutorialStep, WellbeingDelta, WellbeingEffect, WellbeingFactor, WindfallKind,
+    AFFORDABLE_HOUSING_SHARE, CREDIT_CARD_ANNUAL_RATE, CREDIT_CARD_MIN_PAYMENT_FLOOR,
+    DEFAULT_ANNUAL_RETURN, DEFAULT_DUE_DAY, DEFAULT_MARKET_ID, DEFAULT_PAYDAY,
+    DEFAULT_WITHDRAWAL_RATE, DEPOSIT_WEAR_AND_TEAR, EMERGENCY_FUND_SCHEDULE_MONTHS,
+    GROSS_RENTAL_YIELD, HEALTHY_LIVING_BUDGET, INSTALLMENT_DOWN_PAYMENT, INSTALLMENT_MONTHS,
+    LANDLORD_RENT_PREMIUM, LEISURE_BUDGET, MARKET_MIGRATIONS, MAX_LOAN_TERM_MONTHS, MAX_PLAYER_AGE,
+    MAX_PURCHASE_HAPPINESS, MIN_PLAYER_AGE, NET_WORTH_MILESTONE, PART_TIME_HOURS,
+    PURCHASE_HAPPINESS_UNIT, PURCHASE_OPTIONS, SAVINGS_MILESTONE, STUDENT_LOAN_TERM_MONTHS,
 };
+pub use error::FinError;
 pub use market::{AccountType, Currency, MarketProfile, TaxBreakdown};
+pub use markets::{available_markets, market_profile, MarketInfo};
 
 #[cfg(feature = "czech")]
 pub use markets::czech::CzechMarket;
+
+#[cfg(feature = "euro")]
+pub use markets::euro::EuroMarket;
@@ -3,9 +3,27 @@
 //! This module defines the `MarketProfile` trait, which encapsulates
 //! all country-specific financial rules (taxes, retirement accounts, etc.)
 
-use rust_decimal::Decimal;
+use crate::core::economy::{
+    BOND_ANNUAL_RETURN, DEFAULT_BASE_INTEREST_RATE, EQUITY_ANNUAL_VOLATILITY, EQUITY_DIVIDEND_YIELD,
+};
+use crate::core::{
+    Account, AssetCategory, Career, GainKind, Gender, Housing, Job, RealizedGain,
+    SelfEmployedRegime, WindfallKind, CREDIT_CARD_ANNUAL_RATE, DEFAULT_ANNUAL_RETURN,
+    GROSS_RENTAL_YIELD,
+};
+use rust_decimal::{Decimal, RoundingStrategy};
+use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
-use std::time::Duration;
+
+/// Czech survival food budget that the engine's CZK amounts are priced against
+const CZK_ESSENTIAL_MINIMUM: Decimal = dec!(3500);
+
+/// Tax rate on long-held securities gains for markets that don't set their own
+/// TODO: Verify - a typical flat rate, not any one country
+const DEFAULT_LONG_TERM_GAINS_RATE: Decimal = dec!(0.15);
+
+/// Error for pricing a bonus as a windfall (see `MarketProfile::windfall_tax`)
+pub(crate) const BONUS_IS_PAY: &str = "Bonuses are taxed as pay on top of the salary";
 
 /// Represents a currency type
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -36,6 +54,61 @@ impl Currency {
             Currency::EUR => 2,
         }
     }
+
+    /// Formats an amount for display
+    ///
+    /// Rounds to the currency's minor units and applies local digit grouping
+    /// and symbol placement (e.g., "1 234,50 Kč", "$1,234.50").
+    pub fn format(&self, amount: Decimal) -> String {
+        let decimals = self.minor_units();
+        let rounded =
+            amount.round_dp_with_strategy(decimals, RoundingStrategy::MidpointAwayFromZero);
+        let digits = format!("{:.*}", decimals as usize, rounded.abs());
+        let (whole, fraction) = match digits.split_once('.') {
+            Some((whole, fraction)) => (whole, Some(fraction)),
+            None => (digits.as_str(), None),
+        };
+
+        let mut number = String::new();
+        for (i, digit) in whole.chars().enumerate() {
+            if i > 0 && (whole.len() - i).is_multiple_of(3) {
+                number.push(self.grouping_separator());
+            }
+            number.push(digit);
+        }
+        if let Some(fraction) = fraction {
+            number.push(self.decimal_separator());
+            number.push_str(fraction);
+        }
+
+        let sign = if rounded < Decimal::ZERO { "-" } else { "" };
+        if self.symbol_before_amount() {
+            format!("{}{}{}", sign, self.symbol(), number)
+        } else {
+            format!("{}{} {}", sign, number, self.symbol())
+        }
+    }
+
+    /// Thousands separator used when formatting amounts
+    fn grouping_separator(&self) -> char {
+        match self {
+            Currency::CZK | Currency::EUR => ' ',
+            Currency::USD | Currency::GBP => ',',
+        }
+    }
+
+    /// Separator between whole and minor units
+    fn decimal_separator(&self) -> char {
+        match self {
+            Currency::CZK | Currency::EUR => ',',
+            Currency::USD | Currency::GBP => '.',
+        }
+    }
+
+    /// Whether the symbol is written before the amount ("$5") or after ("5 Kč")
+    fn symbol_before_amount(&self) -> bool {
+        matches!(self, Currency::USD | Currency::GBP)
+    }
 }
 
 /// Tax breakdown showing different components
@@ -51,6 +124,37 @@ pub struct TaxBreakdown {
     pub total: Decimal,
 }
 
+impl TaxBreakdown {
+    /// Returns an empty breakdown (no tax owed)
+    pub fn zero() -> Self {
+        TaxBreakdown {
+            income_tax: Decimal::ZERO,
+            social_insurance: Decimal::ZERO,
+            health_insurance: Decimal::ZERO,
+            total: Decimal::ZERO,
+        }
+    }
+
+    /// Splits an annual breakdown into one month's share
+    pub fn per_month(&self) -> Self {
+        let months = Decimal::from(12);
+        TaxBreakdown {
+            income_tax: self.income_tax / months,
+            social_insurance: self.social_insurance / months,
+            health_insurance: self.health_insurance / months,
+            total: self.total / months,
+        }
+    }
+
+    /// Adds another breakdown to this one, component by component
+    pub fn add(&mut self, other: &TaxBreakdown) {
+        self.income_tax += other.income_tax;
+        self.social_insurance += other.social_insurance;
+        self.health_insurance += other.health_insurance;
+        self.total += other.total;
+    }
+}
+
 /// Type of tax-advantaged account
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AccountType {
@@ -81,26 +185,332 @@ pub trait MarketProfile: Send + Sync {
     /// Tax breakdown with all components
     fn calculate_income_tax(&self, gross_income: Decimal) -> Result<TaxBreakdown, String>;
 
+    /// Calculates tax for one month of gross income
+    ///
+    /// Annualizes the monthly amount so yearly thresholds apply correctly,
+    /// then returns one twelfth of each component.
+    fn calculate_monthly_tax(&self, monthly_gross: Decimal) -> Result<TaxBreakdown, String> {
+        let annual = self.calculate_income_tax(monthly_gross * Decimal::from(12))?;
+        Ok(annual.per_month())
+    }
+
+    /// Calculates payroll deductions on one month of an employee's salary
+    ///
+    /// Like `calculate_monthly_tax`, plus any health insurance top-up owed when
+    /// the salary is below the market's minimum assessment base. Bonuses,
+    /// windfalls and other income never trigger the top-up.
+    fn calculate_monthly_payroll_tax(
+        &self,
+        monthly_gross: Decimal,
+    ) -> Result<TaxBreakdown, String> {
+        let mut taxes = self.calculate_monthly_tax(monthly_gross)?;
+        let top_up = self.minimum_health_top_up(monthly_gross);
+        taxes.health_insurance += top_up;
+        taxes.total += top_up;
+        Ok(taxes)
+    }
+
+    /// Returns the extra health insurance owed on a month's salary below the
+    /// minimum assessment base (zero where the market has none)
+    fn minimum_health_top_up(&self, _monthly_gross: Decimal) -> Decimal {
+        Decimal::ZERO
+    }
+
+    /// Calculates annual tax and insurance after tax-deductible contributions
+    ///
+    /// Deductions lower only the income tax base; social and health insurance
+    /// are still assessed on the full gross income.
+    fn calculate_income_tax_with_deductions(
+        &self,
+        gross_income: Decimal,
+        deductions: Decimal,
+    ) -> Result<TaxBreakdown, String> {
+        let mut taxes = self.calculate_income_tax(gross_income)?;
+        if deductions > Decimal::ZERO {
+            let reduced =
+                self.calculate_income_tax((gross_income - deductions).max(Decimal::ZERO))?;
+            taxes.total -= taxes.income_tax - reduced.income_tax;
+            taxes.income_tax = reduced.income_tax;
+        }
+        Ok(taxes)
+    }
+
+    /// Returns this year's contributions that reduce the income tax base
+    ///
+    /// Markets without deductible retirement saving return zero, the default.
+    fn tax_deductible_contributions(&self, _accounts: &[Account]) -> Decimal {
+        Decimal::ZERO
+    }
+
+    /// Calculates annual tax and insurance for a married couple
+    ///
+    /// Markets without joint filing or spouse allowances simply tax each
+    /// partner individually, which is the default.
+    fn calculate_joint_income_tax(
+        &self,
+        gross_income: Decimal,
+        partner_gross_income: Decimal,
+    ) -> Result<TaxBreakdown, String> {
+        let mut taxes = self.calculate_income_tax(gross_income)?;
+        if partner_gross_income > Decimal::ZERO {
+            taxes.add(&self.calculate_income_tax(partner_gross_income)?);
+        }
+        Ok(taxes)
+    }
+
+    /// Calculates tax and insurance for self-employment revenue
+    ///
+    /// # Arguments
+    /// * `gross_revenue` - Annual revenue before expenses
+    /// * `regime` - Self-employment tax regime chosen for this income
+    ///
+    /// # Returns
+    /// Tax breakdown with all components
+    fn calculate_self_employed_tax(
+        &self,
+        gross_revenue: Decimal,
+        regime: SelfEmployedRegime,
+    ) -> Result<TaxBreakdown, String>;
+
+    /// Calculates self-employment tax for one month of revenue
+    fn calculate_monthly_self_employed_tax(
+        &self,
+        monthly_revenue: Decimal,
+        regime: SelfEmployedRegime,
+    ) -> Result<TaxBreakdown, String> {
+        let annual =
+            self.calculate_self_employed_tax(monthly_revenue * Decimal::from(12), regime)?;
+        Ok(annual.per_month())
+    }
+
     /// Returns available tax-advantaged accounts
     fn available_accounts(&self) -> Vec<AccountType>;
 
     /// Calculates capital gains tax
     ///
     /// # Arguments
-    /// * `holding_period` - How long the asset was held
+    /// * `kind` - What was sold, since securities and property can follow different rules
+    /// * `holding_months` - Game months the asset was held
     /// * `gain` - Capital gain amount
     ///
     /// # Returns
     /// Tax amount owed on the gain
-    fn capital_gains_tax(&self, holding_period: Duration, gain: Decimal)
-        -> Result<Decimal, String>;
+    fn capital_gains_tax(
+        &self,
+        kind: GainKind,
+        holding_months: u32,
+        gain: Decimal,
+    ) -> Result<Decimal, String>;
+
+    /// Calculates the tax owed on a whole tax year of realized gains
+    ///
+    /// Defaults to taxing each gain on its own with losses ignored; markets
+    /// with annual exemptions, allowances or loss offsetting override this.
+    fn annual_capital_gains_tax(&self, gains: &[RealizedGain]) -> Result<Decimal, String> {
+        let mut total = Decimal::ZERO;
+        for realized in gains.iter().filter(|g| g.gain > Decimal::ZERO) {
+            total +=
+                self.capital_gains_tax(realized.kind, realized.holding_months, realized.gain)?;
+        }
+        Ok(total)
+    }
+
+    /// Returns the tax rate applied to dividends from taxable accounts
+    fn dividend_tax_rate(&self) -> Decimal;
+
+    /// Calculates the annual tax on rent from let property
+    ///
+    /// Defaults to taxing rent minus upkeep at the dividend rate; markets with
+    /// their own rules for letting income override this.
+    fn rental_income_tax(
+        &self,
+        annual_rent: Decimal,
+        annual_costs: Decimal,
+    ) -> Result<Decimal, String> {
+        let profit = (annual_rent - annual_costs).max(Decimal::ZERO);
+        Ok((profit * self.dividend_tax_rate()).round_dp(2))
+    }
+
+    /// Calculates the tax owed on a windfall of the given kind
+    ///
+    /// Defaults to leaving gifts, inheritances and winnings tax-free; markets
+    /// override as needed. Bonuses are pay, taxed on top of the salary by
+    /// `FinancialState::record_annual_bonus`, so they are rejected here.
+    fn windfall_tax(&self, kind: WindfallKind, _amount: Decimal) -> Result<Decimal, String> {
+        match kind {
+            WindfallKind::Bonus => Err(BONUS_IS_PAY.to_string()),
+            WindfallKind::Inheritance | WindfallKind::Lottery => Ok(Decimal::ZERO),
+        }
+    }
+
+    /// Returns the expected annual inflation rate (e.g., 0.025 for 2.5%)
+    fn inflation_rate(&self) -> Decimal;
+
+    /// Returns the long-run nominal annual return of a stock portfolio
+    /// typical for investors in this market (e.g., 0.07 for 7%)
+    fn expected_equity_return(&self) -> Decimal {
+        DEFAULT_ANNUAL_RETURN
+    }
+
+    /// Returns the annual volatility (standard deviation) of that portfolio
+    fn equity_volatility(&self) -> Decimal {
+        EQUITY_ANNUAL_VOLATILITY
+    }
+
+    /// Returns the part of `expected_equity_return` paid out as dividends each year
+    fn equity_dividend_yield(&self) -> Decimal {
+        EQUITY_DIVIDEND_YIELD
+    }
+
+    /// Returns the long-run nominal annual return of government bonds
+    fn expected_bond_return(&self) -> Decimal {
+        BOND_ANNUAL_RETURN
+    }
+
+    /// Returns the central bank rate a new game starts from
+    fn base_interest_rate(&self) -> Decimal {
+        DEFAULT_BASE_INTEREST_RATE
+    }
+
+    /// Returns the annual interest charged on a carried credit card balance
+    fn credit_card_rate(&self) -> Decimal {
+        CREDIT_CARD_ANNUAL_RATE
+    }
+
+    /// Returns the tax rate on gains from securities held for many years
+    /// Used to estimate after-tax returns of long-term investing.
+    fn long_term_gains_tax_rate(&self) -> Decimal {
+        DEFAULT_LONG_TERM_GAINS_RATE
+    }
+
+    /// Returns the holding period (in months) after which gains on the given
+    /// kind of holding become tax-free, or None if the market has no such time test
+    fn tax_free_holding_months(&self, _kind: GainKind) -> Option<u32> {
+        None
+    }
 
     /// Returns the retirement age for the market
     fn retirement_age(&self) -> u8;
 
+    /// Returns the retirement age for a player of the given gender and birth year
+    /// Markets without gender or cohort rules use `retirement_age`.
+    fn retirement_age_for(&self, gender: Option<Gender>, birth_year: u32) -> u8 {
+        let _ = (gender, birth_year);
+        self.retirement_age()
+    }
+
+    /// Estimates the monthly state pension (or social security) at retirement
+    /// Based on the contribution history: years worked and average salary
+    fn state_pension_estimate(&self, career: &Career) -> Decimal;
+
     /// Returns market identifier (e.g., "czech", "usa", "uk")
     fn market_id(&self) -> &'static str;
 
     /// Returns market display name
     fn market_name(&self) -> &'static str;
+
+    /// Returns housing options available in this market
+    /// Ranges from cheap shared rooms to expensive houses, priced in the market currency
+    fn generate_housing(&self) -> Vec<Housing>;
+
+    /// Returns every job advertised in this market, across all levels
+    /// Salaries are in the market currency; markets without their own
+    /// listings have no openings yet.
+    fn job_listings(&self) -> Vec<Job> {
+        Vec::new()
+    }
+
+    /// Returns typical annual rent as a share of a property's value
+    fn gross_rental_yield(&self) -> Decimal {
+        GROSS_RENTAL_YIELD
+    }
+
+    /// Returns the share of an asset's value lost when selling it in a hurry
+    fn liquidation_haircut(&self, category: &AssetCategory) -> Decimal {
+        category.liquidation_haircut()
+    }
+
+    /// Returns the security deposit required when moving in, in months of rent
+    fn security_deposit_months(&self) -> Decimal;
+
+    /// Returns the flat moving expense paid on every move
+    fn moving_fee(&self) -> Decimal;
+
+    /// Returns the monthly health insurance someone without any income must
+    /// pay on their own (zero where the state or nobody covers it)
+    fn self_payer_health_insurance(&self) -> Decimal {
+        Decimal::ZERO
+    }
+
+    /// Returns the monthly survival food budget, in the market currency
+    fn essential_minimum(&self) -> Decimal;
+
+    /// Returns the monthly gross minimum wage for full-time work
+    fn minimum_wage(&self) -> Decimal;
+
+    /// Returns the lowest salary the job may pay: the minimum wage prorated by hours
+    fn salary_floor(&self, job: &Job) -> Decimal {
+        (self.minimum_wage() * job.hours_fraction).round_dp(0)
+    }
+
+    /// Rejects a job paying less than the market's minimum wage
+    fn check_minimum_wage(&self, job: &Job) -> Result<(), String> {
+        let floor = self.salary_floor(job);
+        if job.monthly_salary < floor {
+            return Err(format!(
+                "{} pays {} a month, below the minimum wage of {}",
+                job.title,
+                self.currency().format(job.monthly_salary),
+                self.currency().format(floor)
+            ));
+        }
+        Ok(())
+    }
+
+    /// Converts an amount priced in Czech crowns to this market's currency
+    /// Game amounts without their own figure per market (event costs, windfalls)
+    /// are written in CZK and scaled by the survival food budget, so they weigh
+    /// the same against living costs in every market.
+    fn scale_czk(&self, czk: Decimal) -> Decimal {
+        (czk * self.essential_minimum() / CZK_ESSENTIAL_MINIMUM).round_dp(0)
+    }
+
+    /// Raises an Essential budget to at least the survival minimum
+    fn clamp_essential_budget(&self, amount: Decimal) -> Decimal {
+        amount.max(self.essential_minimum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_format_czk() {
+        assert_eq!(Currency::CZK.format(dec!(1234567.5)), "1 234 567,50 Kč");
+        assert_eq!(Currency::CZK.format(dec!(3500)), "3 500,00 Kč");
+        assert_eq!(Currency::CZK.format(dec!(999)), "999,00 Kč");
+        assert_eq!(Currency::CZK.format(dec!(-25000)), "-25 000,00 Kč");
+    }
+
+    #[test]
+    #[cfg(all(feature = "czech", feature = "euro"))]
+    fn test_czk_amounts_scale_with_living_costs() {
+        use crate::markets::czech::CzechMarket;
+        use crate::markets::market_profile;
+
+        assert_eq!(CzechMarket.scale_czk(dec!(15000)), dec!(15000));
+        // The euro food minimum is 250 against 3,500 CZK
+        let euro = market_profile("euro").unwrap();
+        assert_eq!(euro.scale_czk(dec!(14000)), dec!(1000));
+    }
+
+    #[test]
+    fn test_format_usd() {
+        assert_eq!(Currency::USD.format(dec!(1234567.891)), "$1,234,567.89");
+        assert_eq!(Currency::USD.format(dec!(0.005)), "$0.01"); // Rounds half away from zero
+        assert_eq!(Currency::USD.format(dec!(-1500)), "-$1,500.00");
+        assert_eq!(Currency::GBP.format(dec!(100000)), "£100,000.00");
+    }
 }
@@ -2,15 +2,113 @@
 //!
 //! Implements Czech-specific financial rules:
 //! - Progressive tax (15%/23% brackets)
-//! - Social insurance (7.1% for employees)
+//! - Social insurance (7.1% for employees, capped at the maximum assessment base)
 //! - Health insurance (4.5% for employees)
 //! - DIP, 3rd Pillar, Stavební spoření
 //! - 3-year "Časový test" for capital gains exemption
+//! - OSVČ self-employment (60% expense lump sum or paušální daň)
+//! - State pension (base amount + percentage per insured year)
+//! - Prague rental market (2 months deposit + 1,500 CZK moving fee)
 
-use crate::market::{AccountType, Currency, MarketProfile, TaxBreakdown};
+use crate::core::{
+    Account, AccountKind, Career, CareerField, GainKind, Gender, Housing, HousingType, Job,
+    JobLevel, LocationQuality, RealizedGain, SelfEmployedRegime, WindfallKind,
+};
+use crate::market::{AccountType, Currency, MarketProfile, TaxBreakdown, BONUS_IS_PAY};
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
-use std::time::Duration;
+
+/// Maximum annual assessment base for social insurance (48x average monthly wage, 2024)
+/// TODO: Verify - updated yearly by government decree
+const SOCIAL_INSURANCE_MAX_BASE: Decimal = dec!(2234736);
+
+/// Minimum monthly assessment base for health insurance: the minimum wage (2024)
+/// TODO: Verify - updated yearly with the minimum wage
+const HEALTH_INSURANCE_MIN_BASE: Decimal = dec!(18900);
+
+/// Full health insurance rate (employee 4.5% + employer 9%)
+const HEALTH_INSURANCE_FULL_RATE: Decimal = dec!(0.135);
+
+/// Game months after which securities gains are exempt ("časový test")
+const TIME_TEST_MONTHS: u32 = 36;
+
+/// Game months after which gains on property are exempt
+/// TODO: Exempt homes lived in for at least 2 years before the sale
+const PROPERTY_TIME_TEST_MONTHS: u32 = 120;
+
+/// Securities sales are exempt while a year's total proceeds stay at or below this
+const SECURITIES_EXEMPT_PROCEEDS: Decimal = dec!(100000);
+
+/// Rate applied to taxable capital gains
+/// TODO: Verify this rate and implement proper bracket calculation
+const CAPITAL_GAINS_RATE: Decimal = dec!(0.15);
+
+/// Lottery winnings up to this amount are tax-free; the excess is taxed at 15%
+/// (inheritances within the family are exempt from income tax)
+const LOTTERY_TAX_FREE_LIMIT: Decimal = dec!(1000000);
+
+/// Share of OSVČ revenue that can be claimed as lump-sum expenses (most trades)
+const OSVC_EXPENSE_LUMP_SUM: Decimal = dec!(0.60);
+
+/// Minimum monthly OSVČ social insurance advance (2024)
+/// TODO: Verify - updated yearly
+const OSVC_MIN_SOCIAL_MONTHLY: Decimal = dec!(2944);
+
+/// Minimum monthly OSVČ health insurance advance (2024)
+/// TODO: Verify - updated yearly
+const OSVC_MIN_HEALTH_MONTHLY: Decimal = dec!(2968);
+
+/// Paušální daň bands (2024): annual revenue limit and monthly
+/// (income tax, social insurance, health insurance) payments
+/// TODO: Verify - band amounts change yearly
+const FLAT_TAX_BANDS: [(Decimal, Decimal, Decimal, Decimal); 3] = [
+    (dec!(1000000), dec!(100), dec!(4430), dec!(2968)),
+    (dec!(1500000), dec!(4963), dec!(8191), dec!(3591)),
+    (dec!(2000000), dec!(9320), dec!(12855), dec!(4964)),
+];
+
+/// Share of rent that can be claimed as lump-sum expenses instead of actual costs
+/// TODO: Verify
+const RENTAL_EXPENSE_LUMP_SUM: Decimal = dec!(0.30);
+
+/// Rate applied to rental profit (§9 income, taxed in the 15% band)
+/// TODO: Verify - rent pushing income over the 23% threshold is not modelled
+const RENTAL_INCOME_TAX_RATE: Decimal = dec!(0.15);
+
+/// Combined annual tax deduction for DIP and III. pilíř contributions (2024)
+/// TODO: Verify - also covers private life insurance premiums
+const RETIREMENT_DEDUCTION_LIMIT: Decimal = dec!(48000);
+
+/// Annual spouse tax credit (sleva na manželku/manžela)
+const SPOUSE_TAX_CREDIT: Decimal = dec!(24840);
+
+/// Maximum annual income of the partner for the spouse tax credit
+const SPOUSE_CREDIT_INCOME_LIMIT: Decimal = dec!(68000);
+
+/// Monthly pension base amount (základní výměra, 2024)
+/// TODO: Verify - updated yearly
+const PENSION_BASE_AMOUNT: Decimal = dec!(4400);
+
+/// Pension percentage amount earned per insured year
+const PENSION_PERCENT_PER_YEAR: Decimal = dec!(0.01495);
+
+/// First and second reduction thresholds of the pension assessment base (2024)
+/// TODO: Verify - updated yearly
+const PENSION_REDUCTION_THRESHOLD_1: Decimal = dec!(20486);
+const PENSION_REDUCTION_THRESHOLD_2: Decimal = dec!(186235);
+
+/// Returns the time test that applies to the kind of holding
+fn time_test_months(kind: GainKind) -> u32 {
+    match kind {
+        GainKind::Securities => TIME_TEST_MONTHS,
+        GainKind::Property => PROPERTY_TIME_TEST_MONTHS,
+    }
+}
+
+/// Whether a holding was owned long enough to pass its time test
+fn passes_time_test(kind: GainKind, holding_months: u32) -> bool {
+    holding_months >= time_test_months(kind)
+}
 
 /// Czech Republic market profile
 #[derive(Debug, Clone)]
@@ -38,10 +136,11 @@ impl MarketProfile for CzechMarket {
         // TODO: Verify these rates and brackets with official sources
         // Current rates (2024): 15% up to certain threshold, 23% above
 
-        // Social insurance: 7.1% (employee portion)
-        let social_insurance = gross_income * dec!(0.071);
+        // Social insurance: 7.1% (employee portion), only up to the maximum assessment base
+        let social_base = gross_income.min(SOCIAL_INSURANCE_MAX_BASE);
+        let social_insurance = social_base * dec!(0.071);
 
-        // Health insurance: 4.5% (employee portion)
+        // Health insurance: 4.5% (employee portion), no cap
         let health_insurance = gross_income * dec!(0.045);
 
         // Super gross income for tax calculation (simplified)
@@ -62,6 +161,39 @@ impl MarketProfile for CzechMarket {
         })
     }
 
+    fn minimum_health_top_up(&self, monthly_gross: Decimal) -> Decimal {
+        // Any employment is assessed on at least the minimum wage and the
+        // employee pays the full rate on the shortfall
+        if monthly_gross > Decimal::ZERO {
+            (HEALTH_INSURANCE_MIN_BASE - monthly_gross).max(Decimal::ZERO)
+                * HEALTH_INSURANCE_FULL_RATE
+        } else {
+            Decimal::ZERO
+        }
+    }
+
+    fn calculate_joint_income_tax(
+        &self,
+        gross_income: Decimal,
+        partner_gross_income: Decimal,
+    ) -> Result<TaxBreakdown, String> {
+        let mut taxes = self.calculate_income_tax(gross_income)?;
+        if partner_gross_income > Decimal::ZERO {
+            taxes.add(&self.calculate_income_tax(partner_gross_income)?);
+        }
+
+        // Spouse tax credit (sleva na manželku/manžela) when the partner earns
+        // almost nothing; it cannot push income tax below zero
+        // TODO: Also require a child under 3 in the household (rule since 2021)
+        if partner_gross_income <= SPOUSE_CREDIT_INCOME_LIMIT {
+            let credit = SPOUSE_TAX_CREDIT.min(taxes.income_tax);
+            taxes.income_tax -= credit;
+            taxes.total -= credit;
+        }
+
+        Ok(taxes)
+    }
+
     fn available_accounts(&self) -> Vec<AccountType> {
         vec![
             AccountType {
@@ -85,30 +217,199 @@ impl MarketProfile for CzechMarket {
         ]
     }
 
+    fn tax_deductible_contributions(&self, accounts: &[Account]) -> Decimal {
+        let contributions: Decimal = accounts
+            .iter()
+            .filter(|a| {
+                matches!(&a.kind, AccountKind::Retirement { account_type_id }
+                    if account_type_id == "dip" || account_type_id == "third_pillar")
+            })
+            .map(|a| a.contributions_this_year)
+            .sum();
+        contributions.min(RETIREMENT_DEDUCTION_LIMIT)
+    }
+
+    fn tax_free_holding_months(&self, kind: GainKind) -> Option<u32> {
+        Some(time_test_months(kind))
+    }
+
     fn capital_gains_tax(
         &self,
-        holding_period: Duration,
+        kind: GainKind,
+        holding_months: u32,
         gain: Decimal,
     ) -> Result<Decimal, String> {
-        // Czech 3-year "Časový test" (Time Test)
-        // If held for 3+ years, capital gains on stocks/ETFs are tax-exempt
-        const THREE_YEARS_IN_SECS: u64 = 3 * 365 * 24 * 60 * 60;
-
-        if holding_period.as_secs() >= THREE_YEARS_IN_SECS {
+        // Czech "Časový test" (Time Test): gains on stocks/ETFs held for 3+ years
+        // and on property held for 10+ years are tax-exempt
+        if passes_time_test(kind, holding_months) {
             Ok(Decimal::ZERO)
         } else {
-            // If held less than 3 years, taxed as ordinary income (15%)
-            // TODO: Verify this rate and implement proper bracket calculation
-            Ok(gain * dec!(0.15))
+            // If held less than 3 years, taxed as ordinary income
+            Ok(gain * CAPITAL_GAINS_RATE)
+        }
+    }
+
+    fn long_term_gains_tax_rate(&self) -> Decimal {
+        // Long holdings pass the time test
+        Decimal::ZERO
+    }
+
+    fn annual_capital_gains_tax(&self, gains: &[RealizedGain]) -> Result<Decimal, String> {
+        // Small investors are exempt when the year's securities sales total 100k or less
+        let securities_proceeds: Decimal = gains
+            .iter()
+            .filter(|g| g.kind == GainKind::Securities)
+            .map(|g| g.proceeds)
+            .sum();
+        let small_investor = securities_proceeds <= SECURITIES_EXEMPT_PROCEEDS;
+
+        // Losses offset gains among the sales that are not exempt
+        let taxable: Decimal = gains
+            .iter()
+            .filter(|g| !passes_time_test(g.kind, g.holding_months))
+            .filter(|g| !(small_investor && g.kind == GainKind::Securities))
+            .map(|g| g.gain)
+            .sum();
+
+        Ok((taxable * CAPITAL_GAINS_RATE).max(Decimal::ZERO))
+    }
+
+    fn windfall_tax(&self, kind: WindfallKind, amount: Decimal) -> Result<Decimal, String> {
+        match kind {
+            WindfallKind::Bonus => Err(BONUS_IS_PAY.to_string()),
+            WindfallKind::Inheritance => Ok(Decimal::ZERO),
+            WindfallKind::Lottery => {
+                Ok(((amount - LOTTERY_TAX_FREE_LIMIT) * CAPITAL_GAINS_RATE).max(Decimal::ZERO))
+            }
         }
     }
 
+    fn dividend_tax_rate(&self) -> Decimal {
+        // Dividends are taxed at 15% as a separate tax base (withheld at source)
+        dec!(0.15)
+    }
+
+    fn rental_income_tax(
+        &self,
+        annual_rent: Decimal,
+        annual_costs: Decimal,
+    ) -> Result<Decimal, String> {
+        // Landlords deduct either actual costs or the 30% lump sum, whichever is higher
+        let expenses = annual_costs.max(annual_rent * RENTAL_EXPENSE_LUMP_SUM);
+        let profit = (annual_rent - expenses).max(Decimal::ZERO);
+        Ok((profit * RENTAL_INCOME_TAX_RATE).round_dp(2))
+    }
+
+    fn calculate_self_employed_tax(
+        &self,
+        gross_revenue: Decimal,
+        regime: SelfEmployedRegime,
+    ) -> Result<TaxBreakdown, String> {
+        if gross_revenue < Decimal::ZERO {
+            return Err("Self-employed revenue cannot be negative".to_string());
+        }
+
+        match regime {
+            SelfEmployedRegime::ExpenseLumpSum => {
+                // Profit after lump-sum expenses is the base for tax and insurance
+                let profit = gross_revenue * (Decimal::ONE - OSVC_EXPENSE_LUMP_SUM);
+
+                // TODO: Implement 23% bracket and taxpayer credit (same as employees)
+                let income_tax = profit * dec!(0.15);
+
+                // Social: 29.2% of 55% of profit, at least the minimum advance
+                let social_base = (profit * dec!(0.55)).min(SOCIAL_INSURANCE_MAX_BASE);
+                let social_insurance =
+                    (social_base * dec!(0.292)).max(OSVC_MIN_SOCIAL_MONTHLY * dec!(12));
+
+                // Health: 13.5% of 50% of profit, at least the minimum advance
+                let health_insurance =
+                    (profit * dec!(0.5) * dec!(0.135)).max(OSVC_MIN_HEALTH_MONTHLY * dec!(12));
+
+                let total = income_tax + social_insurance + health_insurance;
+                Ok(TaxBreakdown {
+                    income_tax,
+                    social_insurance,
+                    health_insurance,
+                    total,
+                })
+            }
+            SelfEmployedRegime::FlatTax => {
+                // One fixed monthly payment based on the revenue band
+                let Some((_, income_tax, social_insurance, health_insurance)) = FLAT_TAX_BANDS
+                    .iter()
+                    .find(|(limit, ..)| gross_revenue <= *limit)
+                else {
+                    // Revenue above the top band drops out of the flat tax into the lump sum
+                    return self.calculate_self_employed_tax(
+                        gross_revenue,
+                        SelfEmployedRegime::ExpenseLumpSum,
+                    );
+                };
+
+                let months = dec!(12);
+                let income_tax = *income_tax * months;
+                let social_insurance = *social_insurance * months;
+                let health_insurance = *health_insurance * months;
+                let total = income_tax + social_insurance + health_insurance;
+                Ok(TaxBreakdown {
+                    income_tax,
+                    social_insurance,
+                    health_insurance,
+                    total,
+                })
+            }
+        }
+    }
+
+    fn inflation_rate(&self) -> Decimal {
+        // CNB targets 2%; long-run average is a bit higher
+        // TODO: Verify
+        dec!(0.025)
+    }
+
+    fn expected_equity_return(&self) -> Decimal {
+        // Czech investors mostly hold global ETFs; CZK appreciation trims returns
+        // TODO: Verify
+        dec!(0.065)
+    }
+
+    fn equity_volatility(&self) -> Decimal {
+        // Currency swings add to the volatility of foreign stocks
+        // TODO: Verify
+        dec!(0.17)
+    }
+
     fn retirement_age(&self) -> u8 {
         // Czech retirement age is gradually increasing
-        // TODO: Implement dynamic calculation based on birth year and gender
         65
     }
 
+    fn retirement_age_for(&self, gender: Option<Gender>, birth_year: u32) -> u8 {
+        // Women born before 1966 still retire earlier; later cohorts converge on 65
+        // Simplified: the real schedule also depends on the number of children raised
+        // TODO: Verify
+        match gender {
+            Some(Gender::Female) if birth_year < 1966 => 64,
+            _ => self.retirement_age(),
+        }
+    }
+
+    fn state_pension_estimate(&self, career: &Career) -> Decimal {
+        // Pension = base amount + 1.495% of the reduced assessment base per insured year
+        // The assessment base counts fully up to the first reduction threshold
+        // and only 26% between the first and second thresholds
+        let average = career.average_monthly_salary();
+        let reduced_base = average.min(PENSION_REDUCTION_THRESHOLD_1)
+            + (average.min(PENSION_REDUCTION_THRESHOLD_2) - PENSION_REDUCTION_THRESHOLD_1)
+                .max(Decimal::ZERO)
+                * dec!(0.26);
+        let percentage_amount =
+            reduced_base * PENSION_PERCENT_PER_YEAR * Decimal::from(career.years_experience);
+
+        PENSION_BASE_AMOUNT + percentage_amount.round_dp(0)
+    }
+
     fn market_id(&self) -> &'static str {
         "czech"
     }
@@ -116,11 +417,275 @@ impl MarketProfile for CzechMarket {
     fn market_name(&self) -> &'static str {
         "Czech Republic"
     }
+
+    fn generate_housing(&self) -> Vec<Housing> {
+        vec![
+            // Cheap options - below reasonable budget
+            Housing {
+                id: "cz_shared_poor_1".to_string(),
+                housing_type: HousingType::Shared,
+                location: LocationQuality::Poor,
+                address: "Shared room, Černý Most".to_string(),
+                monthly_cost: dec!(4000),
+                monthly_utilities: dec!(1000),
+            },
+            Housing {
+                id: "cz_studio_poor_1".to_string(),
+                housing_type: HousingType::Studio,
+                location: LocationQuality::Poor,
+                address: "Small studio, Hostivař".to_string(),
+                monthly_cost: dec!(7000),
+                monthly_utilities: dec!(2000),
+            },
+            // Reasonable options - good value
+            Housing {
+                id: "cz_shared_avg_1".to_string(),
+                housing_type: HousingType::Shared,
+                location: LocationQuality::Average,
+                address: "Shared apartment, Háje".to_string(),
+                monthly_cost: dec!(6000),
+                monthly_utilities: dec!(1200),
+            },
+            Housing {
+                id: "cz_studio_avg_1".to_string(),
+                housing_type: HousingType::Studio,
+                location: LocationQuality::Average,
+                address: "Studio, Chodov".to_string(),
+                monthly_cost: dec!(10000),
+                monthly_utilities: dec!(2500),
+            },
+            Housing {
+                id: "cz_1bed_avg_1".to_string(),
+                housing_type: HousingType::OneBedroom,
+                location: LocationQuality::Average,
+                address: "1+kk, Nové Butovice".to_string(),
+                monthly_cost: dec!(13000),
+                monthly_utilities: dec!(3000),
+            },
+            // Good options - comfortable
+            Housing {
+                id: "cz_1bed_good_1".to_string(),
+                housing_type: HousingType::OneBedroom,
+                location: LocationQuality::Good,
+                address: "1+1, Karlín".to_string(),
+                monthly_cost: dec!(18000),
+                monthly_utilities: dec!(3500),
+            },
+            Housing {
+                id: "cz_2bed_good_1".to_string(),
+                housing_type: HousingType::TwoBedroom,
+                location: LocationQuality::Good,
+                address: "2+kk, Smíchov".to_string(),
+                monthly_cost: dec!(22000),
+                monthly_utilities: dec!(4000),
+            },
+            // Premium options - expensive
+            Housing {
+                id: "cz_2bed_prem_1".to_string(),
+                housing_type: HousingType::TwoBedroom,
+                location: LocationQuality::Premium,
+                address: "2+1, Vinohrady".to_string(),
+                monthly_cost: dec!(28000),
+                monthly_utilities: dec!(4500),
+            },
+            Housing {
+                id: "cz_3bed_prem_1".to_string(),
+                housing_type: HousingType::ThreeBedroom,
+                location: LocationQuality::Premium,
+                address: "3+1, Nové Město".to_string(),
+                monthly_cost: dec!(35000),
+                monthly_utilities: dec!(5000),
+            },
+            // Very expensive - above reasonable budget
+            Housing {
+                id: "cz_house_prem_1".to_string(),
+                housing_type: HousingType::House,
+                location: LocationQuality::Premium,
+                address: "House, Dejvice".to_string(),
+                monthly_cost: dec!(50000),
+                monthly_utilities: dec!(7000),
+            },
+        ]
+    }
+
+    fn job_listings(&self) -> Vec<Job> {
+        vec![
+            Job::new(
+                "cz_retail_entry".to_string(),
+                "Sales Associate".to_string(),
+                CareerField::Retail,
+                JobLevel::Entry,
+                dec!(25000), // 25k CZK/month
+                Some("Local Store".to_string()),
+            ),
+            Job::new(
+                "cz_admin_entry".to_string(),
+                "Administrative Assistant".to_string(),
+                CareerField::Other("Administration".to_string()),
+                JobLevel::Entry,
+                dec!(28000),
+                Some("Office Corp".to_string()),
+            ),
+            Job::new(
+                "cz_tech_entry".to_string(),
+                "Junior IT Support".to_string(),
+                CareerField::Technology,
+                JobLevel::Entry,
+                dec!(32000),
+                Some("Tech Solutions s.r.o.".to_string()),
+            ),
+            Job::new(
+                "cz_dev_junior".to_string(),
+                "Junior Software Developer".to_string(),
+                CareerField::Technology,
+                JobLevel::Junior,
+                dec!(45000),
+                Some("CodeCraft Prague".to_string()),
+            ),
+            // Graduate scheme that takes master's graduates straight from university
+            Job::new(
+                "cz_data_analyst_junior".to_string(),
+                "Graduate Data Analyst".to_string(),
+                CareerField::Technology,
+                JobLevel::Junior,
+                dec!(50000),
+                Some("Avast Prague".to_string()),
+            )
+            .with_required_experience(3),
+            Job::new(
+                "cz_accountant_junior".to_string(),
+                "Junior Accountant".to_string(),
+                CareerField::Finance,
+                JobLevel::Junior,
+                dec!(38000),
+                Some("Finance Group".to_string()),
+            )
+            .with_annual_bonus(Decimal::ONE),
+            Job::new(
+                "cz_teacher_junior".to_string(),
+                "Elementary School Teacher".to_string(),
+                CareerField::Education,
+                JobLevel::Junior,
+                dec!(35000),
+                Some("Praha Elementary".to_string()),
+            ),
+            Job::new(
+                "cz_dev_mid".to_string(),
+                "Software Developer".to_string(),
+                CareerField::Technology,
+                JobLevel::Mid,
+                dec!(65000),
+                Some("TechCorp Prague".to_string()),
+            ),
+            Job::new(
+                "cz_accountant_mid".to_string(),
+                "Accountant".to_string(),
+                CareerField::Finance,
+                JobLevel::Mid,
+                dec!(52000),
+                Some("KPMG Czech".to_string()),
+            )
+            .with_annual_bonus(Decimal::ONE),
+            Job::new(
+                "cz_manager_mid".to_string(),
+                "Team Manager".to_string(),
+                CareerField::Manufacturing,
+                JobLevel::Mid,
+                dec!(58000),
+                Some("Škoda Auto".to_string()),
+            ),
+            Job::new(
+                "cz_nurse_mid".to_string(),
+                "Registered Nurse".to_string(),
+                CareerField::Healthcare,
+                JobLevel::Mid,
+                dec!(48000),
+                Some("Motol Hospital".to_string()),
+            ),
+            Job::new(
+                "cz_dev_senior".to_string(),
+                "Senior Software Engineer".to_string(),
+                CareerField::Technology,
+                JobLevel::Senior,
+                dec!(90000),
+                Some("Avast Software".to_string()),
+            ),
+            Job::new(
+                "cz_accountant_senior".to_string(),
+                "Senior Financial Analyst".to_string(),
+                CareerField::Finance,
+                JobLevel::Senior,
+                dec!(75000),
+                Some("Česká spořitelna".to_string()),
+            )
+            .with_annual_bonus(Decimal::ONE),
+            Job::new(
+                "cz_doctor_senior".to_string(),
+                "Specialist Physician".to_string(),
+                CareerField::Healthcare,
+                JobLevel::Senior,
+                dec!(85000),
+                Some("General Hospital Prague".to_string()),
+            ),
+            Job::new(
+                "cz_arch_lead".to_string(),
+                "Lead Software Architect".to_string(),
+                CareerField::Technology,
+                JobLevel::Lead,
+                dec!(120000),
+                Some("O2 Czech Republic".to_string()),
+            ),
+            Job::new(
+                "cz_cfo_lead".to_string(),
+                "Finance Director".to_string(),
+                CareerField::Finance,
+                JobLevel::Lead,
+                dec!(110000),
+                Some("Česká pojišťovna".to_string()),
+            )
+            .with_annual_bonus(Decimal::ONE),
+            Job::new(
+                "cz_director_lead".to_string(),
+                "Operations Director".to_string(),
+                CareerField::Manufacturing,
+                JobLevel::Lead,
+                dec!(100000),
+                Some("ČEZ Group".to_string()),
+            ),
+        ]
+    }
+
+    fn security_deposit_months(&self) -> Decimal {
+        // Czech landlords typically ask for 2 months of rent (legal maximum is 3)
+        dec!(2)
+    }
+
+    fn moving_fee(&self) -> Decimal {
+        // Moving van and helpers within Prague
+        dec!(1500)
+    }
+
+    fn self_payer_health_insurance(&self) -> Decimal {
+        // Osoby bez zdanitelných příjmů pay the full rate on the minimum wage
+        (HEALTH_INSURANCE_MIN_BASE * HEALTH_INSURANCE_FULL_RATE)
+            .round_dp_with_strategy(0, rust_decimal::RoundingStrategy::AwayFromZero)
+    }
+
+    fn essential_minimum(&self) -> Decimal {
+        // Basic groceries for one person
+        dec!(3500)
+    }
+
+    fn minimum_wage(&self) -> Decimal {
+        // 2024 minimum wage (also the minimum health insurance base)
+        HEALTH_INSURANCE_MIN_BASE
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::{CareerField, Job, JobLevel};
     use rust_decimal_macros::dec;
 
     #[test]
@@ -146,20 +711,256 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_social_insurance_cap() {
+        let market = CzechMarket::new();
+
+        // Below the cap: full 7.1%
+        let below = market.calculate_income_tax(dec!(600000)).unwrap();
+        assert_eq!(below.social_insurance, dec!(42600));
+
+        // Well above the cap: social insurance flattens out at 7.1% of the max base
+        let above = market.calculate_income_tax(dec!(3000000)).unwrap();
+        let far_above = market.calculate_income_tax(dec!(5000000)).unwrap();
+        let capped = SOCIAL_INSURANCE_MAX_BASE * dec!(0.071);
+        assert_eq!(above.social_insurance, capped);
+        assert_eq!(far_above.social_insurance, capped);
+
+        // Health insurance keeps growing with income
+        assert_eq!(above.health_insurance, dec!(135000));
+        assert!(far_above.health_insurance > above.health_insurance);
+    }
+
+    #[test]
+    fn test_self_employed_lump_sum_differs_from_employee() {
+        let market = CzechMarket::new();
+        let gross = dec!(600000); // 50k CZK monthly
+
+        let employee = market.calculate_income_tax(gross).unwrap();
+        let freelancer = market
+            .calculate_self_employed_tax(gross, SelfEmployedRegime::ExpenseLumpSum)
+            .unwrap();
+
+        // 60% expenses leave 240k profit: 15% tax = 36k
+        assert_eq!(freelancer.income_tax, dec!(36000));
+        // Social: 240k * 55% * 29.2%
+        assert_eq!(freelancer.social_insurance, dec!(38544));
+        // Health: 240k * 50% * 13.5% = 16,200 is below the minimum advance
+        assert_eq!(freelancer.health_insurance, dec!(35616));
+        assert!(freelancer.total < employee.total);
+    }
+
+    #[test]
+    fn test_self_employed_flat_tax() {
+        let market = CzechMarket::new();
+
+        let band1 = market
+            .calculate_monthly_self_employed_tax(dec!(50000), SelfEmployedRegime::FlatTax)
+            .unwrap();
+        assert_eq!(band1.total, dec!(7498));
+
+        let band2 = market
+            .calculate_self_employed_tax(dec!(1200000), SelfEmployedRegime::FlatTax)
+            .unwrap();
+        assert_eq!(band2.total, dec!(16745) * dec!(12));
+
+        // Above 2M CZK the flat tax is not available, so the lump sum applies
+        assert_eq!(
+            market
+                .calculate_self_employed_tax(dec!(2500000), SelfEmployedRegime::FlatTax)
+                .unwrap()
+                .total,
+            market
+                .calculate_self_employed_tax(dec!(2500000), SelfEmployedRegime::ExpenseLumpSum)
+                .unwrap()
+                .total
+        );
+    }
+
+    #[test]
+    fn test_monthly_tax_matches_annual() {
+        let market = CzechMarket::new();
+        let monthly = market.calculate_monthly_tax(dec!(50000)).unwrap();
+        let annual = market.calculate_income_tax(dec!(600000)).unwrap();
+
+        assert_eq!(monthly.total * dec!(12), annual.total);
+        assert_eq!(monthly.social_insurance, dec!(3550)); // 7.1% of 50k
+    }
+
+    #[test]
+    fn test_essential_minimum() {
+        let market = CzechMarket;
+        assert_eq!(market.essential_minimum(), dec!(3500));
+        assert_eq!(market.clamp_essential_budget(dec!(2000)), dec!(3500));
+        assert_eq!(market.clamp_essential_budget(dec!(5000)), dec!(5000));
+    }
+
+    #[test]
+    fn test_spouse_tax_credit() {
+        let market = CzechMarket::new();
+        let individual = market.calculate_income_tax(dec!(600000)).unwrap();
+
+        // Non-earning partner: 24,840 credit off the income tax
+        let joint = market
+            .calculate_joint_income_tax(dec!(600000), dec!(0))
+            .unwrap();
+        assert_eq!(joint.income_tax, individual.income_tax - dec!(24840));
+        assert_eq!(joint.total, individual.total - dec!(24840));
+
+        // Earning partner: taxed individually, no credit
+        let both = market
+            .calculate_joint_income_tax(dec!(600000), dec!(400000))
+            .unwrap();
+        let partner = market.calculate_income_tax(dec!(400000)).unwrap();
+        assert_eq!(both.total, individual.total + partner.total);
+    }
+
+    #[test]
+    fn test_state_pension_grows_with_insured_years() {
+        let market = CzechMarket::new();
+        let mut career = Career::new();
+        career.accept_job(Job::new(
+            "dev".to_string(),
+            "Developer".to_string(),
+            CareerField::Technology,
+            JobLevel::Mid,
+            dec!(20000),
+            None,
+        ));
+
+        career.years_experience = 0;
+        assert_eq!(market.state_pension_estimate(&career), dec!(4400));
+
+        // 20,000 is below the first threshold: 4,400 + 20,000 * 1.495% * 40
+        career.years_experience = 40;
+        assert_eq!(market.state_pension_estimate(&career), dec!(16360));
+
+        career.years_experience = 20;
+        assert!(market.state_pension_estimate(&career) < dec!(16360));
+    }
+
+    #[test]
+    fn test_retirement_age_by_gender() {
+        let market = CzechMarket::new();
+        assert_eq!(market.retirement_age_for(Some(Gender::Female), 1960), 64);
+        assert_eq!(market.retirement_age_for(Some(Gender::Male), 1960), 65);
+        assert_eq!(market.retirement_age_for(None, 1960), 65);
+        // Later cohorts retire at the same age
+        assert_eq!(market.retirement_age_for(Some(Gender::Female), 1990), 65);
+    }
+
+    fn sale(proceeds: Decimal, gain: Decimal, years: u32) -> RealizedGain {
+        RealizedGain::new(
+            "ETF".to_string(),
+            GainKind::Securities,
+            proceeds,
+            gain,
+            years * 12,
+            Decimal::ZERO,
+        )
+    }
+
+    #[test]
+    fn test_small_securities_sales_exempt() {
+        let market = CzechMarket::new();
+        // Three sales totalling 90k proceeds stay under the 100k limit
+        let gains = vec![
+            sale(dec!(30000), dec!(10000), 0),
+            sale(dec!(30000), dec!(10000), 1),
+            sale(dec!(30000), dec!(10000), 2),
+        ];
+        assert_eq!(
+            market.annual_capital_gains_tax(&gains).unwrap(),
+            Decimal::ZERO
+        );
+
+        // One more sale crosses the limit and all short holdings become taxable
+        let mut gains = gains;
+        gains.push(sale(dec!(20000), dec!(10000), 0));
+        assert_eq!(market.annual_capital_gains_tax(&gains).unwrap(), dec!(6000));
+    }
+
+    #[test]
+    fn test_annual_gains_offset_losses() {
+        let market = CzechMarket::new();
+        let gains = vec![
+            sale(dec!(200000), dec!(50000), 1),
+            sale(dec!(100000), dec!(-30000), 1),
+            // Held past the time test, exempt regardless
+            sale(dec!(500000), dec!(200000), 4),
+        ];
+        assert_eq!(market.annual_capital_gains_tax(&gains).unwrap(), dec!(3000));
+    }
+
+    #[test]
+    fn test_annual_property_gains_use_ten_year_test() {
+        let market = CzechMarket::new();
+        let flat = |years| RealizedGain {
+            kind: GainKind::Property,
+            ..sale(dec!(4000000), dec!(1000000), years)
+        };
+
+        // Past the securities test but not the property one
+        assert_eq!(
+            market.annual_capital_gains_tax(&[flat(5)]).unwrap(),
+            dec!(150000)
+        );
+        assert_eq!(
+            market.annual_capital_gains_tax(&[flat(10)]).unwrap(),
+            Decimal::ZERO
+        );
+    }
+
     #[test]
     fn test_capital_gains_three_year_exemption() {
         let market = CzechMarket::new();
         let gain = dec!(100000);
 
         // Less than 3 years - should have tax
-        let short_period = Duration::from_secs(2 * 365 * 24 * 60 * 60);
-        let tax_short = market.capital_gains_tax(short_period, gain).unwrap();
+        let tax_short = market
+            .capital_gains_tax(GainKind::Securities, 35, gain)
+            .unwrap();
         assert!(tax_short > Decimal::ZERO);
 
         // 3+ years - should be exempt
-        let long_period = Duration::from_secs(3 * 365 * 24 * 60 * 60);
-        let tax_long = market.capital_gains_tax(long_period, gain).unwrap();
+        let tax_long = market
+            .capital_gains_tax(GainKind::Securities, 36, gain)
+            .unwrap();
         assert_eq!(tax_long, Decimal::ZERO);
+        assert_eq!(
+            market.tax_free_holding_months(GainKind::Securities),
+            Some(36)
+        );
+    }
+
+    #[test]
+    fn test_property_ten_year_exemption() {
+        let market = CzechMarket::new();
+        let gain = dec!(1000000);
+
+        // Property needs 10 years, not the 3 of securities
+        assert_eq!(
+            market
+                .capital_gains_tax(GainKind::Property, 36, gain)
+                .unwrap(),
+            dec!(150000)
+        );
+        assert_eq!(
+            market
+                .capital_gains_tax(GainKind::Property, 119, gain)
+                .unwrap(),
+            dec!(150000)
+        );
+        assert_eq!(
+            market
+                .capital_gains_tax(GainKind::Property, 120, gain)
+                .unwrap(),
+            Decimal::ZERO
+        );
+        assert_eq!(
+            market.tax_free_holding_months(GainKind::Property),
+            Some(120)
+        );
     }
 
     #[test]
@@ -172,4 +973,85 @@ mod tests {
         assert!(accounts.iter().any(|a| a.id == "third_pillar"));
         assert!(accounts.iter().any(|a| a.id == "stavebni_sporeni"));
     }
+
+    #[test]
+    fn test_withdrawals_reduce_deductible_contributions() {
+        let market = CzechMarket::new();
+        let mut dip = Account::new(
+            "dip".to_string(),
+            "DIP".to_string(),
+            AccountKind::Retirement {
+                account_type_id: "dip".to_string(),
+            },
+        );
+        dip.deposit(dec!(40000)).unwrap();
+        assert_eq!(
+            market.tax_deductible_contributions(&[dip.clone()]),
+            dec!(40000)
+        );
+
+        // Money moved in and back out can't be deducted
+        dip.withdraw(dec!(30000)).unwrap();
+        assert_eq!(
+            market.tax_deductible_contributions(&[dip.clone()]),
+            dec!(10000)
+        );
+        dip.withdraw(dec!(10000)).unwrap();
+        assert_eq!(market.tax_deductible_contributions(&[dip]), Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_generate_housing() {
+        let market = CzechMarket::new();
+        let options = market.generate_housing();
+        assert_eq!(options.len(), 10);
+
+        // Should have a range of prices
+        let min_cost = options
+            .iter()
+            .map(|h| h.total_monthly_cost())
+            .min()
+            .unwrap();
+        let max_cost = options
+            .iter()
+            .map(|h| h.total_monthly_cost())
+            .max()
+            .unwrap();
+
+        assert_eq!(min_cost, dec!(3000)); // Shared room on the outskirts, half paid by roommates
+        assert_eq!(max_cost, dec!(57000)); // House in Dejvice
+    }
+
+    #[test]
+    fn test_moving_cost() {
+        let market = CzechMarket::new();
+        let options = market.generate_housing();
+        let studio = options.iter().find(|h| h.id == "cz_studio_avg_1").unwrap();
+
+        // 2 months deposit (20,000) + 1,500 moving fee
+        assert_eq!(studio.moving_cost(&market), dec!(21500));
+    }
+
+    #[test]
+    fn test_tiny_salary_pays_minimum_health_insurance() {
+        let market = CzechMarket::new();
+
+        // 4.5% of the salary plus the full 13.5% on the shortfall to the minimum wage
+        let tiny = market.calculate_monthly_payroll_tax(dec!(1000)).unwrap();
+        assert_eq!(tiny.health_insurance, dec!(2461.5));
+        assert!(tiny.health_insurance > dec!(1000) * dec!(0.045));
+
+        // Above the minimum wage only the employee rate applies
+        let regular = market.calculate_monthly_payroll_tax(dec!(40000)).unwrap();
+        assert_eq!(regular.health_insurance, dec!(1800));
+
+        // Only salary is topped up, not a bonus or windfall taxed on its own
+        let bonus = market.calculate_monthly_tax(dec!(1000)).unwrap();
+        assert_eq!(bonus.health_insurance, dec!(45));
+
+        // No income at all is a self-payer question, not payroll
+        let none = market.calculate_income_tax(Decimal::ZERO).unwrap();
+        assert_eq!(none.total, Decimal::ZERO);
+        assert_eq!(market.self_payer_health_insurance(), dec!(2552));
+    }
 }
@@ -0,0 +1,465 @@
+//! Generic euro-area market implementation
+//!
+//! A configurable profile for the many EU countries that share the euro.
+//! The defaults describe a typical euro-area system; individual countries can
+//! override brackets and rates through the public fields.
+
+use crate::core::{
+    Career, CareerField, GainKind, Housing, HousingType, Job, JobLevel, LocationQuality,
+    RealizedGain, SelfEmployedRegime, WindfallKind,
+};
+use crate::market::{AccountType, Currency, MarketProfile, TaxBreakdown, BONUS_IS_PAY};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+/// Share of self-employed revenue assumed to cover business costs
+const SELF_EMPLOYED_EXPENSE_SHARE: Decimal = dec!(0.30);
+
+/// Years of contributions counted toward the state pension
+const MAX_PENSION_YEARS: u8 = 40;
+
+/// Euro-area market profile
+#[derive(Debug, Clone)]
+pub struct EuroMarket {
+    /// Market identifier
+    pub id: &'static str,
+    /// Display name
+    pub name: &'static str,
+    /// Progressive income tax bands as (upper limit of annual income, rate)
+    /// The last band has no upper limit.
+    pub tax_brackets: Vec<(Option<Decimal>, Decimal)>,
+    /// Employee social insurance rate on gross income
+    pub social_insurance_rate: Decimal,
+    /// Employee health insurance rate on gross income
+    pub health_insurance_rate: Decimal,
+    /// Flat rate on capital gains
+    pub capital_gains_rate: Decimal,
+    /// Flat rate on dividends
+    pub dividend_rate: Decimal,
+    /// Expected annual inflation
+    pub inflation: Decimal,
+    /// Expected annual return of a stock portfolio
+    pub equity_return: Decimal,
+    /// Annual volatility of that portfolio
+    pub equity_volatility: Decimal,
+    /// Monthly gross minimum wage for full-time work
+    pub minimum_wage: Decimal,
+    /// State pension age
+    pub pension_age: u8,
+    /// Share of the average salary paid as pension per contribution year
+    pub pension_accrual_rate: Decimal,
+}
+
+impl EuroMarket {
+    /// Creates a profile with typical euro-area defaults
+    /// TODO: Verify - a blend of common euro-area rates, not any one country
+    pub fn new() -> Self {
+        EuroMarket {
+            id: "euro",
+            name: "Euro Area",
+            tax_brackets: vec![
+                (Some(dec!(12000)), Decimal::ZERO),
+                (Some(dec!(40000)), dec!(0.20)),
+                (Some(dec!(80000)), dec!(0.30)),
+                (None, dec!(0.42)),
+            ],
+            social_insurance_rate: dec!(0.09),
+            health_insurance_rate: dec!(0.04),
+            capital_gains_rate: dec!(0.25),
+            dividend_rate: dec!(0.25),
+            // ECB targets 2%
+            inflation: dec!(0.02),
+            // Broad European equity index
+            equity_return: dec!(0.065),
+            equity_volatility: dec!(0.16),
+            minimum_wage: dec!(1500),
+            pension_age: 65,
+            pension_accrual_rate: dec!(0.015),
+        }
+    }
+
+    /// Applies the progressive brackets to an annual taxable income
+    fn bracket_tax(&self, income: Decimal) -> Decimal {
+        let mut tax = Decimal::ZERO;
+        let mut lower = Decimal::ZERO;
+        for (upper, rate) in &self.tax_brackets {
+            let top = upper.map_or(income, |u| income.min(u));
+            if top > lower {
+                tax += (top - lower) * rate;
+            }
+            match upper {
+                Some(u) if income > *u => lower = *u,
+                _ => break,
+            }
+        }
+        tax
+    }
+}
+
+impl Default for EuroMarket {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MarketProfile for EuroMarket {
+    fn currency(&self) -> Currency {
+        Currency::EUR
+    }
+
+    fn calculate_income_tax(&self, gross_income: Decimal) -> Result<TaxBreakdown, String> {
+        if gross_income < Decimal::ZERO {
+            return Err("Income cannot be negative".to_string());
+        }
+        let social_insurance = gross_income * self.social_insurance_rate;
+        let health_insurance = gross_income * self.health_insurance_rate;
+        // Employee contributions are deductible from the income tax base
+        let income_tax = self.bracket_tax(gross_income - social_insurance - health_insurance);
+
+        Ok(TaxBreakdown {
+            income_tax,
+            social_insurance,
+            health_insurance,
+            total: income_tax + social_insurance + health_insurance,
+        })
+    }
+
+    fn calculate_self_employed_tax(
+        &self,
+        gross_revenue: Decimal,
+        _regime: SelfEmployedRegime,
+    ) -> Result<TaxBreakdown, String> {
+        // Both regimes fall back to lump-sum expenses
+        let profit = gross_revenue * (Decimal::ONE - SELF_EMPLOYED_EXPENSE_SHARE);
+        self.calculate_income_tax(profit)
+    }
+
+    fn available_accounts(&self) -> Vec<AccountType> {
+        vec![AccountType {
+            id: "private_pension".to_string(),
+            name: "Private Pension Plan".to_string(),
+            annual_limit: Some(dec!(3000)),
+            employer_match: true,
+        }]
+    }
+
+    fn capital_gains_tax(
+        &self,
+        _kind: GainKind,
+        _holding_months: u32,
+        gain: Decimal,
+    ) -> Result<Decimal, String> {
+        Ok((gain * self.capital_gains_rate).max(Decimal::ZERO))
+    }
+
+    fn long_term_gains_tax_rate(&self) -> Decimal {
+        self.capital_gains_rate
+    }
+
+    fn annual_capital_gains_tax(&self, gains: &[RealizedGain]) -> Result<Decimal, String> {
+        let net: Decimal = gains.iter().map(|g| g.gain).sum();
+        Ok((net * self.capital_gains_rate).max(Decimal::ZERO))
+    }
+
+    fn windfall_tax(&self, kind: WindfallKind, _amount: Decimal) -> Result<Decimal, String> {
+        match kind {
+            WindfallKind::Bonus => Err(BONUS_IS_PAY.to_string()),
+            // TODO: Inheritance tax varies widely between member states
+            WindfallKind::Inheritance | WindfallKind::Lottery => Ok(Decimal::ZERO),
+        }
+    }
+
+    fn dividend_tax_rate(&self) -> Decimal {
+        self.dividend_rate
+    }
+
+    fn inflation_rate(&self) -> Decimal {
+        self.inflation
+    }
+
+    fn expected_equity_return(&self) -> Decimal {
+        self.equity_return
+    }
+
+    fn equity_volatility(&self) -> Decimal {
+        self.equity_volatility
+    }
+
+    fn retirement_age(&self) -> u8 {
+        self.pension_age
+    }
+
+    fn state_pension_estimate(&self, career: &Career) -> Decimal {
+        let years = career.years_experience.min(MAX_PENSION_YEARS);
+        (career.average_monthly_salary() * self.pension_accrual_rate * Decimal::from(years))
+            .round_dp(2)
+    }
+
+    fn market_id(&self) -> &'static str {
+        self.id
+    }
+
+    fn market_name(&self) -> &'static str {
+        self.name
+    }
+
+    fn generate_housing(&self) -> Vec<Housing> {
+        vec![
+            Housing {
+                id: "eu_shared_avg_1".to_string(),
+                housing_type: HousingType::Shared,
+                location: LocationQuality::Average,
+                address: "Room in a shared flat".to_string(),
+                monthly_cost: dec!(450),
+                monthly_utilities: dec!(80),
+            },
+            Housing {
+                id: "eu_studio_avg_1".to_string(),
+                housing_type: HousingType::Studio,
+                location: LocationQuality::Average,
+                address: "Studio near the city centre".to_string(),
+                monthly_cost: dec!(750),
+                monthly_utilities: dec!(120),
+            },
+            Housing {
+                id: "eu_one_bed_good_1".to_string(),
+                housing_type: HousingType::OneBedroom,
+                location: LocationQuality::Good,
+                address: "One-bedroom flat, quiet district".to_string(),
+                monthly_cost: dec!(1100),
+                monthly_utilities: dec!(150),
+            },
+        ]
+    }
+
+    /// TODO: Verify - typical euro-area gross salaries, not any one country
+    fn job_listings(&self) -> Vec<Job> {
+        vec![
+            Job::new(
+                "eu_retail_entry".to_string(),
+                "Sales Associate".to_string(),
+                CareerField::Retail,
+                JobLevel::Entry,
+                dec!(1700),
+                Some("City Market".to_string()),
+            ),
+            Job::new(
+                "eu_admin_entry".to_string(),
+                "Administrative Assistant".to_string(),
+                CareerField::Other("Administration".to_string()),
+                JobLevel::Entry,
+                dec!(1900),
+                Some("Office Services GmbH".to_string()),
+            ),
+            Job::new(
+                "eu_tech_entry".to_string(),
+                "IT Support Technician".to_string(),
+                CareerField::Technology,
+                JobLevel::Entry,
+                dec!(2300),
+                Some("Helpdesk Solutions".to_string()),
+            ),
+            Job::new(
+                "eu_dev_junior".to_string(),
+                "Junior Software Developer".to_string(),
+                CareerField::Technology,
+                JobLevel::Junior,
+                dec!(3200),
+                Some("Berlin Code Works".to_string()),
+            ),
+            // Graduate scheme that takes master's graduates straight from university
+            Job::new(
+                "eu_data_analyst_junior".to_string(),
+                "Graduate Data Analyst".to_string(),
+                CareerField::Technology,
+                JobLevel::Junior,
+                dec!(3600),
+                Some("Amsterdam Analytics".to_string()),
+            )
+            .with_required_experience(3),
+            Job::new(
+                "eu_accountant_junior".to_string(),
+                "Junior Accountant".to_string(),
+                CareerField::Finance,
+                JobLevel::Junior,
+                dec!(2700),
+                Some("Euro Audit Partners".to_string()),
+            )
+            .with_annual_bonus(Decimal::ONE),
+            Job::new(
+                "eu_teacher_junior".to_string(),
+                "Primary School Teacher".to_string(),
+                CareerField::Education,
+                JobLevel::Junior,
+                dec!(2600),
+                Some("Municipal Primary School".to_string()),
+            ),
+            Job::new(
+                "eu_dev_mid".to_string(),
+                "Software Developer".to_string(),
+                CareerField::Technology,
+                JobLevel::Mid,
+                dec!(4500),
+                Some("SAP".to_string()),
+            ),
+            Job::new(
+                "eu_accountant_mid".to_string(),
+                "Accountant".to_string(),
+                CareerField::Finance,
+                JobLevel::Mid,
+                dec!(3800),
+                Some("Deloitte".to_string()),
+            )
+            .with_annual_bonus(Decimal::ONE),
+            Job::new(
+                "eu_manager_mid".to_string(),
+                "Production Team Manager".to_string(),
+                CareerField::Manufacturing,
+                JobLevel::Mid,
+                dec!(4200),
+                Some("Siemens".to_string()),
+            ),
+            Job::new(
+                "eu_nurse_mid".to_string(),
+                "Registered Nurse".to_string(),
+                CareerField::Healthcare,
+                JobLevel::Mid,
+                dec!(3300),
+                Some("University Hospital".to_string()),
+            ),
+            Job::new(
+                "eu_dev_senior".to_string(),
+                "Senior Software Engineer".to_string(),
+                CareerField::Technology,
+                JobLevel::Senior,
+                dec!(6000),
+                Some("Adyen".to_string()),
+            ),
+            Job::new(
+                "eu_analyst_senior".to_string(),
+                "Senior Financial Analyst".to_string(),
+                CareerField::Finance,
+                JobLevel::Senior,
+                dec!(5200),
+                Some("BNP Paribas".to_string()),
+            )
+            .with_annual_bonus(Decimal::ONE),
+            Job::new(
+                "eu_doctor_senior".to_string(),
+                "Specialist Physician".to_string(),
+                CareerField::Healthcare,
+                JobLevel::Senior,
+                dec!(6500),
+                Some("Charité Berlin".to_string()),
+            ),
+            Job::new(
+                "eu_arch_lead".to_string(),
+                "Lead Software Architect".to_string(),
+                CareerField::Technology,
+                JobLevel::Lead,
+                dec!(8000),
+                Some("ASML".to_string()),
+            ),
+            Job::new(
+                "eu_cfo_lead".to_string(),
+                "Finance Director".to_string(),
+                CareerField::Finance,
+                JobLevel::Lead,
+                dec!(7500),
+                Some("Allianz".to_string()),
+            )
+            .with_annual_bonus(Decimal::ONE),
+            Job::new(
+                "eu_director_lead".to_string(),
+                "Operations Director".to_string(),
+                CareerField::Manufacturing,
+                JobLevel::Lead,
+                dec!(7000),
+                Some("Airbus".to_string()),
+            ),
+        ]
+    }
+
+    fn security_deposit_months(&self) -> Decimal {
+        dec!(2)
+    }
+
+    fn moving_fee(&self) -> Decimal {
+        dec!(500)
+    }
+
+    fn essential_minimum(&self) -> Decimal {
+        dec!(250)
+    }
+
+    fn minimum_wage(&self) -> Decimal {
+        self.minimum_wage
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{EconomyState, JobMarket};
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_currency() {
+        assert_eq!(EuroMarket::new().currency(), Currency::EUR);
+    }
+
+    #[test]
+    fn test_tax_breakdown_is_consistent() {
+        let market = EuroMarket::new();
+        let taxes = market.calculate_income_tax(dec!(50000)).unwrap();
+
+        // 13% contributions, then brackets on the remaining 43,500:
+        // 28,000 * 20% + 3,500 * 30% = 6,650
+        assert_eq!(taxes.social_insurance, dec!(4500));
+        assert_eq!(taxes.health_insurance, dec!(2000));
+        assert_eq!(taxes.income_tax, dec!(6650));
+        assert_eq!(
+            taxes.total,
+            taxes.income_tax + taxes.social_insurance + taxes.health_insurance
+        );
+
+        // Low incomes stay within the tax-free band
+        let low = market.calculate_income_tax(dec!(10000)).unwrap();
+        assert_eq!(low.income_tax, Decimal::ZERO);
+        assert!(market.calculate_income_tax(dec!(-1)).is_err());
+    }
+
+    #[test]
+    fn test_jobs_are_listed_in_euros() {
+        let market = EuroMarket::new();
+        let jobs = market.job_listings();
+        assert!(jobs
+            .iter()
+            .all(|job| market.check_minimum_wage(job).is_ok()));
+        assert!(jobs.iter().all(|job| job.id.starts_with("eu_")));
+
+        // New players start at entry level, veterans see lead roles
+        let starting =
+            JobMarket::generate_starting_jobs(&market, &HashMap::new(), &EconomyState::default());
+        assert!(!starting.is_empty());
+        assert!(starting.iter().all(|job| job.level == JobLevel::Entry));
+        let mut career = Career::new();
+        career.years_experience = 10;
+        let lead =
+            JobMarket::generate_jobs(&market, &career, &HashMap::new(), &EconomyState::default());
+        assert!(lead.iter().any(|job| job.level == JobLevel::Lead));
+    }
+
+    #[test]
+    fn test_brackets_are_configurable() {
+        let flat = EuroMarket {
+            id: "flat",
+            name: "Flat Tax Land",
+            tax_brackets: vec![(None, dec!(0.19))],
+            ..EuroMarket::new()
+        };
+        let taxes = flat.calculate_income_tax(dec!(100000)).unwrap();
+        assert_eq!(taxes.income_tax, dec!(87000) * dec!(0.19));
+        assert_eq!(flat.market_id(), "flat");
+    }
+}
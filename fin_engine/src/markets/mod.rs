@@ -1,6 +1,9 @@
 //! Market-specific implementations
 //!
 //! Each submodule implements the `MarketProfile` trait for a specific country.
+//! The registry below lists whichever markets are compiled in.
+
+use crate::market::{Currency, MarketProfile};
 
 #[cfg(feature = "czech")]
 pub mod czech;
@@ -10,3 +13,114 @@ pub mod usa;
 
 #[cfg(feature = "uk")]
 pub mod uk;
+
+#[cfg(feature = "euro")]
+pub mod euro;
+
+/// Summary of a market for selection screens
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarketInfo {
+    /// Market identifier (as stored in `GameState::market_id`)
+    pub id: &'static str,
+    /// Display name
+    pub name: &'static str,
+    /// Currency the market uses
+    pub currency: Currency,
+    /// Currency symbol
+    pub symbol: &'static str,
+}
+
+/// Returns a profile for every market enabled by feature flags
+pub fn market_profiles() -> Vec<Box<dyn MarketProfile>> {
+    vec![
+        #[cfg(feature = "czech")]
+        Box::new(czech::CzechMarket::new()),
+        #[cfg(feature = "usa")]
+        Box::new(usa::UsaMarket::new()),
+        #[cfg(feature = "uk")]
+        Box::new(uk::UkMarket::new()),
+        #[cfg(feature = "euro")]
+        Box::new(euro::EuroMarket::new()),
+    ]
+}
+
+/// Looks up a compiled-in market by its identifier
+pub fn market_profile(id: &str) -> Option<Box<dyn MarketProfile>> {
+    market_profiles().into_iter().find(|m| m.market_id() == id)
+}
+
+/// Lists the markets a new game can be started in
+pub fn available_markets() -> Vec<MarketInfo> {
+    market_profiles()
+        .iter()
+        .map(|market| {
+            let currency = market.currency();
+            MarketInfo {
+                id: market.market_id(),
+                name: market.market_name(),
+                currency,
+                symbol: currency.symbol(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[cfg(feature = "usa")]
+    #[test]
+    fn test_enabled_usa_market_is_listed() {
+        let usa = available_markets()
+            .into_iter()
+            .find(|m| m.id == "usa")
+            .unwrap();
+        assert_eq!(usa.name, "United States");
+        assert_eq!(usa.currency, Currency::USD);
+        assert_eq!(usa.symbol, "$");
+        assert!(market_profile("usa").is_some());
+    }
+
+    #[cfg(not(feature = "usa"))]
+    #[test]
+    fn test_disabled_usa_market_is_not_listed() {
+        assert!(available_markets().iter().all(|m| m.id != "usa"));
+        assert!(market_profile("usa").is_none());
+        // The default Czech market is always there to start a game in
+        assert!(available_markets().iter().any(|m| m.id == "czech"));
+    }
+
+    #[test]
+    fn test_market_lookup() {
+        for info in available_markets() {
+            let market = market_profile(info.id).unwrap();
+            assert_eq!(market.market_name(), info.name);
+            assert_eq!(market.currency().symbol(), info.symbol);
+        }
+        assert!(market_profile("atlantis").is_none());
+    }
+
+    #[test]
+    fn test_market_return_assumptions_are_plausible() {
+        for market in market_profiles() {
+            let expected = market.expected_equity_return();
+            let volatility = market.equity_volatility();
+            // Real returns stay positive but within long-run historical ranges
+            assert!(expected > market.inflation_rate(), "{}", market.market_id());
+            assert!(expected <= dec!(0.10));
+            assert!(volatility >= dec!(0.10));
+            assert!(volatility <= dec!(0.25));
+            // Bonds earn less than stocks but at least keep up with inflation
+            let bonds = market.expected_bond_return();
+            assert!(bonds < expected, "{}", market.market_id());
+            assert!(bonds >= market.inflation_rate(), "{}", market.market_id());
+        }
+        #[cfg(feature = "czech")]
+        assert_eq!(
+            market_profile("czech").unwrap().expected_equity_return(),
+            dec!(0.065)
+        );
+    }
+}
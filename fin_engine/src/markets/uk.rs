@@ -2,9 +2,25 @@
 //!
 //! TODO: Implement UK-specific financial rules
 
-use crate::market::{AccountType, Currency, MarketProfile, TaxBreakdown};
+use crate::core::{Career, GainKind, Housing, RealizedGain, SelfEmployedRegime, WindfallKind};
+use crate::market::{AccountType, Currency, MarketProfile, TaxBreakdown, BONUS_IS_PAY};
 use rust_decimal::Decimal;
-use std::time::Duration;
+use rust_decimal_macros::dec;
+
+/// Annual exempt amount for capital gains (2024/25)
+/// TODO: Verify - cut from 6,000 to 3,000 in April 2024
+const CGT_ANNUAL_EXEMPT_AMOUNT: Decimal = dec!(3000);
+
+/// Capital gains rate for higher-rate taxpayers (2024/25, from October 2024)
+/// TODO: Use 18% for gains within the basic rate band
+const CGT_RATE: Decimal = dec!(0.24);
+
+/// Inheritances above this nil-rate band pay inheritance tax
+/// TODO: Add the residence nil-rate band (175,000)
+const INHERITANCE_NIL_RATE_BAND: Decimal = dec!(325000);
+
+/// Inheritance tax rate above the nil-rate band
+const INHERITANCE_TAX_RATE: Decimal = dec!(0.40);
 
 /// UK market profile
 #[derive(Debug, Clone)]
@@ -40,18 +56,103 @@ impl MarketProfile for UkMarket {
 
     fn capital_gains_tax(
         &self,
-        _holding_period: Duration,
+        _kind: GainKind,
+        _holding_months: u32,
         _gain: Decimal,
     ) -> Result<Decimal, String> {
         // TODO: Implement UK capital gains tax (annual allowance, 10%/20% rates)
         Err("UK market not yet implemented".to_string())
     }
 
+    fn long_term_gains_tax_rate(&self) -> Decimal {
+        // No lower rate for long holdings
+        CGT_RATE
+    }
+
+    fn annual_capital_gains_tax(&self, gains: &[RealizedGain]) -> Result<Decimal, String> {
+        // Losses offset gains, then the annual exempt amount is deducted
+        let net: Decimal = gains.iter().map(|g| g.gain).sum();
+        Ok(((net - CGT_ANNUAL_EXEMPT_AMOUNT) * CGT_RATE).max(Decimal::ZERO))
+    }
+
+    fn windfall_tax(&self, kind: WindfallKind, amount: Decimal) -> Result<Decimal, String> {
+        match kind {
+            WindfallKind::Bonus => Err(BONUS_IS_PAY.to_string()),
+            WindfallKind::Inheritance => Ok(((amount - INHERITANCE_NIL_RATE_BAND)
+                * INHERITANCE_TAX_RATE)
+                .max(Decimal::ZERO)),
+            // Premium Bonds and lottery prizes are tax-free
+            WindfallKind::Lottery => Ok(Decimal::ZERO),
+        }
+    }
+
+    fn dividend_tax_rate(&self) -> Decimal {
+        // Basic rate dividend tax
+        // TODO: Apply the 500 dividend allowance and higher rates (33.75%, 39.35%)
+        dec!(0.0875)
+    }
+
+    fn calculate_self_employed_tax(
+        &self,
+        _gross_revenue: Decimal,
+        _regime: SelfEmployedRegime,
+    ) -> Result<TaxBreakdown, String> {
+        // TODO: Implement UK self-assessment (trading allowance, Class 2/4 NI)
+        Err("UK market not yet implemented".to_string())
+    }
+
+    fn inflation_rate(&self) -> Decimal {
+        // Bank of England targets 2% CPI
+        // TODO: Verify
+        dec!(0.025)
+    }
+
+    fn expected_equity_return(&self) -> Decimal {
+        // Global equity tracker held in GBP
+        // TODO: Verify
+        dec!(0.07)
+    }
+
+    fn equity_volatility(&self) -> Decimal {
+        // TODO: Verify
+        dec!(0.15)
+    }
+
+    fn expected_bond_return(&self) -> Decimal {
+        // Long-run return of gilts
+        // TODO: Verify
+        dec!(0.035)
+    }
+
+    fn base_interest_rate(&self) -> Decimal {
+        // Bank of England base rate
+        // TODO: Verify
+        dec!(0.05)
+    }
+
+    fn credit_card_rate(&self) -> Decimal {
+        // Typical representative APR
+        // TODO: Verify
+        dec!(0.22)
+    }
+
     fn retirement_age(&self) -> u8 {
         // UK state pension age
         66
     }
 
+    fn state_pension_estimate(&self, career: &Career) -> Decimal {
+        // New State Pension: full rate (221.20/week, 2024/25) needs 35 qualifying
+        // years, at least 10 years to get anything, pro-rata in between
+        // TODO: Verify - uprated every April
+        let years = career.years_experience.min(35);
+        if years < 10 {
+            return Decimal::ZERO;
+        }
+        let full_monthly = dec!(221.20) * dec!(52) / dec!(12);
+        (full_monthly * Decimal::from(years) / dec!(35)).round_dp(2)
+    }
+
     fn market_id(&self) -> &'static str {
         "uk"
     }
@@ -59,4 +160,30 @@ impl MarketProfile for UkMarket {
     fn market_name(&self) -> &'static str {
         "United Kingdom"
     }
+
+    fn generate_housing(&self) -> Vec<Housing> {
+        // TODO: Implement UK housing options
+        vec![]
+    }
+
+    fn security_deposit_months(&self) -> Decimal {
+        // Tenant Fees Act 2019 caps deposits at 5 weeks' rent
+        // TODO: Verify - approximated as 1.15 months
+        dec!(1.15)
+    }
+
+    fn moving_fee(&self) -> Decimal {
+        dec!(400)
+    }
+
+    fn essential_minimum(&self) -> Decimal {
+        // TODO: Verify
+        dec!(200)
+    }
+
+    fn minimum_wage(&self) -> Decimal {
+        // National Living Wage (2024/25) of £11.44 an hour at 37.5 hours a week
+        // TODO: Verify
+        dec!(1859)
+    }
 }
@@ -2,9 +2,27 @@
 //!
 //! TODO: Implement USA-specific financial rules
 
-use crate::market::{AccountType, Currency, MarketProfile, TaxBreakdown};
+use crate::core::{
+    AssetCategory, Career, GainKind, Housing, HousingType, LocationQuality, RealizedGain,
+    SelfEmployedRegime, WindfallKind,
+};
+use crate::market::{AccountType, Currency, MarketProfile, TaxBreakdown, BONUS_IS_PAY};
 use rust_decimal::Decimal;
-use std::time::Duration;
+use rust_decimal_macros::dec;
+
+/// Holdings owned at least this many months qualify for long-term rates
+const LONG_TERM_HOLDING_MONTHS: u32 = 12;
+
+/// Short-term gains are taxed as ordinary income
+/// TODO: Use the ordinary income brackets once income tax is implemented
+const SHORT_TERM_GAINS_RATE: Decimal = dec!(0.22);
+
+/// Long-term capital gains rate for most earners
+/// TODO: Implement the 0%/15%/20% brackets
+const LONG_TERM_GAINS_RATE: Decimal = dec!(0.15);
+
+/// Federal withholding on gambling and lottery winnings
+const LOTTERY_WITHHOLDING_RATE: Decimal = dec!(0.24);
 
 /// USA market profile
 #[derive(Debug, Clone)]
@@ -40,18 +58,116 @@ impl MarketProfile for UsaMarket {
 
     fn capital_gains_tax(
         &self,
-        _holding_period: Duration,
+        _kind: GainKind,
+        _holding_months: u32,
         _gain: Decimal,
     ) -> Result<Decimal, String> {
         // TODO: Implement USA capital gains tax (short-term vs long-term)
         Err("USA market not yet implemented".to_string())
     }
 
+    fn long_term_gains_tax_rate(&self) -> Decimal {
+        LONG_TERM_GAINS_RATE
+    }
+
+    fn annual_capital_gains_tax(&self, gains: &[RealizedGain]) -> Result<Decimal, String> {
+        let (long, short): (Vec<&RealizedGain>, Vec<&RealizedGain>) = gains
+            .iter()
+            .partition(|g| g.holding_months >= LONG_TERM_HOLDING_MONTHS);
+        let mut short_net: Decimal = short.iter().map(|g| g.gain).sum();
+        let mut long_net: Decimal = long.iter().map(|g| g.gain).sum();
+
+        // A net loss in one bucket offsets gains in the other
+        if short_net < Decimal::ZERO {
+            long_net += short_net;
+            short_net = Decimal::ZERO;
+        } else if long_net < Decimal::ZERO {
+            short_net += long_net;
+            long_net = Decimal::ZERO;
+        }
+
+        Ok((short_net.max(Decimal::ZERO) * SHORT_TERM_GAINS_RATE)
+            + (long_net.max(Decimal::ZERO) * LONG_TERM_GAINS_RATE))
+    }
+
+    fn windfall_tax(&self, kind: WindfallKind, amount: Decimal) -> Result<Decimal, String> {
+        match kind {
+            WindfallKind::Bonus => Err(BONUS_IS_PAY.to_string()),
+            // Federal estate tax only applies to very large estates
+            WindfallKind::Inheritance => Ok(Decimal::ZERO),
+            WindfallKind::Lottery => Ok(amount * LOTTERY_WITHHOLDING_RATE),
+        }
+    }
+
+    fn dividend_tax_rate(&self) -> Decimal {
+        // Qualified dividends share the long-term capital gains rate
+        LONG_TERM_GAINS_RATE
+    }
+
+    fn calculate_self_employed_tax(
+        &self,
+        _gross_revenue: Decimal,
+        _regime: SelfEmployedRegime,
+    ) -> Result<TaxBreakdown, String> {
+        // TODO: Implement USA self-employment tax (15.3% SE tax + income tax)
+        Err("USA market not yet implemented".to_string())
+    }
+
+    fn inflation_rate(&self) -> Decimal {
+        // Fed targets 2%; long-run CPI average is closer to 3%
+        // TODO: Verify
+        dec!(0.03)
+    }
+
+    fn expected_equity_return(&self) -> Decimal {
+        // Long-run S&P 500 nominal return is around 10%; assume a more cautious figure
+        // TODO: Verify
+        dec!(0.08)
+    }
+
+    fn equity_volatility(&self) -> Decimal {
+        // S&P 500 annual standard deviation
+        // TODO: Verify
+        dec!(0.16)
+    }
+
+    fn expected_bond_return(&self) -> Decimal {
+        // Long-run return of intermediate Treasuries
+        // TODO: Verify
+        dec!(0.04)
+    }
+
+    fn base_interest_rate(&self) -> Decimal {
+        // Federal funds rate
+        // TODO: Verify
+        dec!(0.05)
+    }
+
+    fn credit_card_rate(&self) -> Decimal {
+        // Average APR on cards that carry a balance
+        // TODO: Verify
+        dec!(0.21)
+    }
+
     fn retirement_age(&self) -> u8 {
         // USA full retirement age (for Social Security)
         67
     }
 
+    fn state_pension_estimate(&self, career: &Career) -> Decimal {
+        // Simplified Social Security PIA: 90% / 32% / 15% of average earnings
+        // across the bend points, scaled by years worked out of 35
+        // TODO: Verify bend points (2024) and use indexed earnings
+        let average = career.average_monthly_salary();
+        let first = average.min(dec!(1174));
+        let second = (average.min(dec!(7078)) - dec!(1174)).max(Decimal::ZERO);
+        let third = (average - dec!(7078)).max(Decimal::ZERO);
+        let full = first * dec!(0.90) + second * dec!(0.32) + third * dec!(0.15);
+
+        let years = Decimal::from(career.years_experience.min(35));
+        (full * years / dec!(35)).round_dp(2)
+    }
+
     fn market_id(&self) -> &'static str {
         "usa"
     }
@@ -59,4 +175,131 @@ impl MarketProfile for UsaMarket {
     fn market_name(&self) -> &'static str {
         "United States"
     }
+
+    fn generate_housing(&self) -> Vec<Housing> {
+        // TODO: Expand with more cities and price tiers
+        vec![
+            Housing {
+                id: "us_shared_avg_1".to_string(),
+                housing_type: HousingType::Shared,
+                location: LocationQuality::Average,
+                address: "Room in shared house, Columbus".to_string(),
+                monthly_cost: dec!(700),
+                monthly_utilities: dec!(150),
+            },
+            Housing {
+                id: "us_studio_avg_1".to_string(),
+                housing_type: HousingType::Studio,
+                location: LocationQuality::Average,
+                address: "Studio, Columbus".to_string(),
+                monthly_cost: dec!(1100),
+                monthly_utilities: dec!(200),
+            },
+            Housing {
+                id: "us_1bed_good_1".to_string(),
+                housing_type: HousingType::OneBedroom,
+                location: LocationQuality::Good,
+                address: "1 bedroom, Short North".to_string(),
+                monthly_cost: dec!(1600),
+                monthly_utilities: dec!(250),
+            },
+            Housing {
+                id: "us_house_prem_1".to_string(),
+                housing_type: HousingType::House,
+                location: LocationQuality::Premium,
+                address: "House, German Village".to_string(),
+                monthly_cost: dec!(3500),
+                monthly_utilities: dec!(450),
+            },
+        ]
+    }
+
+    fn gross_rental_yield(&self) -> Decimal {
+        // US homes rent for more relative to their price than Prague flats
+        // TODO: Verify
+        dec!(0.06)
+    }
+
+    fn liquidation_haircut(&self, category: &AssetCategory) -> Decimal {
+        match category {
+            // Agent commissions and closing costs
+            // TODO: Verify
+            AssetCategory::RealEstate => dec!(0.08),
+            _ => category.liquidation_haircut(),
+        }
+    }
+
+    fn security_deposit_months(&self) -> Decimal {
+        // Most US leases ask for one month of rent as deposit
+        dec!(1)
+    }
+
+    fn moving_fee(&self) -> Decimal {
+        dec!(800)
+    }
+
+    fn essential_minimum(&self) -> Decimal {
+        // TODO: Verify
+        dec!(300)
+    }
+
+    fn minimum_wage(&self) -> Decimal {
+        // Federal $7.25 an hour at 40 hours a week
+        // TODO: Verify - many states set a higher minimum
+        dec!(1257)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::GainKind;
+
+    #[test]
+    fn test_short_and_long_term_gains() {
+        let market = UsaMarket::new();
+        let sale = |gain, months: u32| {
+            RealizedGain::new(
+                "Index fund".to_string(),
+                GainKind::Securities,
+                dec!(10000),
+                gain,
+                months,
+                Decimal::ZERO,
+            )
+        };
+
+        let gains = vec![sale(dec!(1000), 3), sale(dec!(2000), 13)];
+        assert_eq!(market.annual_capital_gains_tax(&gains).unwrap(), dec!(520));
+
+        // A short-term loss reduces the long-term gain
+        let gains = vec![sale(dec!(-500), 3), sale(dec!(2000), 13)];
+        assert_eq!(market.annual_capital_gains_tax(&gains).unwrap(), dec!(225));
+    }
+
+    #[test]
+    fn test_generate_housing() {
+        let market = UsaMarket::new();
+        let options = market.generate_housing();
+        assert!(!options.is_empty());
+
+        // Prices are in USD, so far below the CZK figures
+        let max_cost = options
+            .iter()
+            .map(|h| h.total_monthly_cost())
+            .max()
+            .unwrap();
+        assert!(max_cost < dec!(5000));
+        assert!(options.iter().all(|h| h.id.starts_with("us_")));
+    }
+
+    #[test]
+    fn test_moving_cost() {
+        let market = UsaMarket::new();
+        let options = market.generate_housing();
+        let studio = options.iter().find(|h| h.id == "us_studio_avg_1").unwrap();
+
+        // 1 month deposit (1,100) + 800 moving fee
+        assert_eq!(studio.moving_cost(&market), dec!(1900));
+    }
 }
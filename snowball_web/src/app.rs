@@ -1,17 +1,10 @@
 use crate::app_state::{AppAction, AppState};
-use crate::components::{Initialization, InitializationData};
+use crate::components::{Initialization, InitializationData, NewGameControl};
+use crate::market::get_market_profile;
 use crate::screens::{ExecutionScreen, PlanningScreen, ReviewScreen};
-use fin_engine::{CzechMarket, GamePhase, GameState};
+use fin_engine::{GamePhase, GameState, GameStateBuilder};
 use yew::prelude::*;
 
-/// Gets the market profile for a given market ID
-fn get_market_profile(market_id: &str) -> Box<dyn fin_engine::market::MarketProfile> {
-    match market_id {
-        "czech" => Box::new(CzechMarket),
-        _ => Box::new(CzechMarket), // Default to Czech for now
-    }
-}
-
 #[function_component(App)]
 pub fn app() -> Html {
     let app_state = use_reducer(AppState::default);
@@ -26,38 +19,49 @@ pub fn app() -> Html {
             let current_year = js_sys::Date::new_0().get_full_year();
 
             // Create new game state
-            match GameState::new(
-                save_id,
-                data.market_id.clone(),
-                data.player_name,
-                data.player_age,
-                current_year,
-            ) {
-                Ok(mut game_state) => {
-                    // If a starting job was selected, accept it and setup initial finances
-                    if let Some(job) = data.starting_job {
-                        // Give starting cash (50% of monthly salary)
-                        game_state.finances.cash =
-                            job.monthly_salary / rust_decimal::Decimal::from(2);
+            let mut builder =
+                GameStateBuilder::new(save_id, data.player_name, data.player_age, current_year)
+                    .with_market(data.market_id.clone())
+                    .with_difficulty(data.difficulty);
+            // A starting job also brings starting cash and the minimum food budget
+            if let Some(job) = data.starting_job {
+                builder = builder.with_starting_job(job);
+            }
+            if let Some(gender) = data.gender {
+                builder = builder.with_gender(gender);
+            }
+            if let Some(savings) = data.starting_savings {
+                builder = builder.with_starting_cash(savings);
+            }
 
-                        // Set minimum food budget (3,500 Kč/month - survival level)
-                        game_state.finances.set_budget(
-                            fin_engine::ExpenseCategory::Essential,
-                            rust_decimal::Decimal::from(3500),
-                        );
+            match builder.build() {
+                Ok(mut game_state) => {
+                    game_state.tutorial_enabled = data.tutorial_enabled;
+                    game_state
+                        .player
+                        .graduate(data.education, &data.degree_field);
 
-                        // Accept the job
-                        game_state.career.accept_job(job.clone());
+                    if let Some(loan) = &data.student_loan {
+                        if let Err(e) = game_state.add_student_loan(
+                            loan.balance,
+                            loan.annual_rate,
+                            fin_engine::STUDENT_LOAN_TERM_MONTHS,
+                        ) {
+                            web_sys::console::error_1(
+                                &format!("Failed to add student loan: {}", e).into(),
+                            );
+                        }
+                    }
 
-                        // Create income entry for the job
-                        let income_id = format!("job_{}", job.id);
-                        game_state.finances.income_sources.push(fin_engine::Income {
-                            id: income_id,
-                            name: job.title.clone(),
-                            kind: fin_engine::IncomeKind::Employment,
-                            gross_monthly: job.monthly_salary,
-                            active: true,
-                        });
+                    if data.skipped_months > 0 {
+                        let market = get_market_profile(&game_state.market_id);
+                        if let Err(e) =
+                            game_state.fast_forward(data.skipped_months, market.as_ref())
+                        {
+                            web_sys::console::error_1(
+                                &format!("Failed to skip ahead: {}", e).into(),
+                            );
+                        }
                     }
 
                     app_state.dispatch(AppAction::StartGame(game_state));
@@ -76,8 +80,13 @@ pub fn app() -> Html {
         Callback::from(move |_| {
             if let AppState::Playing { game_state } = &*app_state {
                 let mut new_state = (**game_state).clone();
-                new_state.advance_phase();
-                app_state.dispatch(AppAction::UpdateGameState(new_state));
+                let market = get_market_profile(&new_state.market_id);
+                match new_state.advance_phase(market.as_ref()) {
+                    Ok(_) => app_state.dispatch(AppAction::UpdateGameState(new_state)),
+                    Err(e) => {
+                        web_sys::console::error_1(&format!("Error starting month: {}", e).into());
+                    }
+                }
             }
         })
     };
@@ -110,12 +119,26 @@ pub fn app() -> Html {
         Callback::from(move |_| {
             if let AppState::Playing { game_state } = &*app_state {
                 let mut new_state = (**game_state).clone();
-                new_state.advance_phase(); // Review -> Planning, advances month
-                app_state.dispatch(AppAction::UpdateGameState(new_state));
+                let market = get_market_profile(&new_state.market_id);
+                // Review -> Planning advances the month; in retirement this settles a month
+                match new_state.advance_phase(market.as_ref()) {
+                    Ok(_) => app_state.dispatch(AppAction::UpdateGameState(new_state)),
+                    Err(e) => {
+                        web_sys::console::error_1(&format!("Error advancing month: {}", e).into());
+                    }
+                }
             }
         })
     };
 
+    let on_new_game = {
+        let app_state = app_state.clone();
+        Callback::from(move |_| {
+            // Games are not persisted yet, so there is no save to clear
+            app_state.dispatch(AppAction::ResetToInitialization);
+        })
+    };
+
     let on_update_state = {
         let app_state = app_state.clone();
         Callback::from(move |new_state: GameState| {
@@ -129,32 +152,41 @@ pub fn app() -> Html {
                 <Initialization on_start={on_start_game} />
             }
         }
-        AppState::Playing { game_state } => match game_state.phase {
-            GamePhase::Planning => {
-                html! {
-                    <PlanningScreen
-                        game_state={(**game_state).clone()}
-                        on_start_month={on_start_month}
-                        on_update_state={on_update_state.clone()}
-                    />
+        AppState::Playing { game_state } => {
+            let screen = match game_state.phase {
+                GamePhase::Planning => {
+                    html! {
+                        <PlanningScreen
+                            game_state={game_state.clone()}
+                            on_start_month={on_start_month}
+                            on_update_state={on_update_state.clone()}
+                        />
+                    }
                 }
-            }
-            GamePhase::Execution { .. } => {
-                html! {
-                    <ExecutionScreen
-                        game_state={(**game_state).clone()}
-                        on_advance_day={on_advance_day}
-                    />
+                GamePhase::Execution { .. } => {
+                    html! {
+                        <ExecutionScreen
+                            game_state={game_state.clone()}
+                            on_advance_day={on_advance_day}
+                        />
+                    }
                 }
-            }
-            GamePhase::Review => {
-                html! {
-                    <ReviewScreen
-                        game_state={(**game_state).clone()}
-                        on_next_month={on_next_month}
-                    />
+                GamePhase::Review | GamePhase::Retirement | GamePhase::Bankrupt => {
+                    html! {
+                        <ReviewScreen
+                            game_state={game_state.clone()}
+                            on_next_month={on_next_month}
+                        />
+                    }
                 }
+            };
+
+            html! {
+                <>
+                    { screen }
+                    <NewGameControl on_confirm={on_new_game} />
+                </>
             }
-        },
+        }
     }
 }
@@ -14,7 +14,6 @@ pub enum AppState {
 
 /// Actions that can modify the app state
 #[derive(Debug, Clone)]
-#[allow(dead_code)]
 pub enum AppAction {
     /// Start a new game with the given state
     StartGame(GameState),
@@ -0,0 +1,69 @@
+use crate::market::get_market_profile;
+use fin_engine::GameState;
+use rust_decimal::Decimal;
+use std::rc::Rc;
+use yew::prelude::*;
+
+#[derive(Properties)]
+pub struct FireProgressProps {
+    pub game_state: Rc<GameState>,
+}
+
+impl PartialEq for FireProgressProps {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.game_state, &other.game_state)
+    }
+}
+
+/// Progress bar toward the FIRE number (25x annual expenses)
+/// Turns into a celebration once net worth reaches it.
+#[function_component(FireProgress)]
+pub fn fire_progress(props: &FireProgressProps) -> Html {
+    let finances = &props.game_state.finances;
+    let currency = get_market_profile(&props.game_state.market_id).currency();
+    let fire_number = finances.fire_number();
+    // Without expenses there is nothing to retire from yet
+    if fire_number <= Decimal::ZERO {
+        return html! {};
+    }
+    let progress = finances
+        .fire_progress()
+        .clamp(Decimal::ZERO, Decimal::from(100))
+        .round_dp(0);
+
+    if finances.is_fire() {
+        return html! {
+            <div class="bg-gradient-to-r from-yellow-100 to-green-100 border border-green-300 rounded-lg p-4">
+                <p class="text-lg font-bold text-green-700">{ "🎉 Financially Independent!" }</p>
+                <p class="text-sm text-gray-700">
+                    { format!(
+                        "Your net worth covers 25 years of expenses ({}). Work is optional now.",
+                        currency.format(fire_number)
+                    ) }
+                </p>
+            </div>
+        };
+    }
+
+    html! {
+        <div>
+            <div class="flex justify-between mb-2">
+                <span class="text-sm text-gray-600">
+                    { format!("FIRE Progress (goal {})", currency.format(fire_number)) }
+                </span>
+                <span class="text-sm font-semibold text-orange-600">
+                    { format!("{}%", progress) }
+                </span>
+            </div>
+            <div class="bg-gray-200 rounded-full h-3">
+                <div
+                    class="bg-orange-500 h-3 rounded-full transition-all"
+                    style={format!("width: {}%", progress)}
+                ></div>
+            </div>
+            <p class="text-xs text-gray-500 mt-1">
+                { "Financial independence at 25x your annual expenses" }
+            </p>
+        </div>
+    }
+}
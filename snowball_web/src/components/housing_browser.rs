@@ -1,4 +1,6 @@
-use fin_engine::{Housing, HousingMarket};
+use crate::market::get_market_profile;
+use fin_engine::{Housing, AFFORDABLE_HOUSING_SHARE};
+use rust_decimal::Decimal;
 use yew::prelude::*;
 
 #[derive(Properties, PartialEq)]
@@ -6,6 +8,12 @@ pub struct HousingBrowserProps {
     pub current_housing: Option<Housing>,
     pub market_id: String,
     pub current_cash: rust_decimal::Decimal,
+    /// Part of the current deposit returned on moving out
+    pub deposit_refund: rust_decimal::Decimal,
+    /// Monthly take-home pay, for the 30% rule
+    pub net_income: Decimal,
+    /// Share of take-home pay the current home costs (None without income)
+    pub housing_cost_ratio: Option<Decimal>,
     pub on_select_housing: Callback<Housing>,
     pub on_close: Callback<()>,
 }
@@ -13,11 +21,10 @@ pub struct HousingBrowserProps {
 #[function_component(HousingBrowser)]
 pub fn housing_browser(props: &HousingBrowserProps) -> Html {
     // Generate available housing options
-    let available_housing = if props.market_id == "czech" {
-        HousingMarket::generate_czech_housing()
-    } else {
-        Vec::new()
-    };
+    let market = get_market_profile(&props.market_id);
+    let available_housing = market.generate_housing();
+    let currency = market.currency();
+    let affordable_share = (AFFORDABLE_HOUSING_SHARE * Decimal::from(100)).round_dp(0);
 
     let on_close_click = {
         let on_close = props.on_close.clone();
@@ -64,14 +71,32 @@ pub fn housing_browser(props: &HousingBrowserProps) -> Html {
                                             { housing.location.name() }
                                         </p>
                                         <p class="text-xs text-gray-500">
-                                            { format!("Rent: {:.0} Kč • Utilities: {:.0} Kč",
-                                                housing.monthly_cost, housing.monthly_utilities) }
+                                            { format!("Rent: {} • Utilities: {}",
+                                                currency.format(housing.monthly_cost),
+                                                currency.format(housing.monthly_utilities)) }
                                         </p>
+                                        {if housing.roommate_contribution() > Decimal::ZERO {
+                                            html! {
+                                                <p class="text-xs text-gray-500">
+                                                    { format!("Roommates pay {} of the rent", currency.format(housing.roommate_contribution())) }
+                                                </p>
+                                            }
+                                        } else {
+                                            html! {}
+                                        }}
+                                        {if let Some(ratio) = props.housing_cost_ratio {
+                                            html! {
+                                                <p class={if ratio > AFFORDABLE_HOUSING_SHARE { "text-xs text-red-600" } else { "text-xs text-gray-500" }}>
+                                                    { format!("{}% of your take-home pay", (ratio * Decimal::from(100)).round_dp(0)) }
+                                                </p>
+                                            }
+                                        } else {
+                                            html! {}
+                                        }}
                                     </div>
                                     <div class="text-right">
                                         <p class="text-2xl font-bold text-green-600">
-                                            { format!("{:.0}", housing.total_monthly_cost()) }
-                                            { " Kč" }
+                                            { currency.format(housing.total_monthly_cost()) }
                                         </p>
                                         <p class="text-xs text-gray-500">{ "per month" }</p>
                                     </div>
@@ -106,8 +131,9 @@ pub fn housing_browser(props: &HousingBrowserProps) -> Html {
                                         .map(|h| h.id == housing.id)
                                         .unwrap_or(false);
 
-                                    let moving_cost = housing.moving_cost();
-                                    let can_afford = props.current_cash >= moving_cost;
+                                    let moving_cost = housing.moving_cost(market.as_ref());
+                                    let can_afford = props.current_cash + props.deposit_refund >= moving_cost;
+                                    let over_budget = !housing.is_affordable(props.net_income);
 
                                     let housing_clone = housing.clone();
                                     let on_select = {
@@ -140,26 +166,55 @@ pub fn housing_browser(props: &HousingBrowserProps) -> Html {
                                                         <span class="text-xs px-2 py-1 rounded bg-cyan-100 text-cyan-700">
                                                             { housing.location.name() }
                                                         </span>
+                                                        {if over_budget {
+                                                            html! {
+                                                                <span
+                                                                    class="text-xs px-2 py-1 rounded bg-red-100 text-red-700"
+                                                                    title={format!("Costs more than {}% of your take-home pay", affordable_share)}
+                                                                >
+                                                                    { "⚠️ Over budget" }
+                                                                </span>
+                                                            }
+                                                        } else {
+                                                            html! {}
+                                                        }}
                                                     </div>
                                                     <p class="text-sm text-gray-600 mb-2">
                                                         { &housing.address }
                                                     </p>
                                                     <div class="text-xs text-gray-500 space-y-1">
                                                         <p>
-                                                            { format!("Rent: {:.0} Kč/month", housing.monthly_cost) }
+                                                            { format!("Rent: {}/month", currency.format(housing.monthly_cost)) }
                                                         </p>
+                                                        {if housing.roommate_contribution() > Decimal::ZERO {
+                                                            html! {
+                                                                <p>
+                                                                    { format!("Roommates pay: {}/month", currency.format(housing.roommate_contribution())) }
+                                                                </p>
+                                                            }
+                                                        } else {
+                                                            html! {}
+                                                        }}
                                                         <p>
-                                                            { format!("Utilities: {:.0} Kč/month", housing.monthly_utilities) }
+                                                            { format!("Utilities: {}/month", currency.format(housing.monthly_utilities)) }
                                                         </p>
                                                         <p class="font-semibold text-orange-600">
-                                                            { format!("Moving cost: {:.0} Kč", moving_cost) }
+                                                            { format!("Moving cost: {}", currency.format(moving_cost)) }
                                                         </p>
+                                                        {if props.deposit_refund > Decimal::ZERO {
+                                                            html! {
+                                                                <p class="text-green-600">
+                                                                    { format!("Old deposit back: {}", currency.format(props.deposit_refund)) }
+                                                                </p>
+                                                            }
+                                                        } else {
+                                                            html! {}
+                                                        }}
                                                     </div>
                                                 </div>
                                                 <div class="text-right">
                                                     <p class="text-xl font-bold text-gray-800">
-                                                        { format!("{:.0}", housing.total_monthly_cost()) }
-                                                        { " Kč" }
+                                                        { currency.format(housing.total_monthly_cost()) }
                                                     </p>
                                                     <p class="text-xs text-gray-500">{ "per month" }</p>
                                                 </div>
@@ -180,7 +235,7 @@ pub fn housing_browser(props: &HousingBrowserProps) -> Html {
                                                         class="w-full bg-gray-300 text-gray-600 font-semibold py-2 px-4 rounded cursor-not-allowed"
                                                         disabled=true
                                                     >
-                                                        { format!("Cannot Afford (need {:.0} Kč)", moving_cost) }
+                                                        { format!("Cannot Afford (need {})", currency.format(moving_cost)) }
                                                     </button>
                                                 }
                                             } else {
@@ -208,7 +263,18 @@ pub fn housing_browser(props: &HousingBrowserProps) -> Html {
                 // Footer
                 <div class="bg-gray-50 p-4 border-t border-gray-200">
                     <p class="text-xs text-gray-600 text-center">
-                        { "Moving costs include security deposit (2 months rent) plus 1,500 Kč moving expenses" }
+                        { format!(
+                            "Moving costs include security deposit ({} months rent) plus {} moving expenses",
+                            market.security_deposit_months(),
+                            currency.format(market.moving_fee())
+                        ) }
+                    </p>
+                    <p class="text-xs text-gray-600 text-center mt-1">
+                        { format!(
+                            "Homes costing more than {}% of your take-home pay ({}) are marked over budget",
+                            affordable_share,
+                            currency.format((props.net_income * AFFORDABLE_HOUSING_SHARE).round_dp(0))
+                        ) }
                     </p>
                 </div>
             </div>
@@ -1,35 +1,13 @@
-use fin_engine::{Job, JobMarket};
+use crate::market::get_market_profile;
+use fin_engine::{
+    available_markets, market_profile, CareerField, Difficulty, EducationLevel, Gender, Job,
+    JobMarket, Loan, PlayerStats, STUDENT_LOAN_TERM_MONTHS,
+};
+use rust_decimal::Decimal;
 use yew::prelude::*;
 
-/// Market option for selection
-#[derive(Debug, Clone, PartialEq)]
-pub struct MarketOption {
-    pub id: &'static str,
-    pub name: &'static str,
-    pub currency_symbol: &'static str,
-    pub available: bool,
-}
-
-const MARKET_OPTIONS: &[MarketOption] = &[
-    MarketOption {
-        id: "czech",
-        name: "Czech Republic",
-        currency_symbol: "Kč",
-        available: true,
-    },
-    MarketOption {
-        id: "usa",
-        name: "United States",
-        currency_symbol: "$",
-        available: false,
-    },
-    MarketOption {
-        id: "uk",
-        name: "United Kingdom",
-        currency_symbol: "£",
-        available: false,
-    },
-];
+/// Longest head start that can be skipped when starting a game
+const MAX_SKIPPED_MONTHS: u32 = 120;
 
 #[derive(Properties, PartialEq)]
 pub struct InitializationProps {
@@ -40,31 +18,81 @@ pub struct InitializationProps {
 pub struct InitializationData {
     pub player_name: Option<String>,
     pub player_age: u8,
+    /// Optional, only used by markets whose retirement rules depend on it
+    pub gender: Option<Gender>,
     pub market_id: String,
     pub starting_job: Option<Job>,
+    pub difficulty: Difficulty,
+    pub student_loan: Option<StudentLoanData>,
+    /// Savings the player starts with (None uses the difficulty's share of the first salary)
+    pub starting_savings: Option<Decimal>,
+    pub education: EducationLevel,
+    /// Field of study (ignored without a degree)
+    pub degree_field: CareerField,
+    pub tutorial_enabled: bool,
+    /// Months played automatically before the player takes over
+    pub skipped_months: u32,
+}
+
+/// Student debt the player starts the game with
+#[derive(Debug, Clone, PartialEq)]
+pub struct StudentLoanData {
+    pub balance: Decimal,
+    /// Annual interest rate (0.05 = 5%)
+    pub annual_rate: Decimal,
 }
 
 #[function_component(Initialization)]
 pub fn initialization(props: &InitializationProps) -> Html {
     let player_name = use_state(String::new);
     let player_age = use_state(|| 25u8);
+    let selected_gender = use_state(|| Option::<Gender>::None);
     let selected_market = use_state(|| "czech".to_string());
     let selected_job = use_state(|| Option::<Job>::None);
+    let selected_difficulty = use_state(Difficulty::default);
+    let selected_education = use_state(EducationLevel::default);
+    let selected_degree_field = use_state(|| CareerField::Technology);
+    let has_student_loan = use_state(|| false);
+    let tutorial_enabled = use_state(|| true);
+    let student_loan_balance = use_state(String::new);
+    let student_loan_rate = use_state(|| "5".to_string());
+    let starting_savings = use_state(String::new);
+    let skipped_months = use_state(String::new);
     let validation_error = use_state(|| Option::<String>::None);
 
     // Generate entry-level jobs for the selected market
-    let available_jobs = use_memo((*selected_market).clone(), |market_id| {
-        // Create a dummy career with 0 experience to get entry-level jobs
-        let dummy_career = fin_engine::Career::new();
-        if market_id == "czech" {
-            JobMarket::generate_czech_jobs(&dummy_career)
-                .into_iter()
-                .filter(|job| job.required_experience == 0)
-                .collect::<Vec<Job>>()
-        } else {
-            Vec::new()
-        }
-    });
+    // (a degree unlocks junior roles in its field)
+    let available_jobs = use_memo(
+        (
+            (*selected_market).clone(),
+            *selected_education,
+            (*selected_degree_field).clone(),
+        ),
+        |(market_id, education, field)| {
+            let mut graduate = PlayerStats::new(18, None, None);
+            graduate.graduate(*education, field);
+            let market = get_market_profile(market_id);
+            JobMarket::enforce_minimum_wage(
+                JobMarket::generate_starting_jobs(
+                    market.as_ref(),
+                    &graduate.skills,
+                    &fin_engine::EconomyState::default(),
+                ),
+                market.as_ref(),
+            )
+        },
+    );
+
+    let currency = get_market_profile(&selected_market).currency();
+    // Markets without a working tax calculation only show gross pay
+    let selected_profile = market_profile(&selected_market);
+    let estimate_net = |gross: Decimal| -> Option<Decimal> {
+        let taxes = selected_profile
+            .as_ref()?
+            .calculate_monthly_payroll_tax(gross)
+            .ok()?;
+        Some(gross - taxes.total)
+    };
 
     let on_name_change = {
         let player_name = player_name.clone();
@@ -100,6 +128,81 @@ pub fn initialization(props: &InitializationProps) -> Html {
         })
     };
 
+    let on_gender_select = {
+        let selected_gender = selected_gender.clone();
+        Callback::from(move |gender: Option<Gender>| {
+            selected_gender.set(gender);
+        })
+    };
+
+    let on_difficulty_select = {
+        let selected_difficulty = selected_difficulty.clone();
+        Callback::from(move |difficulty: Difficulty| {
+            selected_difficulty.set(difficulty);
+        })
+    };
+
+    let on_education_select = {
+        let selected_education = selected_education.clone();
+        let selected_job = selected_job.clone();
+        Callback::from(move |education: EducationLevel| {
+            selected_education.set(education);
+            // The offered jobs change with education
+            selected_job.set(None);
+        })
+    };
+
+    let on_degree_field_select = {
+        let selected_degree_field = selected_degree_field.clone();
+        let selected_job = selected_job.clone();
+        Callback::from(move |field: CareerField| {
+            selected_degree_field.set(field);
+            selected_job.set(None);
+        })
+    };
+
+    let on_tutorial_toggle = {
+        let tutorial_enabled = tutorial_enabled.clone();
+        Callback::from(move |_| tutorial_enabled.set(!*tutorial_enabled))
+    };
+
+    let on_student_loan_toggle = {
+        let has_student_loan = has_student_loan.clone();
+        Callback::from(move |_| has_student_loan.set(!*has_student_loan))
+    };
+
+    let on_student_loan_balance = {
+        let student_loan_balance = student_loan_balance.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            student_loan_balance.set(input.value());
+        })
+    };
+
+    let on_student_loan_rate = {
+        let student_loan_rate = student_loan_rate.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            student_loan_rate.set(input.value());
+        })
+    };
+
+    let on_skipped_months = {
+        let skipped_months = skipped_months.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            skipped_months.set(input.value());
+        })
+    };
+
+    let on_starting_savings = {
+        let starting_savings = starting_savings.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            starting_savings.set(input.value());
+        })
+    };
+
     let on_job_select = {
         let selected_job = selected_job.clone();
         Callback::from(move |job: Job| {
@@ -110,8 +213,18 @@ pub fn initialization(props: &InitializationProps) -> Html {
     let on_submit = {
         let player_name = player_name.clone();
         let player_age = player_age.clone();
+        let selected_gender = selected_gender.clone();
         let selected_market = selected_market.clone();
         let selected_job = selected_job.clone();
+        let selected_difficulty = selected_difficulty.clone();
+        let selected_education = selected_education.clone();
+        let selected_degree_field = selected_degree_field.clone();
+        let has_student_loan = has_student_loan.clone();
+        let student_loan_balance = student_loan_balance.clone();
+        let student_loan_rate = student_loan_rate.clone();
+        let starting_savings = starting_savings.clone();
+        let skipped_months = skipped_months.clone();
+        let tutorial_enabled = tutorial_enabled.clone();
         let validation_error = validation_error.clone();
         let on_start = props.on_start.clone();
 
@@ -130,11 +243,68 @@ pub fn initialization(props: &InitializationProps) -> Html {
                 Some(player_name.trim().to_string())
             };
 
+            let student_loan = if *has_student_loan {
+                let balance = student_loan_balance.trim().parse::<Decimal>();
+                let rate = student_loan_rate.trim().parse::<Decimal>();
+                match (balance, rate) {
+                    (Ok(balance), Ok(rate)) if balance > Decimal::ZERO && rate >= Decimal::ZERO => {
+                        Some(StudentLoanData {
+                            balance,
+                            annual_rate: rate / Decimal::from(100),
+                        })
+                    }
+                    _ => {
+                        validation_error.set(Some(
+                            "Enter a positive student loan balance and interest rate".to_string(),
+                        ));
+                        return;
+                    }
+                }
+            } else {
+                None
+            };
+
+            let starting_savings = if starting_savings.trim().is_empty() {
+                None
+            } else {
+                match starting_savings.trim().parse::<Decimal>() {
+                    Ok(savings) if savings >= Decimal::ZERO => Some(savings),
+                    _ => {
+                        validation_error
+                            .set(Some("Starting savings can't be negative".to_string()));
+                        return;
+                    }
+                }
+            };
+
+            let skipped_months = if skipped_months.trim().is_empty() {
+                0
+            } else {
+                match skipped_months.trim().parse::<u32>() {
+                    Ok(months) if months <= MAX_SKIPPED_MONTHS => months,
+                    _ => {
+                        validation_error.set(Some(format!(
+                            "Skip between 0 and {} months",
+                            MAX_SKIPPED_MONTHS
+                        )));
+                        return;
+                    }
+                }
+            };
+
             let data = InitializationData {
                 player_name: name,
                 player_age: age,
+                gender: *selected_gender,
                 market_id: (*selected_market).clone(),
                 starting_job: (*selected_job).clone(),
+                difficulty: *selected_difficulty,
+                student_loan,
+                starting_savings,
+                education: *selected_education,
+                degree_field: (*selected_degree_field).clone(),
+                tutorial_enabled: *tutorial_enabled,
+                skipped_months,
             };
 
             on_start.emit(data);
@@ -193,13 +363,49 @@ pub fn initialization(props: &InitializationProps) -> Html {
                         </p>
                     </div>
 
+                    // Gender
+                    <div class="mb-6">
+                        <label class="block text-gray-700 text-sm font-semibold mb-3">
+                            { "Gender (optional)" }
+                        </label>
+                        <div class="grid grid-cols-3 gap-3">
+                            {std::iter::once(None).chain(Gender::all().into_iter().map(Some)).map(|gender| {
+                                let is_selected = *selected_gender == gender;
+                                let label = gender.map_or("Prefer not to say", |gender| gender.name());
+                                let on_click = {
+                                    let on_gender_select = on_gender_select.clone();
+                                    Callback::from(move |_| on_gender_select.emit(gender))
+                                };
+                                html! {
+                                    <div
+                                        key={label}
+                                        class={format!(
+                                            "p-3 rounded-lg border-2 cursor-pointer transition-all text-center {}",
+                                            if is_selected {
+                                                "border-blue-500 bg-blue-50"
+                                            } else {
+                                                "border-gray-200 hover:border-blue-300"
+                                            }
+                                        )}
+                                        onclick={on_click}
+                                    >
+                                        <div class="font-semibold text-gray-800">{ label }</div>
+                                    </div>
+                                }
+                            }).collect::<Html>()}
+                        </div>
+                        <p class="text-gray-500 text-xs mt-1">
+                            { "Some markets set the retirement age by gender" }
+                        </p>
+                    </div>
+
                     // Market Selection
                     <div class="mb-6">
                         <label class="block text-gray-700 text-sm font-semibold mb-3">
                             { "Select Financial Market" }
                         </label>
                         <div class="space-y-3">
-                            {MARKET_OPTIONS.iter().map(|market| {
+                            {available_markets().into_iter().map(|market| {
                                 let is_selected = *selected_market == market.id;
                                 let market_id = market.id.to_string();
                                 let on_click = {
@@ -210,21 +416,17 @@ pub fn initialization(props: &InitializationProps) -> Html {
                                     })
                                 };
 
-                                let card_class = if market.available {
-                                    if is_selected {
-                                        "border-2 border-blue-500 bg-blue-50 cursor-pointer"
-                                    } else {
-                                        "border-2 border-gray-200 hover:border-blue-300 cursor-pointer"
-                                    }
+                                let card_class = if is_selected {
+                                    "border-2 border-blue-500 bg-blue-50 cursor-pointer"
                                 } else {
-                                    "border-2 border-gray-200 bg-gray-50 opacity-50 cursor-not-allowed"
+                                    "border-2 border-gray-200 hover:border-blue-300 cursor-pointer"
                                 };
 
                                 html! {
                                     <div
                                         key={market.id}
                                         class={format!("p-4 rounded-lg transition-all {}", card_class)}
-                                        onclick={if market.available { on_click } else { Callback::from(|_| {}) }}
+                                        onclick={on_click}
                                     >
                                         <div class="flex items-center justify-between">
                                             <div class="flex items-center space-x-3">
@@ -241,15 +443,10 @@ pub fn initialization(props: &InitializationProps) -> Html {
                                                 <div>
                                                     <div class="font-semibold text-gray-800">
                                                         { market.name }
-                                                        {if !market.available {
-                                                            html! { <span class="text-xs text-gray-500 ml-2">{ "(Coming Soon)" }</span> }
-                                                        } else {
-                                                            html! {}
-                                                        }}
                                                     </div>
                                                     <div class="text-sm text-gray-600">
                                                         { "Currency: " }
-                                                        { market.currency_symbol }
+                                                        { market.symbol }
                                                     </div>
                                                 </div>
                                             </div>
@@ -260,6 +457,227 @@ pub fn initialization(props: &InitializationProps) -> Html {
                         </div>
                     </div>
 
+                    // Difficulty Selection
+                    <div class="mb-6">
+                        <label class="block text-gray-700 text-sm font-semibold mb-3">
+                            { "Difficulty" }
+                        </label>
+                        <div class="grid grid-cols-3 gap-3">
+                            {Difficulty::all().into_iter().map(|difficulty| {
+                                let is_selected = *selected_difficulty == difficulty;
+                                let on_click = {
+                                    let on_difficulty_select = on_difficulty_select.clone();
+                                    Callback::from(move |_| on_difficulty_select.emit(difficulty))
+                                };
+                                html! {
+                                    <div
+                                        key={difficulty.name()}
+                                        class={format!(
+                                            "p-3 rounded-lg border-2 cursor-pointer transition-all {}",
+                                            if is_selected {
+                                                "border-blue-500 bg-blue-50"
+                                            } else {
+                                                "border-gray-200 hover:border-blue-300"
+                                            }
+                                        )}
+                                        onclick={on_click}
+                                    >
+                                        <div class="font-semibold text-gray-800">{ difficulty.name() }</div>
+                                        <div class="text-xs text-gray-600">{ difficulty.description() }</div>
+                                    </div>
+                                }
+                            }).collect::<Html>()}
+                        </div>
+                    </div>
+
+                    // Education
+                    <div class="mb-6">
+                        <label class="block text-gray-700 text-sm font-semibold mb-3">
+                            { "Education" }
+                        </label>
+                        <div class="grid grid-cols-3 gap-3">
+                            {EducationLevel::all().into_iter().map(|education| {
+                                let is_selected = *selected_education == education;
+                                let on_click = {
+                                    let on_education_select = on_education_select.clone();
+                                    Callback::from(move |_| on_education_select.emit(education))
+                                };
+                                html! {
+                                    <div
+                                        key={education.name()}
+                                        class={format!(
+                                            "p-3 rounded-lg border-2 cursor-pointer transition-all {}",
+                                            if is_selected {
+                                                "border-blue-500 bg-blue-50"
+                                            } else {
+                                                "border-gray-200 hover:border-blue-300"
+                                            }
+                                        )}
+                                        onclick={on_click}
+                                    >
+                                        <div class="font-semibold text-gray-800">{ education.name() }</div>
+                                    </div>
+                                }
+                            }).collect::<Html>()}
+                        </div>
+                        {if selected_education.has_field() {
+                            html! {
+                                <div class="flex flex-wrap gap-2 mt-3">
+                                    <span class="text-gray-600 text-xs self-center">{ "Field of study:" }</span>
+                                    {CareerField::available_fields().into_iter().map(|field| {
+                                        let is_selected = *selected_degree_field == field;
+                                        let name = field.name();
+                                        let on_click = {
+                                            let on_degree_field_select = on_degree_field_select.clone();
+                                            Callback::from(move |_| on_degree_field_select.emit(field.clone()))
+                                        };
+                                        html! {
+                                            <button
+                                                type="button"
+                                                key={name.clone()}
+                                                class={format!(
+                                                    "text-xs py-1 px-3 rounded-full border transition {}",
+                                                    if is_selected {
+                                                        "border-blue-500 bg-blue-500 text-white"
+                                                    } else {
+                                                        "border-gray-300 text-gray-700 hover:border-blue-300"
+                                                    }
+                                                )}
+                                                onclick={on_click}
+                                            >
+                                                { name }
+                                            </button>
+                                        }
+                                    }).collect::<Html>()}
+                                </div>
+                            }
+                        } else {
+                            html! {}
+                        }}
+                    </div>
+
+                    // Guided Mode
+                    <div class="mb-6">
+                        <label class="flex items-center gap-2 text-gray-700 text-sm font-semibold">
+                            <input
+                                type="checkbox"
+                                checked={*tutorial_enabled}
+                                onclick={on_tutorial_toggle}
+                            />
+                            { "Show tutorial hints" }
+                        </label>
+                    </div>
+
+                    // Skip Ahead
+                    <div class="mb-6">
+                        <label class="block text-gray-700 text-sm font-semibold mb-2">
+                            { "Skip ahead (months, optional)" }
+                        </label>
+                        <input
+                            type="number"
+                            min="0"
+                            max={MAX_SKIPPED_MONTHS.to_string()}
+                            class="w-full px-3 py-2 border border-gray-300 rounded-lg"
+                            placeholder="0"
+                            value={(*skipped_months).clone()}
+                            oninput={on_skipped_months}
+                        />
+                        <p class="text-gray-500 text-xs mt-1">
+                            { "Plays these months on your starting setup before you take over." }
+                        </p>
+                    </div>
+
+                    // Starting Savings
+                    <div class="mb-6">
+                        <label class="block text-gray-700 text-sm font-semibold mb-2">
+                            { format!("Starting savings ({}, optional)", currency.symbol()) }
+                        </label>
+                        <input
+                            type="number"
+                            min="0"
+                            class="w-full px-3 py-2 border border-gray-300 rounded-lg"
+                            placeholder={match &*selected_job {
+                                Some(job) => format!(
+                                    "Default: {}",
+                                    currency.format(selected_difficulty.starting_cash(job.monthly_salary))
+                                ),
+                                None => "Default: nothing".to_string(),
+                            }}
+                            value={(*starting_savings).clone()}
+                            oninput={on_starting_savings}
+                        />
+                        <p class="text-gray-500 text-xs mt-1">
+                            { "Leave empty to start with the difficulty's share of your first salary." }
+                        </p>
+                    </div>
+
+                    // Student Loan
+                    <div class="mb-6">
+                        <label class="flex items-center gap-2 text-gray-700 text-sm font-semibold">
+                            <input
+                                type="checkbox"
+                                checked={*has_student_loan}
+                                onclick={on_student_loan_toggle}
+                            />
+                            { "I have student loans" }
+                        </label>
+                        {if *has_student_loan {
+                            let payment = match (
+                                student_loan_balance.trim().parse::<Decimal>(),
+                                student_loan_rate.trim().parse::<Decimal>(),
+                            ) {
+                                (Ok(balance), Ok(rate)) if balance > Decimal::ZERO => {
+                                    Loan::annuity_payment(
+                                        balance,
+                                        rate / Decimal::from(100),
+                                        STUDENT_LOAN_TERM_MONTHS,
+                                    )
+                                    .ok()
+                                }
+                                _ => None,
+                            };
+                            html! {
+                                <div class="grid grid-cols-2 gap-3 mt-3">
+                                    <div>
+                                        <label class="block text-gray-600 text-xs mb-1">
+                                            { format!("Balance ({})", currency.symbol()) }
+                                        </label>
+                                        <input
+                                            type="number"
+                                            min="0"
+                                            class="w-full px-3 py-2 border border-gray-300 rounded-lg"
+                                            value={(*student_loan_balance).clone()}
+                                            oninput={on_student_loan_balance}
+                                        />
+                                    </div>
+                                    <div>
+                                        <label class="block text-gray-600 text-xs mb-1">{ "Interest rate (%)" }</label>
+                                        <input
+                                            type="number"
+                                            min="0"
+                                            step="0.1"
+                                            class="w-full px-3 py-2 border border-gray-300 rounded-lg"
+                                            value={(*student_loan_rate).clone()}
+                                            oninput={on_student_loan_rate}
+                                        />
+                                    </div>
+                                    <p class="col-span-2 text-gray-500 text-xs">
+                                        {match payment {
+                                            Some(payment) => format!(
+                                                "Repaid over {} years: {} per month",
+                                                STUDENT_LOAN_TERM_MONTHS / 12,
+                                                currency.format(payment)
+                                            ),
+                                            None => "Enter your loan balance".to_string(),
+                                        }}
+                                    </p>
+                                </div>
+                            }
+                        } else {
+                            html! {}
+                        }}
+                    </div>
+
                     // Starting Job Selection
                     <div class="mb-6">
                         <label class="block text-gray-700 text-sm font-semibold mb-3">
@@ -325,11 +743,20 @@ pub fn initialization(props: &InitializationProps) -> Html {
                                                     </div>
                                                     <div class="text-right ml-2">
                                                         <div class="text-sm font-bold text-gray-800">
-                                                            { format!("{:.0} Kč", job.monthly_salary) }
+                                                            { currency.format(job.monthly_salary) }
                                                         </div>
                                                         <div class="text-xs text-gray-500">
-                                                            { "per month" }
+                                                            { "gross per month" }
                                                         </div>
+                                                        {if let Some(net) = estimate_net(job.monthly_salary) {
+                                                            html! {
+                                                                <div class="text-xs font-semibold text-green-700">
+                                                                    { format!("≈ {} take-home", currency.format(net.round_dp(0))) }
+                                                                </div>
+                                                            }
+                                                        } else {
+                                                            html! {}
+                                                        }}
                                                     </div>
                                                 </div>
                                             </div>
@@ -1,10 +1,50 @@
-use fin_engine::{Career, Job, JobMarket};
+use crate::market::get_market_profile;
+use fin_engine::market::MarketProfile;
+use fin_engine::{
+    Career, CareerField, EconomyPhase, EconomyState, Job, JobMarket, PART_TIME_HOURS,
+};
+use rust_decimal::Decimal;
+use std::collections::HashMap;
 use yew::prelude::*;
 
+/// Maximum number of jobs that can be pinned for side-by-side comparison
+const MAX_COMPARED_JOBS: usize = 3;
+
+/// Estimates monthly take-home pay for a job after market taxes and insurance
+/// Returns None if the market cannot calculate taxes yet
+pub fn estimated_net_salary(job: &Job, market: &dyn MarketProfile) -> Option<Decimal> {
+    market
+        .calculate_monthly_payroll_tax(job.monthly_salary)
+        .ok()
+        .map(|tax| job.monthly_salary - tax.total)
+}
+
+/// Describes time in the current job, in years once past the first
+pub fn tenure_label(career: &Career) -> String {
+    let plural = |count: u32, unit: &str| {
+        if count == 1 {
+            format!("1 {}", unit)
+        } else {
+            format!("{} {}s", count, unit)
+        }
+    };
+    match career.tenure() {
+        (0, months) => plural(months, "month"),
+        (years, 0) => plural(years, "year"),
+        (years, months) => format!("{} {}", plural(years, "year"), plural(months, "month")),
+    }
+}
+
 #[derive(Properties, PartialEq)]
 pub struct JobBrowserProps {
     pub career: Career,
+    /// Player skill per career field
+    pub skills: HashMap<CareerField, u8>,
+    pub economy: EconomyState,
     pub market_id: String,
+    /// Outcome of the last job application (e.g. a rejection notice)
+    #[prop_or_default]
+    pub application_message: Option<String>,
     pub on_accept_job: Callback<Job>,
     pub on_close: Callback<()>,
 }
@@ -12,12 +52,41 @@ pub struct JobBrowserProps {
 #[function_component(JobBrowser)]
 pub fn job_browser(props: &JobBrowserProps) -> Html {
     let career = &props.career;
+    let skills = &props.skills;
+    let market = get_market_profile(&props.market_id);
+    let currency = market.currency();
 
     // Generate available jobs based on career
-    let available_jobs = if props.market_id == "czech" {
-        JobMarket::generate_czech_jobs(career)
-    } else {
-        Vec::new()
+    let available_jobs = JobMarket::enforce_minimum_wage(
+        JobMarket::generate_jobs(market.as_ref(), career, skills, &props.economy),
+        market.as_ref(),
+    );
+
+    // Jobs pinned for side-by-side comparison (by job ID)
+    let compared_ids = use_state(Vec::<String>::new);
+    let compared_jobs: Vec<Job> = compared_ids
+        .iter()
+        .filter_map(|id| available_jobs.iter().find(|j| &j.id == id).cloned())
+        .collect();
+
+    let on_toggle_compare = {
+        let compared_ids = compared_ids.clone();
+        Callback::from(move |job_id: String| {
+            let mut ids = (*compared_ids).clone();
+            if let Some(pos) = ids.iter().position(|id| *id == job_id) {
+                ids.remove(pos);
+            } else if ids.len() < MAX_COMPARED_JOBS {
+                ids.push(job_id);
+            }
+            compared_ids.set(ids);
+        })
+    };
+
+    let on_clear_compare = {
+        let compared_ids = compared_ids.clone();
+        Callback::from(move |_| {
+            compared_ids.set(Vec::new());
+        })
     };
 
     let on_close_click = {
@@ -27,6 +96,49 @@ pub fn job_browser(props: &JobBrowserProps) -> Html {
         })
     };
 
+    // Application success chance as a whole percentage
+    let chance_percent = |job: &Job| -> Decimal {
+        (JobMarket::application_chance(career, skills, job, &props.economy) * Decimal::from(100))
+            .round()
+    };
+    let is_rejected = |job: &Job| career.rejected_applications.contains(&job.id);
+
+    // Renders the action button for a job in the comparison table
+    let compare_action = |job: &Job| -> Html {
+        let is_current = career
+            .current_job
+            .as_ref()
+            .map(|j| j.id == job.id)
+            .unwrap_or(false);
+        if is_current {
+            html! { <span class="text-xs text-green-700 font-semibold">{ "Current Job" }</span> }
+        } else if is_rejected(job) {
+            html! { <span class="text-xs text-red-600">{ "Rejected This Month" }</span> }
+        } else if !career.qualifies_for(job, skills) {
+            let job_clone = job.clone();
+            let on_accept_job = props.on_accept_job.clone();
+            html! {
+                <button
+                    onclick={Callback::from(move |_| on_accept_job.emit(job_clone.clone()))}
+                    class="bg-amber-500 text-white text-xs font-semibold py-1 px-3 rounded hover:bg-amber-600 transition"
+                >
+                    { format!("Apply ({}% chance)", chance_percent(job)) }
+                </button>
+            }
+        } else {
+            let job_clone = job.clone();
+            let on_accept_job = props.on_accept_job.clone();
+            html! {
+                <button
+                    onclick={Callback::from(move |_| on_accept_job.emit(job_clone.clone()))}
+                    class="bg-gradient-to-r from-indigo-500 to-purple-600 text-white text-xs font-semibold py-1 px-3 rounded hover:from-indigo-600 hover:to-purple-700 transition"
+                >
+                    {if career.is_employed() { "Switch to This Job" } else { "Accept Job Offer" }}
+                </button>
+            }
+        }
+    };
+
     html! {
         <div class="fixed inset-0 bg-black bg-opacity-50 flex items-center justify-center p-4 z-50">
             <div class="bg-white rounded-lg shadow-2xl max-w-4xl w-full max-h-[90vh] overflow-hidden">
@@ -38,7 +150,14 @@ pub fn job_browser(props: &JobBrowserProps) -> Html {
                             <p class="text-purple-100 text-sm">
                                 { format!("Experience: {} years", career.years_experience) }
                                 { " • Qualified for: " }
-                                { career.max_qualified_level().name() }
+                                { career.max_qualified_level(skills).name() }
+                                { " • Economy: " }
+                                { props.economy.phase.name() }
+                                {if props.economy.phase == EconomyPhase::Recession {
+                                    " (few openings)"
+                                } else {
+                                    ""
+                                }}
                             </p>
                         </div>
                         <button
@@ -50,6 +169,16 @@ pub fn job_browser(props: &JobBrowserProps) -> Html {
                     </div>
                 </div>
 
+                {if let Some(message) = &props.application_message {
+                    html! {
+                        <div class="px-6 py-3 bg-amber-50 border-b border-amber-200 text-sm text-amber-800">
+                            { message }
+                        </div>
+                    }
+                } else {
+                    html! {}
+                }}
+
                 // Current Job Section
                 <div class="p-6 border-b border-gray-200">
                     <h3 class="text-lg font-semibold text-gray-800 mb-3">{ "Current Employment" }</h3>
@@ -68,15 +197,28 @@ pub fn job_browser(props: &JobBrowserProps) -> Html {
                                             { job.field.name() }
                                         </p>
                                         <p class="text-xs text-gray-500">
-                                            { format!("{} months at this position", career.months_in_current_job) }
+                                            { format!("{} at this position", tenure_label(career)) }
+                                            {if job.is_part_time() {
+                                                format!(" • Part-time ({}% hours)", (job.hours_fraction * Decimal::from(100)).normalize())
+                                            } else {
+                                                String::new()
+                                            }}
                                         </p>
                                     </div>
                                     <div class="text-right">
                                         <p class="text-2xl font-bold text-green-600">
-                                            { format!("{:.0}", job.monthly_salary) }
-                                            { " Kč" }
+                                            { currency.format(job.monthly_salary) }
                                         </p>
-                                        <p class="text-xs text-gray-500">{ "per month" }</p>
+                                        <p class="text-xs text-gray-500">{ "gross per month" }</p>
+                                        {if let Some(net) = estimated_net_salary(job, market.as_ref()) {
+                                            html! {
+                                                <p class="text-sm font-semibold text-gray-700 mt-1">
+                                                    { format!("~{} net", currency.format(net)) }
+                                                </p>
+                                            }
+                                        } else {
+                                            html! {}
+                                        }}
                                     </div>
                                 </div>
                             </div>
@@ -91,6 +233,72 @@ pub fn job_browser(props: &JobBrowserProps) -> Html {
                     }}
                 </div>
 
+                // Job Comparison Table
+                {if compared_jobs.is_empty() {
+                    html! {}
+                } else {
+                    html! {
+                        <div class="p-6 border-b border-gray-200 bg-indigo-50 overflow-x-auto">
+                            <div class="flex justify-between items-center mb-3">
+                                <h3 class="text-lg font-semibold text-gray-800">
+                                    { format!("Compare Jobs ({}/{})", compared_jobs.len(), MAX_COMPARED_JOBS) }
+                                </h3>
+                                <button
+                                    onclick={on_clear_compare}
+                                    class="text-sm text-indigo-600 hover:text-indigo-800"
+                                >
+                                    { "Clear" }
+                                </button>
+                            </div>
+                            <table class="w-full text-sm">
+                                <tbody>
+                                    <tr class="border-b border-indigo-100">
+                                        <td class="py-2 pr-4 text-gray-500">{ "Title" }</td>
+                                        {compared_jobs.iter().map(|job| html! {
+                                            <td class="py-2 pr-4 font-semibold text-gray-800">{ &job.title }</td>
+                                        }).collect::<Html>()}
+                                    </tr>
+                                    <tr class="border-b border-indigo-100">
+                                        <td class="py-2 pr-4 text-gray-500">{ "Level" }</td>
+                                        {compared_jobs.iter().map(|job| html! {
+                                            <td class="py-2 pr-4 text-gray-700">{ job.level_name() }</td>
+                                        }).collect::<Html>()}
+                                    </tr>
+                                    <tr class="border-b border-indigo-100">
+                                        <td class="py-2 pr-4 text-gray-500">{ "Gross / month" }</td>
+                                        {compared_jobs.iter().map(|job| html! {
+                                            <td class="py-2 pr-4 text-gray-700">{ currency.format(job.monthly_salary) }</td>
+                                        }).collect::<Html>()}
+                                    </tr>
+                                    <tr class="border-b border-indigo-100">
+                                        <td class="py-2 pr-4 text-gray-500">{ "Est. net / month" }</td>
+                                        {compared_jobs.iter().map(|job| html! {
+                                            <td class="py-2 pr-4 font-semibold text-green-700">
+                                                {match estimated_net_salary(job, market.as_ref()) {
+                                                    Some(net) => currency.format(net),
+                                                    None => "—".to_string(),
+                                                }}
+                                            </td>
+                                        }).collect::<Html>()}
+                                    </tr>
+                                    <tr class="border-b border-indigo-100">
+                                        <td class="py-2 pr-4 text-gray-500">{ "Experience" }</td>
+                                        {compared_jobs.iter().map(|job| html! {
+                                            <td class="py-2 pr-4 text-gray-700">{ format!("{} years", job.required_experience) }</td>
+                                        }).collect::<Html>()}
+                                    </tr>
+                                    <tr>
+                                        <td class="py-2 pr-4"></td>
+                                        {compared_jobs.iter().map(|job| html! {
+                                            <td class="py-2 pr-4">{ compare_action(job) }</td>
+                                        }).collect::<Html>()}
+                                    </tr>
+                                </tbody>
+                            </table>
+                        </div>
+                    }
+                }}
+
                 // Available Jobs List
                 <div class="p-6 overflow-y-auto max-h-96">
                     <h3 class="text-lg font-semibold text-gray-800 mb-4">{ "Available Positions" }</h3>
@@ -108,7 +316,7 @@ pub fn job_browser(props: &JobBrowserProps) -> Html {
                         html! {
                             <div class="space-y-3">
                                 {available_jobs.iter().map(|job| {
-                                    let qualifies = job.qualifies(career.years_experience);
+                                    let qualifies = career.qualifies_for(job, skills);
                                     let job_clone = job.clone();
                                     let on_accept = {
                                         let on_accept_job = props.on_accept_job.clone();
@@ -119,6 +327,16 @@ pub fn job_browser(props: &JobBrowserProps) -> Html {
 
                                     let is_current = career.current_job.as_ref().map(|j| j.id == job.id).unwrap_or(false);
 
+                                    let is_compared = compared_ids.contains(&job.id);
+                                    let compare_full = compared_ids.len() >= MAX_COMPARED_JOBS;
+                                    let on_compare = {
+                                        let on_toggle_compare = on_toggle_compare.clone();
+                                        let job_id = job.id.clone();
+                                        Callback::from(move |_| {
+                                            on_toggle_compare.emit(job_id.clone());
+                                        })
+                                    };
+
                                     html! {
                                         <div
                                             key={job.id.clone()}
@@ -143,6 +361,22 @@ pub fn job_browser(props: &JobBrowserProps) -> Html {
                                                         )}>
                                                             { job.level_name() }
                                                         </span>
+                                                        <button
+                                                            onclick={on_compare}
+                                                            disabled={!is_compared && compare_full}
+                                                            class={format!(
+                                                                "text-xs px-2 py-1 rounded border transition {}",
+                                                                if is_compared {
+                                                                    "border-indigo-500 bg-indigo-500 text-white"
+                                                                } else if compare_full {
+                                                                    "border-gray-200 text-gray-400 cursor-not-allowed"
+                                                                } else {
+                                                                    "border-indigo-300 text-indigo-600 hover:bg-indigo-100"
+                                                                }
+                                                            )}
+                                                        >
+                                                            {if is_compared { "✓ Comparing" } else { "+ Compare" }}
+                                                        </button>
                                                     </div>
                                                     <p class="text-sm text-gray-600">
                                                         {if let Some(company) = &job.company {
@@ -152,12 +386,29 @@ pub fn job_browser(props: &JobBrowserProps) -> Html {
                                                         }}
                                                         { job.field.name() }
                                                     </p>
+                                                    <p class="text-xs text-gray-500 mt-1">
+                                                        { format!(
+                                                            "Work-life balance: {} (burnout +{}/month{})",
+                                                            job.work_life_balance(),
+                                                            job.monthly_burnout(),
+                                                            match job.happiness_impact {
+                                                                0 => String::new(),
+                                                                impact if impact > 0 => format!(", happiness +{}", impact),
+                                                                impact => format!(", happiness {}", impact),
+                                                            }
+                                                        ) }
+                                                    </p>
                                                     <p class="text-xs text-gray-500 mt-1">
                                                         { format!("Requires {} years experience", job.required_experience) }
                                                         {if !qualifies {
                                                             html! {
                                                                 <span class="text-red-600 ml-2">
-                                                                    { format!("(You have {})", career.years_experience) }
+                                                                    { format!(
+                                                                        "(You have {} in {}, skill {})",
+                                                                        career.effective_experience(&job.field),
+                                                                        job.field.name(),
+                                                                        skills.get(&job.field).copied().unwrap_or(0)
+                                                                    ) }
                                                                 </span>
                                                             }
                                                         } else {
@@ -167,10 +418,27 @@ pub fn job_browser(props: &JobBrowserProps) -> Html {
                                                 </div>
                                                 <div class="text-right">
                                                     <p class="text-xl font-bold text-gray-800">
-                                                        { format!("{:.0}", job.monthly_salary) }
-                                                        { " Kč" }
+                                                        { currency.format(job.monthly_salary) }
                                                     </p>
-                                                    <p class="text-xs text-gray-500">{ "per month" }</p>
+                                                    <p class="text-xs text-gray-500">{ "gross per month" }</p>
+                                                    {if job.annual_bonus() > Decimal::ZERO {
+                                                        html! {
+                                                            <p class="text-xs text-indigo-600">
+                                                                { format!("+ {} December bonus", currency.format(job.annual_bonus())) }
+                                                            </p>
+                                                        }
+                                                    } else {
+                                                        html! {}
+                                                    }}
+                                                    {if let Some(net) = estimated_net_salary(job, market.as_ref()) {
+                                                        html! {
+                                                            <p class="text-sm font-semibold text-green-700 mt-1">
+                                                                { format!("~{} net", currency.format(net)) }
+                                                            </p>
+                                                        }
+                                                    } else {
+                                                        html! {}
+                                                    }}
                                                 </div>
                                             </div>
 
@@ -183,27 +451,54 @@ pub fn job_browser(props: &JobBrowserProps) -> Html {
                                                         { "Current Job" }
                                                     </button>
                                                 }
-                                            } else if !qualifies {
+                                            } else if is_rejected(job) {
                                                 html! {
                                                     <button
                                                         class="w-full bg-gray-300 text-gray-600 font-semibold py-2 px-4 rounded cursor-not-allowed"
                                                         disabled=true
                                                     >
-                                                        { "Not Qualified" }
+                                                        { "Rejected This Month" }
                                                     </button>
                                                 }
-                                            } else {
+                                            } else if !qualifies {
                                                 html! {
                                                     <button
                                                         onclick={on_accept}
-                                                        class="w-full bg-gradient-to-r from-indigo-500 to-purple-600 text-white font-semibold py-2 px-4 rounded hover:from-indigo-600 hover:to-purple-700 transition transform hover:scale-105"
+                                                        class="w-full bg-amber-500 text-white font-semibold py-2 px-4 rounded hover:bg-amber-600 transition"
                                                     >
-                                                        {if career.is_employed() {
-                                                            "Switch to This Job"
+                                                        { format!("Apply ({}% chance)", chance_percent(job)) }
+                                                    </button>
+                                                }
+                                            } else {
+                                                let on_accept_part_time = job.clone().part_time(PART_TIME_HOURS).ok().map(|part_time| {
+                                                    let on_accept_job = props.on_accept_job.clone();
+                                                    Callback::from(move |_| on_accept_job.emit(part_time.clone()))
+                                                });
+                                                html! {
+                                                    <div class="flex gap-2">
+                                                        <button
+                                                            onclick={on_accept}
+                                                            class="flex-1 bg-gradient-to-r from-indigo-500 to-purple-600 text-white font-semibold py-2 px-4 rounded hover:from-indigo-600 hover:to-purple-700 transition transform hover:scale-105"
+                                                        >
+                                                            {if career.is_employed() {
+                                                                "Switch to This Job"
+                                                            } else {
+                                                                "Accept Job Offer"
+                                                            }}
+                                                        </button>
+                                                        {if let Some(on_accept_part_time) = on_accept_part_time {
+                                                            html! {
+                                                                <button
+                                                                    onclick={on_accept_part_time}
+                                                                    class="border border-indigo-300 text-indigo-600 font-semibold py-2 px-4 rounded hover:bg-indigo-100 transition"
+                                                                >
+                                                                    { format!("Part-time ({}%)", (PART_TIME_HOURS * Decimal::from(100)).normalize()) }
+                                                                </button>
+                                                            }
                                                         } else {
-                                                            "Accept Job Offer"
+                                                            html! {}
                                                         }}
-                                                    </button>
+                                                    </div>
                                                 }
                                             }}
                                         </div>
@@ -1,7 +1,13 @@
+pub mod fire_progress;
 pub mod housing_browser;
 pub mod initialization;
 pub mod job_browser;
+pub mod new_game_control;
+pub mod tutorial_hint;
 
+pub use fire_progress::FireProgress;
 pub use housing_browser::HousingBrowser;
 pub use initialization::{Initialization, InitializationData};
 pub use job_browser::JobBrowser;
+pub use new_game_control::NewGameControl;
+pub use tutorial_hint::TutorialHint;
@@ -0,0 +1,75 @@
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct NewGameControlProps {
+    pub on_confirm: Callback<()>,
+}
+
+/// Floating "New Game" button that asks for confirmation before abandoning the current game
+#[function_component(NewGameControl)]
+pub fn new_game_control(props: &NewGameControlProps) -> Html {
+    let confirming = use_state(|| false);
+
+    let on_open = {
+        let confirming = confirming.clone();
+        Callback::from(move |_| {
+            confirming.set(true);
+        })
+    };
+
+    let on_cancel = {
+        let confirming = confirming.clone();
+        Callback::from(move |_| {
+            confirming.set(false);
+        })
+    };
+
+    let on_confirm_click = {
+        let confirming = confirming.clone();
+        let on_confirm = props.on_confirm.clone();
+        Callback::from(move |_| {
+            confirming.set(false);
+            on_confirm.emit(());
+        })
+    };
+
+    html! {
+        <>
+            <button
+                onclick={on_open}
+                class="fixed bottom-4 right-4 bg-white text-gray-600 hover:text-gray-800 text-sm font-semibold py-2 px-4 rounded-full shadow-lg border border-gray-200 z-40"
+            >
+                { "↺ New Game" }
+            </button>
+
+            {if *confirming {
+                html! {
+                    <div class="fixed inset-0 bg-black bg-opacity-50 flex items-center justify-center p-4 z-50">
+                        <div class="bg-white rounded-lg shadow-2xl max-w-md w-full p-6">
+                            <h2 class="text-xl font-bold text-gray-800 mb-2">{ "Start a New Game?" }</h2>
+                            <p class="text-sm text-gray-600 mb-6">
+                                { "Your current game will be lost. This cannot be undone." }
+                            </p>
+                            <div class="flex justify-end gap-3">
+                                <button
+                                    onclick={on_cancel}
+                                    class="bg-gray-200 hover:bg-gray-300 text-gray-700 font-semibold py-2 px-4 rounded transition"
+                                >
+                                    { "Keep Playing" }
+                                </button>
+                                <button
+                                    onclick={on_confirm_click}
+                                    class="bg-red-500 hover:bg-red-600 text-white font-semibold py-2 px-4 rounded transition"
+                                >
+                                    { "Start Over" }
+                                </button>
+                            </div>
+                        </div>
+                    </div>
+                }
+            } else {
+                html! {}
+            }}
+        </>
+    }
+}
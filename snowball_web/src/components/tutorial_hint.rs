@@ -0,0 +1,44 @@
+use fin_engine::{GameState, TutorialStep};
+use std::rc::Rc;
+use yew::prelude::*;
+
+#[derive(Properties)]
+pub struct TutorialHintProps {
+    pub game_state: Rc<GameState>,
+}
+
+impl PartialEq for TutorialHintProps {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.game_state, &other.game_state)
+    }
+}
+
+/// Guided-mode banner showing the next tutorial step the player hasn't done yet
+/// Renders nothing when the tutorial is off or finished.
+#[function_component(TutorialHint)]
+pub fn tutorial_hint(props: &TutorialHintProps) -> Html {
+    let Some(step) = props.game_state.tutorial_hint() else {
+        return html! {};
+    };
+    let position = TutorialStep::all()
+        .iter()
+        .position(|s| *s == step)
+        .unwrap_or(0)
+        + 1;
+
+    html! {
+        <div class="bg-yellow-50 border-l-4 border-yellow-400 p-4 mb-6">
+            <div class="flex">
+                <div class="flex-shrink-0">
+                    <span class="text-2xl">{ "💡" }</span>
+                </div>
+                <div class="ml-3">
+                    <h3 class="text-sm font-semibold text-yellow-800 mb-1">
+                        { format!("Step {} of {}: {}", position, TutorialStep::all().len(), step.name()) }
+                    </h3>
+                    <p class="text-sm text-yellow-700">{ step.hint() }</p>
+                </div>
+            </div>
+        </div>
+    }
+}
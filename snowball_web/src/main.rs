@@ -1,6 +1,7 @@
 mod app;
 mod app_state;
 mod components;
+mod market;
 mod screens;
 
 use app::App;
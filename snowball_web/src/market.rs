@@ -0,0 +1,12 @@
+use fin_engine::market::MarketProfile;
+use fin_engine::{market_profile, CzechMarket};
+
+/// Gets the market profile for a given market ID
+/// Games are validated when loaded, so an unknown ID is a bug; it is logged
+/// and played under Czech rules rather than crashing the page.
+pub fn get_market_profile(market_id: &str) -> Box<dyn MarketProfile> {
+    market_profile(market_id).unwrap_or_else(|| {
+        web_sys::console::error_1(&format!("Unknown market '{}'", market_id).into());
+        Box::new(CzechMarket)
+    })
+}
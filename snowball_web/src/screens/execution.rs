@@ -1,5 +1,9 @@
-use fin_engine::{GamePhase, GameState};
+use crate::components::TutorialHint;
+use crate::market::get_market_profile;
+use fin_engine::{Event, GamePhase, GameState};
 use gloo_timers::callback::Interval;
+use rust_decimal::Decimal;
+use std::rc::Rc;
 use yew::prelude::*;
 
 #[derive(Clone, Copy, PartialEq)]
@@ -35,17 +39,25 @@ impl PlaybackSpeed {
     }
 }
 
-#[derive(Properties, PartialEq)]
+#[derive(Properties)]
 pub struct ExecutionProps {
-    pub game_state: GameState,
+    pub game_state: Rc<GameState>,
     pub on_advance_day: Callback<()>,
 }
 
+impl PartialEq for ExecutionProps {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.game_state, &other.game_state)
+            && self.on_advance_day == other.on_advance_day
+    }
+}
+
 #[function_component(ExecutionScreen)]
 pub fn execution_screen(props: &ExecutionProps) -> Html {
     let game_state = &props.game_state;
     let player = &game_state.player;
     let finances = &game_state.finances;
+    let currency = get_market_profile(&game_state.market_id).currency();
 
     let current_day = if let GamePhase::Execution { current_day } = game_state.phase {
         current_day
@@ -53,6 +65,27 @@ pub fn execution_screen(props: &ExecutionProps) -> Html {
         1
     };
 
+    // What still falls due or gets paid in before the month ends
+    let upcoming = |days: Vec<u8>| -> Vec<String> {
+        days.into_iter()
+            .filter(|day| *day > current_day)
+            .map(|day| day.to_string())
+            .collect()
+    };
+    let bills = upcoming(finances.due_days());
+    let paydays = upcoming(finances.paydays());
+    let bills = match bills.len() {
+        0 => None,
+        1 => Some(format!("Bills on day {}", bills[0])),
+        _ => Some(format!("Bills on days {}", bills.join(", "))),
+    };
+    let schedule = match (bills, paydays.first()) {
+        (None, None) => "No more bills or pay this month".to_string(),
+        (None, Some(payday)) => format!("Next payday on day {}", payday),
+        (Some(bills), None) => bills,
+        (Some(bills), Some(payday)) => format!("{} • Next payday on day {}", bills, payday),
+    };
+
     let is_playing = use_state(|| true); // Start playing by default
     let is_skipping = use_state(|| false); // Track if we're skipping to end
     let speed = use_state(|| PlaybackSpeed::Normal);
@@ -64,7 +97,6 @@ pub fn execution_screen(props: &ExecutionProps) -> Html {
         let is_playing = is_playing.clone();
         let is_skipping = is_skipping.clone();
         let speed = *speed;
-        let current_day = current_day;
 
         use_effect_with(
             (current_day, *is_playing, *is_skipping, speed),
@@ -146,9 +178,15 @@ pub fn execution_screen(props: &ExecutionProps) -> Html {
                         </div>
                         <div class="text-right">
                             <p class="text-xs text-gray-500">{ "Cash Balance" }</p>
-                            <p class="text-lg font-bold text-gray-800">
-                                { format!("{:.2}", finances.cash) }
-                                { " Kč" }
+                            <p class={if finances.cash < Decimal::ZERO {
+                                "text-lg font-bold text-red-600"
+                            } else {
+                                "text-lg font-bold text-gray-800"
+                            }}>
+                                { currency.format(finances.cash) }
+                            </p>
+                            <p class="text-xs text-gray-400">
+                                { schedule }
                             </p>
                         </div>
                     </div>
@@ -157,6 +195,8 @@ pub fn execution_screen(props: &ExecutionProps) -> Html {
 
             // Main Content
             <div class="max-w-4xl mx-auto px-4 py-8">
+                <TutorialHint game_state={game_state.clone()} />
+
                 // Phase Indicator
                 <div class="bg-purple-500 text-white rounded-lg p-6 mb-6 shadow-lg">
                     <div class="flex items-center justify-between mb-4">
@@ -215,6 +255,10 @@ pub fn execution_screen(props: &ExecutionProps) -> Html {
                                 <p class="text-xs text-gray-500 mb-1">{ "Burnout" }</p>
                                 <p class="text-lg font-bold text-gray-800">{ player.burnout }</p>
                             </div>
+                            <div>
+                                <p class="text-xs text-gray-500 mb-1">{ "Health" }</p>
+                                <p class="text-lg font-bold text-gray-800">{ player.health }</p>
+                            </div>
                             <div>
                                 <p class="text-xs text-gray-500 mb-1">{ "Peace Score" }</p>
                                 <p class="text-lg font-bold text-indigo-600">
@@ -239,14 +283,53 @@ pub fn execution_screen(props: &ExecutionProps) -> Html {
                             </div>
                         </div>
 
-                        {if current_day % 7 == 0 {
+                        {game_state.month_events.iter().map(|event| {
+                            html! {
+                                <div class="flex items-center gap-3 p-3 bg-red-50 rounded-lg">
+                                    <span class="text-2xl">{ "🚨" }</span>
+                                    <div>
+                                        <p class="text-sm font-semibold text-gray-800">{ event.title() }</p>
+                                        <p class="text-xs text-gray-600">
+                                            {match event {
+                                                Event::Layoff { job_title } => {
+                                                    format!("Your position as {} was cut", job_title)
+                                                }
+                                                Event::MarketCrash { drop } => {
+                                                    format!("Stock markets fell {}% this month", (-*drop * Decimal::from(100)).normalize())
+                                                }
+                                                Event::TaxSettlement { refund } if *refund >= Decimal::ZERO => {
+                                                    format!("Annual tax reconciliation refunded {}", currency.format(*refund))
+                                                }
+                                                Event::Windfall { amount, tax, .. } if *tax > Decimal::ZERO => {
+                                                    format!(
+                                                        "Received {} ({} tax withheld)",
+                                                        currency.format(*amount - *tax),
+                                                        currency.format(*tax)
+                                                    )
+                                                }
+                                                Event::Windfall { amount, .. } => {
+                                                    format!("Received {} tax-free", currency.format(*amount))
+                                                }
+                                                _ => format!("Cost: {}", currency.format(event.cost())),
+                                            }}
+                                        </p>
+                                    </div>
+                                </div>
+                            }
+                        }).collect::<Html>()}
+
+                        {if game_state.time.is_weekend() {
                             html! {
                                 <div class="flex items-center gap-3 p-3 bg-green-50 rounded-lg">
                                     <span class="text-2xl">{ "🎉" }</span>
                                     <div>
                                         <p class="text-sm font-semibold text-gray-800">{ "Weekend!" }</p>
                                         <p class="text-xs text-gray-600">
-                                            { "Time to relax and recharge. Happiness +5" }
+                                            {if player.burnout > 70 {
+                                                "Too exhausted to fully recharge. Happiness +1"
+                                            } else {
+                                                "Time to relax and recharge. Happiness +2"
+                                            }}
                                         </p>
                                     </div>
                                 </div>
@@ -1,15 +1,40 @@
-use crate::components::{HousingBrowser, JobBrowser};
-use fin_engine::{ExpenseCategory, GameState, Housing, Income, IncomeKind, Job};
+use crate::components::job_browser::tenure_label;
+use crate::components::{FireProgress, HousingBrowser, JobBrowser, TutorialHint};
+use crate::market::get_market_profile;
+use fin_engine::{
+    debt_payoff_advice, suggest_contributions, AccountKind, AdviceAction, AssetCategory,
+    BudgetPreset, Expense, ExpenseCategory, GameState, Housing, Job, LoanKind, MarketProfile,
+    INSTALLMENT_DOWN_PAYMENT, INSTALLMENT_MONTHS, PURCHASE_OPTIONS,
+};
 use rust_decimal::Decimal;
+use std::rc::Rc;
 use yew::prelude::*;
 
-#[derive(Properties, PartialEq)]
+/// Length of the career break offered from the Career section
+const SABBATICAL_MONTHS: u8 = 3;
+
+/// Parses the Essential budget typed by the player, raised to the market's survival minimum
+fn essential_budget_input(market: &dyn MarketProfile, input: &str) -> Option<Decimal> {
+    let amount = input.parse::<Decimal>().ok()?;
+    Some(market.clamp_essential_budget(amount))
+}
+
+#[derive(Properties)]
 pub struct PlanningProps {
-    pub game_state: GameState,
+    pub game_state: Rc<GameState>,
     pub on_start_month: Callback<()>,
     pub on_update_state: Callback<GameState>,
 }
 
+impl PartialEq for PlanningProps {
+    fn eq(&self, other: &Self) -> bool {
+        // Game states are replaced, never mutated in place, so identity is enough
+        Rc::ptr_eq(&self.game_state, &other.game_state)
+            && self.on_start_month == other.on_start_month
+            && self.on_update_state == other.on_update_state
+    }
+}
+
 #[function_component(PlanningScreen)]
 pub fn planning_screen(props: &PlanningProps) -> Html {
     let game_state = &props.game_state;
@@ -17,15 +42,30 @@ pub fn planning_screen(props: &PlanningProps) -> Html {
     let player = &game_state.player;
     let career = &game_state.career;
     let housing = &game_state.housing;
+    let market = get_market_profile(&game_state.market_id);
+    let currency = market.currency();
+    let essential_minimum = market.essential_minimum();
+    let market_account_types = market.available_accounts();
+    let months_elapsed = game_state.months_elapsed();
 
     let net_worth = finances.net_worth();
     let monthly_income = finances.monthly_gross_income();
     let monthly_expenses = finances.monthly_expenses();
+    let net_income = if monthly_income > Decimal::ZERO {
+        monthly_income
+            - finances
+                .monthly_taxes(market.as_ref())
+                .map(|t| t.total)
+                .unwrap_or(Decimal::ZERO)
+    } else {
+        Decimal::ZERO
+    };
     let financial_peace = player.financial_peace_score();
 
     // Modal states
     let show_job_browser = use_state(|| false);
     let show_housing_browser = use_state(|| false);
+    let application_message = use_state(|| None::<String>);
 
     let on_start_click = {
         let on_start_month = props.on_start_month.clone();
@@ -43,7 +83,9 @@ pub fn planning_screen(props: &PlanningProps) -> Html {
 
     let on_close_job_browser = {
         let show_job_browser = show_job_browser.clone();
+        let application_message = application_message.clone();
         Callback::from(move |_| {
+            application_message.set(None);
             show_job_browser.set(false);
         })
     };
@@ -51,43 +93,34 @@ pub fn planning_screen(props: &PlanningProps) -> Html {
     let on_accept_job = {
         let show_job_browser = show_job_browser.clone();
         let on_update_state = props.on_update_state.clone();
+        let application_message = application_message.clone();
         let game_state_clone = game_state.clone();
         Callback::from(move |job: Job| {
             // Clone the game state for modification
-            let mut new_state = game_state_clone.clone();
-
-            // If this is the first job, give starting cash and set minimum food budget
-            let is_first_job = new_state.career.current_job.is_none();
-            if is_first_job {
-                // Give 50% of monthly salary as starting cash
-                new_state.finances.cash = job.monthly_salary / Decimal::from(2);
+            let mut new_state = (*game_state_clone).clone();
+            let market = get_market_profile(&new_state.market_id);
 
-                // Set minimum food budget (3,500 Kč/month - survival level)
-                new_state
-                    .finances
-                    .set_budget(ExpenseCategory::Essential, Decimal::from(3500));
+            // Stretch roles may turn the player down
+            match new_state.apply_for_job(&job, market.as_ref()) {
+                Ok(true) => application_message.set(None),
+                Ok(false) => {
+                    application_message.set(Some(format!(
+                        "{} turned down your application. Try again next month.",
+                        job.company.as_deref().unwrap_or(&job.title)
+                    )));
+                    on_update_state.emit(new_state);
+                    return;
+                }
+                Err(e) => {
+                    application_message.set(Some(e));
+                    return;
+                }
             }
 
-            // Accept the job in career
-            new_state.career.accept_job(job.clone());
-
-            // Create or update income entry
-            let income_id = format!("job_{}", job.id);
-
-            // Remove any existing job income
-            new_state
-                .finances
-                .income_sources
-                .retain(|inc| !inc.id.starts_with("job_"));
-
-            // Add new job income
-            new_state.finances.income_sources.push(Income {
-                id: income_id,
-                name: job.title.clone(),
-                kind: IncomeKind::Employment,
-                gross_monthly: job.monthly_salary,
-                active: true,
-            });
+            // Only a first job grants starting cash and the minimum food budget
+            // (not after quitting a previous job, which would wipe savings)
+            let is_first_job = new_state.is_first_job();
+            new_state.accept_job(job, is_first_job, market.as_ref());
 
             // Update state and close modal
             on_update_state.emit(new_state);
@@ -95,6 +128,48 @@ pub fn planning_screen(props: &PlanningProps) -> Html {
         })
     };
 
+    let confirm_quit = use_state(|| false);
+
+    let on_quit_click = {
+        let confirm_quit = confirm_quit.clone();
+        Callback::from(move |_| {
+            confirm_quit.set(true);
+        })
+    };
+
+    let on_cancel_quit = {
+        let confirm_quit = confirm_quit.clone();
+        Callback::from(move |_| {
+            confirm_quit.set(false);
+        })
+    };
+
+    let on_confirm_quit = {
+        let confirm_quit = confirm_quit.clone();
+        let on_update_state = props.on_update_state.clone();
+        let game_state_clone = game_state.clone();
+        Callback::from(move |_| {
+            let mut new_state = (*game_state_clone).clone();
+            new_state.quit_job();
+            on_update_state.emit(new_state);
+            confirm_quit.set(false);
+        })
+    };
+
+    let on_sabbatical_click = {
+        let on_update_state = props.on_update_state.clone();
+        let game_state_clone = game_state.clone();
+        Callback::from(move |_| {
+            let mut new_state = (*game_state_clone).clone();
+            match new_state.start_sabbatical(SABBATICAL_MONTHS) {
+                Ok(()) => on_update_state.emit(new_state),
+                Err(e) => {
+                    web_sys::console::error_1(&format!("Failed to start sabbatical: {}", e).into())
+                }
+            }
+        })
+    };
+
     let on_browse_housing_click = {
         let show_housing_browser = show_housing_browser.clone();
         Callback::from(move |_| {
@@ -114,10 +189,11 @@ pub fn planning_screen(props: &PlanningProps) -> Html {
         let on_update_state = props.on_update_state.clone();
         let game_state_clone = game_state.clone();
         Callback::from(move |housing: Housing| {
-            let mut new_state = game_state_clone.clone();
+            let mut new_state = (*game_state_clone).clone();
 
             // Try to change housing (handles affordability check and moving costs)
-            match new_state.change_housing(housing) {
+            let market = get_market_profile(&new_state.market_id);
+            match new_state.change_housing(housing, market.as_ref()) {
                 Ok(_) => {
                     on_update_state.emit(new_state);
                     show_housing_browser.set(false);
@@ -130,17 +206,234 @@ pub fn planning_screen(props: &PlanningProps) -> Html {
         })
     };
 
+    // Big purchases, paid in cash or on installments
+    let purchase_message = use_state(|| None::<String>);
+    let on_purchase = {
+        let on_update_state = props.on_update_state.clone();
+        let game_state_clone = game_state.clone();
+        let purchase_message = purchase_message.clone();
+        Callback::from(move |(index, financed): (usize, bool)| {
+            let mut new_state = (*game_state_clone).clone();
+            let market = get_market_profile(&new_state.market_id);
+            let result = new_state
+                .buy_purchase(&PURCHASE_OPTIONS[index], financed, market.as_ref())
+                .map(|_| ());
+            match result {
+                Ok(()) => {
+                    purchase_message.set(None);
+                    on_update_state.emit(new_state);
+                }
+                Err(e) => purchase_message.set(Some(e)),
+            }
+        })
+    };
+
+    // Moving money between cash and accounts
+    let account_amount = use_state(|| None::<Decimal>);
+    let account_message = use_state(|| None::<String>);
+    let on_open_account = {
+        let on_update_state = props.on_update_state.clone();
+        let game_state_clone = game_state.clone();
+        let account_message = account_message.clone();
+        Callback::from(move |(name, kind): (String, AccountKind)| {
+            let mut new_state = (*game_state_clone).clone();
+            new_state.open_account(name, kind);
+            account_message.set(None);
+            on_update_state.emit(new_state);
+        })
+    };
+    let on_open_market_account = {
+        let on_update_state = props.on_update_state.clone();
+        let game_state_clone = game_state.clone();
+        let account_message = account_message.clone();
+        Callback::from(move |account_type_id: String| {
+            let mut new_state = (*game_state_clone).clone();
+            let market = get_market_profile(&new_state.market_id);
+            let Some(account_type) = market
+                .available_accounts()
+                .into_iter()
+                .find(|t| t.id == account_type_id)
+            else {
+                return;
+            };
+            match new_state.open_market_account(&account_type) {
+                Ok(_) => {
+                    account_message.set(None);
+                    on_update_state.emit(new_state);
+                }
+                Err(e) => account_message.set(Some(e)),
+            }
+        })
+    };
+    let on_move_money = {
+        let on_update_state = props.on_update_state.clone();
+        let game_state_clone = game_state.clone();
+        let account_amount = account_amount.clone();
+        let account_message = account_message.clone();
+        Callback::from(move |(account_id, deposit): (String, bool)| {
+            let Some(amount) = *account_amount else {
+                account_message.set(Some("Enter an amount first".to_string()));
+                return;
+            };
+            let mut new_state = (*game_state_clone).clone();
+            let market = get_market_profile(&new_state.market_id);
+            let result = if deposit {
+                new_state
+                    .finances
+                    .deposit_to_account(&account_id, amount, market.as_ref())
+            } else {
+                new_state
+                    .withdraw_from_account(&account_id, amount, market.as_ref())
+                    .map(|_| ())
+            };
+            match result {
+                Ok(()) => {
+                    account_message.set(None);
+                    on_update_state.emit(new_state);
+                }
+                Err(e) => account_message.set(Some(e)),
+            }
+        })
+    };
+
     // Budget allocation callbacks
     let on_budget_change = {
         let on_update_state = props.on_update_state.clone();
         let game_state_clone = game_state.clone();
         Callback::from(move |(category, amount): (ExpenseCategory, Decimal)| {
-            let mut new_state = game_state_clone.clone();
+            let mut new_state = (*game_state_clone).clone();
             new_state.finances.set_budget(category, amount);
             on_update_state.emit(new_state);
         })
     };
 
+    let on_suggest_budget = {
+        let on_update_state = props.on_update_state.clone();
+        let game_state_clone = game_state.clone();
+        Callback::from(move |_| {
+            let mut new_state = (*game_state_clone).clone();
+            let market = get_market_profile(&new_state.market_id);
+            let gross = new_state.finances.monthly_gross_income();
+            let taxes = new_state
+                .finances
+                .monthly_taxes(market.as_ref())
+                .map(|t| t.total)
+                .unwrap_or(Decimal::ZERO);
+            new_state
+                .finances
+                .apply_budget_preset(gross - taxes, BudgetPreset::FiftyThirtyTwenty);
+            on_update_state.emit(new_state);
+        })
+    };
+
+    // Recurring subscriptions (streaming, gym, ...)
+    let subscription_name = use_state(String::new);
+    let subscription_amount = use_state(|| None::<Decimal>);
+
+    let on_add_subscription = {
+        let on_update_state = props.on_update_state.clone();
+        let game_state_clone = game_state.clone();
+        let subscription_name = subscription_name.clone();
+        let subscription_amount = subscription_amount.clone();
+        Callback::from(move |category: ExpenseCategory| {
+            let name = subscription_name.trim().to_string();
+            let Some(amount) = (*subscription_amount).filter(|a| *a > Decimal::ZERO) else {
+                return;
+            };
+            if name.is_empty() {
+                return;
+            }
+            let mut new_state = (*game_state_clone).clone();
+            let id = (1..)
+                .map(|n| format!("subscription_{}", n))
+                .find(|id| new_state.finances.expenses.iter().all(|e| &e.id != id))
+                .unwrap_or_default();
+            new_state
+                .finances
+                .add_expense(Expense::new(id, name, category, amount));
+            subscription_name.set(String::new());
+            subscription_amount.set(None);
+            on_update_state.emit(new_state);
+        })
+    };
+
+    let on_toggle_subscription = {
+        let on_update_state = props.on_update_state.clone();
+        let game_state_clone = game_state.clone();
+        Callback::from(move |(id, active): (String, bool)| {
+            let mut new_state = (*game_state_clone).clone();
+            match new_state.finances.set_expense_active(&id, active) {
+                Ok(()) => on_update_state.emit(new_state),
+                Err(e) => {
+                    web_sys::console::error_1(&format!("Cannot update subscription: {}", e).into());
+                }
+            }
+        })
+    };
+
+    let on_remove_subscription = {
+        let on_update_state = props.on_update_state.clone();
+        let game_state_clone = game_state.clone();
+        Callback::from(move |id: String| {
+            let mut new_state = (*game_state_clone).clone();
+            match new_state.finances.remove_expense(&id) {
+                Ok(_) => on_update_state.emit(new_state),
+                Err(e) => {
+                    web_sys::console::error_1(&format!("Cannot remove subscription: {}", e).into());
+                }
+            }
+        })
+    };
+
+    // Compound growth calculator
+    let projection_contribution = use_state(|| None::<Decimal>);
+    let expected_return = game_state.expected_annual_return(market.as_ref());
+    let debt_advice = debt_payoff_advice(finances, market.as_ref());
+    let contribution_suggestions =
+        suggest_contributions(finances, market.as_ref(), &game_state.player).unwrap_or_default();
+
+    let on_sell_asset = {
+        let on_update_state = props.on_update_state.clone();
+        let game_state_clone = game_state.clone();
+        Callback::from(move |asset_id: String| {
+            let mut new_state = (*game_state_clone).clone();
+            let market = get_market_profile(&new_state.market_id);
+            let month = new_state.months_elapsed();
+            match new_state
+                .finances
+                .sell_asset(&asset_id, market.as_ref(), month)
+            {
+                Ok(_) => on_update_state.emit(new_state),
+                Err(e) => {
+                    web_sys::console::error_1(&format!("Cannot sell asset: {}", e).into());
+                }
+            }
+        })
+    };
+
+    let on_toggle_rental = {
+        let on_update_state = props.on_update_state.clone();
+        let game_state_clone = game_state.clone();
+        Callback::from(move |asset_id: String| {
+            let mut new_state = (*game_state_clone).clone();
+            let result = match new_state.finances.assets.iter().find(|a| a.id == asset_id) {
+                Some(asset) if asset.rented => new_state.finances.stop_renting_asset(&asset_id),
+                Some(asset) => {
+                    let market = get_market_profile(&new_state.market_id);
+                    let rent = asset.market_rent(market.gross_rental_yield());
+                    new_state.finances.rent_out_asset(&asset_id, rent)
+                }
+                None => Err(format!("Asset '{}' not found", asset_id)),
+            };
+            match result {
+                Ok(()) => on_update_state.emit(new_state),
+                Err(e) => {
+                    web_sys::console::error_1(&format!("Cannot change tenancy: {}", e).into());
+                }
+            }
+        })
+    };
+
     html! {
         <div class="min-h-screen bg-gradient-to-br from-blue-50 to-indigo-100">
             // Header
@@ -161,8 +454,7 @@ pub fn planning_screen(props: &PlanningProps) -> Html {
                             <div class="text-right">
                                 <p class="text-xs text-gray-500">{ "Net Worth" }</p>
                                 <p class="text-lg font-bold text-gray-800">
-                                    { format!("{:.2}", net_worth) }
-                                    { " Kč" }
+                                    { currency.format(net_worth) }
                                 </p>
                             </div>
                             <div class="text-right">
@@ -179,6 +471,8 @@ pub fn planning_screen(props: &PlanningProps) -> Html {
 
             // Main Content
             <div class="max-w-4xl mx-auto px-4 py-8">
+                <TutorialHint game_state={game_state.clone()} />
+
                 // Phase Indicator
                 <div class="bg-blue-500 text-white rounded-lg p-6 mb-6 shadow-lg">
                     <div class="flex items-center justify-between">
@@ -231,6 +525,21 @@ pub fn planning_screen(props: &PlanningProps) -> Html {
                             </div>
                         </div>
                     </div>
+                    <p class="text-xs text-gray-500 mt-4">
+                        { format!(
+                            "Economy: {} • Base rate {:.2}% • Savings {:.2}%",
+                            game_state.economy.phase.name(),
+                            game_state.economy.base_interest_rate * Decimal::from(100),
+                            game_state.economy.savings_interest_rate() * Decimal::from(100),
+                        ) }
+                    </p>
+                    <p class="text-xs text-gray-500 mt-1">
+                        { format!("Credit score {} • ", player.credit_score) }
+                        {match game_state.offered_loan_rate(LoanKind::Mortgage) {
+                            Ok(rate) => format!("Your mortgage rate {:.2}%", rate * Decimal::from(100)),
+                            Err(_) => "Lenders won't offer you a loan right now".to_string(),
+                        }}
+                    </p>
                 </div>
 
                 // Career Section
@@ -264,18 +573,77 @@ pub fn planning_screen(props: &PlanningProps) -> Html {
                                                 { format!("{} years experience", career.years_experience) }
                                             </span>
                                             <span>
-                                                { format!("{} months at position", career.months_in_current_job) }
+                                                { format!("{} at position", tenure_label(career)) }
                                             </span>
                                         </div>
                                     </div>
                                     <div class="text-right">
                                         <p class="text-2xl font-bold text-green-600">
-                                            { format!("{:.0}", job.monthly_salary) }
-                                            { " Kč" }
+                                            { currency.format(job.monthly_salary) }
                                         </p>
                                         <p class="text-xs text-gray-500">{ "per month" }</p>
                                     </div>
                                 </div>
+
+                                {if *confirm_quit {
+                                    html! {
+                                        <div class="mt-4 p-3 bg-red-50 border border-red-200 rounded-lg">
+                                            <p class="text-sm text-red-800 mb-3">
+                                                { format!(
+                                                    "Quit your job? You will stop receiving {} per month.",
+                                                    currency.format(job.monthly_salary)
+                                                ) }
+                                            </p>
+                                            <div class="flex gap-2">
+                                                <button
+                                                    onclick={on_confirm_quit}
+                                                    class="bg-red-500 hover:bg-red-600 text-white text-sm font-semibold py-1 px-3 rounded transition"
+                                                >
+                                                    { "Yes, Quit" }
+                                                </button>
+                                                <button
+                                                    onclick={on_cancel_quit}
+                                                    class="bg-gray-200 hover:bg-gray-300 text-gray-700 text-sm font-semibold py-1 px-3 rounded transition"
+                                                >
+                                                    { "Cancel" }
+                                                </button>
+                                            </div>
+                                        </div>
+                                    }
+                                } else {
+                                    html! {
+                                        <div class="mt-4 flex justify-end gap-4">
+                                            <button
+                                                onclick={on_sabbatical_click}
+                                                class="text-sm text-indigo-600 hover:text-indigo-800 font-semibold"
+                                                title="Leave work to recover from burnout or study"
+                                            >
+                                                { format!("Take {}-Month Sabbatical", SABBATICAL_MONTHS) }
+                                            </button>
+                                            <button
+                                                onclick={on_quit_click}
+                                                class="text-sm text-red-600 hover:text-red-800 font-semibold"
+                                            >
+                                                { "Quit Job" }
+                                            </button>
+                                        </div>
+                                    }
+                                }}
+                            </div>
+                        }
+                    } else if career.is_on_sabbatical() {
+                        html! {
+                            <div class="bg-indigo-50 border-2 border-indigo-400 rounded-lg p-4 text-center">
+                                <p class="text-indigo-800 font-semibold mb-1">{ "On Sabbatical" }</p>
+                                <p class="text-sm text-indigo-600">
+                                    { format!(
+                                        "{} months left. Burnout recovers quickly; Education spending builds extra skill.",
+                                        career.sabbatical_months_left
+                                    ) }
+                                </p>
+                                <p class="text-xs text-gray-500 mt-2">
+                                    { "Browse Jobs to end the break early" }
+                                </p>
                             </div>
                         }
                     } else {
@@ -320,10 +688,10 @@ pub fn planning_screen(props: &PlanningProps) -> Html {
                                         </p>
                                         <div class="flex gap-4 text-xs text-gray-500">
                                             <span>
-                                                { format!("Rent: {:.0} Kč", home.monthly_cost) }
+                                                { format!("Rent: {}", currency.format(home.monthly_cost)) }
                                             </span>
                                             <span>
-                                                { format!("Utilities: {:.0} Kč", home.monthly_utilities) }
+                                                { format!("Utilities: {}", currency.format(home.monthly_utilities)) }
                                             </span>
                                             <span>
                                                 { format!("{} months here", game_state.months_at_housing) }
@@ -332,8 +700,7 @@ pub fn planning_screen(props: &PlanningProps) -> Html {
                                     </div>
                                     <div class="text-right">
                                         <p class="text-2xl font-bold text-teal-600">
-                                            { format!("{:.0}", home.total_monthly_cost()) }
-                                            { " Kč" }
+                                            { currency.format(home.total_monthly_cost()) }
                                         </p>
                                         <p class="text-xs text-gray-500">{ "per month" }</p>
                                     </div>
@@ -357,7 +724,22 @@ pub fn planning_screen(props: &PlanningProps) -> Html {
 
                 // Budget Allocation Section
                 <div class="bg-white rounded-lg shadow-md p-6 mb-6">
-                    <h3 class="text-lg font-semibold text-gray-800 mb-4">{ "Monthly Budget Allocation" }</h3>
+                    <div class="flex justify-between items-center mb-4">
+                        <h3 class="text-lg font-semibold text-gray-800">{ "Monthly Budget Allocation" }</h3>
+                        {if monthly_income > Decimal::ZERO {
+                            html! {
+                                <button
+                                    onclick={on_suggest_budget}
+                                    title="Split your net income 50% needs, 30% wants, 20% savings"
+                                    class="bg-indigo-500 hover:bg-indigo-600 text-white font-semibold py-2 px-4 rounded transition"
+                                >
+                                    { "Suggest Budget (50/30/20)" }
+                                </button>
+                            }
+                        } else {
+                            html! {}
+                        }}
+                    </div>
                     <p class="text-sm text-gray-600 mb-4">
                         { "Set your monthly budgets. Essential expenses have minimums you must meet." }
                     </p>
@@ -371,12 +753,14 @@ pub fn planning_screen(props: &PlanningProps) -> Html {
                                         { "Food & Groceries " }
                                         <span class="text-red-600 text-xs">{ "(Required)" }</span>
                                     </p>
-                                    <p class="text-xs text-gray-500">{ "Minimum: 3,500 Kč/month for survival" }</p>
+                                    <p class="text-xs text-gray-500">
+                                        { format!("Minimum: {}/month for survival", currency.format(essential_minimum)) }
+                                    </p>
                                 </div>
                                 {if let Some(budget) = finances.budget.get(&ExpenseCategory::Essential) {
                                     html! {
                                         <p class="text-sm text-gray-600">
-                                            { format!("Spent: {:.0} / {:.0} Kč", budget.spent, budget.allocated) }
+                                            { format!("Spent: {} / {}", currency.format(budget.spent), currency.format(budget.allocated)) }
                                         </p>
                                     }
                                 } else {
@@ -385,28 +769,24 @@ pub fn planning_screen(props: &PlanningProps) -> Html {
                             </div>
                             <input
                                 type="number"
-                                min="3500"
+                                min={essential_minimum.to_string()}
                                 class="w-full px-3 py-2 border border-orange-300 rounded focus:outline-none focus:ring-2 focus:ring-orange-500"
-                                placeholder="3500"
-                                value={finances.budget.get(&ExpenseCategory::Essential).map(|b| b.allocated.to_string()).unwrap_or("3500".to_string())}
+                                placeholder={essential_minimum.to_string()}
+                                value={finances.budget.get(&ExpenseCategory::Essential).map(|b| b.allocated.to_string()).unwrap_or(essential_minimum.to_string())}
                                 oninput={
                                     let on_budget_change = on_budget_change.clone();
+                                    let market_id = game_state.market_id.clone();
                                     Callback::from(move |e: InputEvent| {
                                         let input: web_sys::HtmlInputElement = e.target_unchecked_into();
-                                        if let Ok(amount) = input.value().parse::<Decimal>() {
-                                            // Enforce minimum of 3,500 Kč
-                                            let final_amount = if amount < Decimal::from(3500) {
-                                                Decimal::from(3500)
-                                            } else {
-                                                amount
-                                            };
-                                            on_budget_change.emit((ExpenseCategory::Essential, final_amount));
+                                        let market = get_market_profile(&market_id);
+                                        if let Some(amount) = essential_budget_input(market.as_ref(), &input.value()) {
+                                            on_budget_change.emit((ExpenseCategory::Essential, amount));
                                         }
                                     })
                                 }
                             />
                             <p class="text-xs text-orange-700 mt-2">
-                                { "This covers basic groceries. You can increase this for better food quality." }
+                                { "This covers basic groceries. Budgeting 20% or more above the minimum improves happiness; months stuck at the minimum wear you down." }
                             </p>
                         </div>
 
@@ -425,7 +805,7 @@ pub fn planning_screen(props: &PlanningProps) -> Html {
                                 {if let Some(budget) = finances.budget.get(&ExpenseCategory::Lifestyle) {
                                     html! {
                                         <p class="text-sm text-gray-600">
-                                            { format!("Spent: {:.0} / {:.0} Kč", budget.spent, budget.allocated) }
+                                            { format!("Spent: {} / {}", currency.format(budget.spent), currency.format(budget.allocated)) }
                                         </p>
                                     }
                                 } else {
@@ -459,7 +839,7 @@ pub fn planning_screen(props: &PlanningProps) -> Html {
                                 {if let Some(budget) = finances.budget.get(&ExpenseCategory::Health) {
                                     html! {
                                         <p class="text-sm text-gray-600">
-                                            { format!("Spent: {:.0} / {:.0} Kč", budget.spent, budget.allocated) }
+                                            { format!("Spent: {} / {}", currency.format(budget.spent), currency.format(budget.allocated)) }
                                         </p>
                                     }
                                 } else {
@@ -493,7 +873,7 @@ pub fn planning_screen(props: &PlanningProps) -> Html {
                                 {if let Some(budget) = finances.budget.get(&ExpenseCategory::Transportation) {
                                     html! {
                                         <p class="text-sm text-gray-600">
-                                            { format!("Spent: {:.0} / {:.0} Kč", budget.spent, budget.allocated) }
+                                            { format!("Spent: {} / {}", currency.format(budget.spent), currency.format(budget.allocated)) }
                                         </p>
                                     }
                                 } else {
@@ -527,7 +907,7 @@ pub fn planning_screen(props: &PlanningProps) -> Html {
                                 {if let Some(budget) = finances.budget.get(&ExpenseCategory::Education) {
                                     html! {
                                         <p class="text-sm text-gray-600">
-                                            { format!("Spent: {:.0} / {:.0} Kč", budget.spent, budget.allocated) }
+                                            { format!("Spent: {} / {}", currency.format(budget.spent), currency.format(budget.allocated)) }
                                         </p>
                                     }
                                 } else {
@@ -561,7 +941,7 @@ pub fn planning_screen(props: &PlanningProps) -> Html {
                                 {if let Some(budget) = finances.budget.get(&ExpenseCategory::Other) {
                                     html! {
                                         <p class="text-sm text-gray-600">
-                                            { format!("Spent: {:.0} / {:.0} Kč", budget.spent, budget.allocated) }
+                                            { format!("Spent: {} / {}", currency.format(budget.spent), currency.format(budget.allocated)) }
                                         </p>
                                     }
                                 } else {
@@ -591,10 +971,15 @@ pub fn planning_screen(props: &PlanningProps) -> Html {
                                 <p class="text-lg font-semibold text-gray-800">{ "Total Monthly Budget" }</p>
                                 <p class="text-xl font-bold text-purple-600">
                                     {{
-                                        let total: Decimal = finances.budget.values()
+                                        let allocated: Decimal = finances.budget.values()
                                             .map(|b| b.allocated)
                                             .sum();
-                                        format!("{:.0} Kč", total)
+                                        // Active subscriptions come on top of the allocations
+                                        let subscriptions: Decimal = finances.expenses.iter()
+                                            .filter(|e| e.active && matches!(e.category, ExpenseCategory::Lifestyle | ExpenseCategory::Health))
+                                            .map(|e| e.monthly_amount)
+                                            .sum();
+                                        currency.format(allocated + subscriptions)
                                     }}
                                 </p>
                             </div>
@@ -602,6 +987,548 @@ pub fn planning_screen(props: &PlanningProps) -> Html {
                     </div>
                 </div>
 
+                // Subscriptions
+                <div class="bg-white rounded-lg shadow-md p-6 mb-6">
+                    <h3 class="text-lg font-semibold text-gray-800 mb-4">{ "Subscriptions" }</h3>
+                    <div class="space-y-3">
+                        {finances.expenses.iter()
+                            .filter(|e| matches!(e.category, ExpenseCategory::Lifestyle | ExpenseCategory::Health))
+                            .map(|expense| {
+                                let on_toggle = {
+                                    let on_toggle_subscription = on_toggle_subscription.clone();
+                                    let id = expense.id.clone();
+                                    let active = !expense.active;
+                                    Callback::from(move |_| on_toggle_subscription.emit((id.clone(), active)))
+                                };
+                                let on_remove = {
+                                    let on_remove_subscription = on_remove_subscription.clone();
+                                    let id = expense.id.clone();
+                                    Callback::from(move |_| on_remove_subscription.emit(id.clone()))
+                                };
+                                html! {
+                                    <div key={expense.id.clone()} class="flex justify-between items-center p-3 bg-gray-50 rounded-lg">
+                                        <div>
+                                            <p class={if expense.active { "text-sm font-semibold text-gray-800" } else { "text-sm font-semibold text-gray-400 line-through" }}>
+                                                { &expense.name }
+                                            </p>
+                                            <p class="text-xs text-gray-600">
+                                                { format!(
+                                                    "{} • {} / month",
+                                                    if expense.category == ExpenseCategory::Health { "Health" } else { "Lifestyle" },
+                                                    currency.format(expense.monthly_amount),
+                                                ) }
+                                            </p>
+                                        </div>
+                                        <div class="flex items-center gap-2">
+                                            <button
+                                                onclick={on_toggle}
+                                                class="bg-gray-200 hover:bg-gray-300 text-gray-800 text-sm font-semibold py-1 px-3 rounded transition"
+                                            >
+                                                { if expense.active { "Pause" } else { "Resume" } }
+                                            </button>
+                                            <button
+                                                onclick={on_remove}
+                                                class="bg-red-500 hover:bg-red-600 text-white text-sm font-semibold py-1 px-3 rounded transition"
+                                            >
+                                                { "Cancel" }
+                                            </button>
+                                        </div>
+                                    </div>
+                                }
+                            })
+                            .collect::<Html>()}
+                        <div class="flex flex-wrap gap-2 items-center">
+                            <input
+                                type="text"
+                                class="flex-1 px-3 py-2 border border-gray-300 rounded focus:outline-none focus:ring-2 focus:ring-purple-500"
+                                placeholder="Streaming, gym..."
+                                value={(*subscription_name).clone()}
+                                oninput={
+                                    let subscription_name = subscription_name.clone();
+                                    Callback::from(move |e: InputEvent| {
+                                        let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                                        subscription_name.set(input.value());
+                                    })
+                                }
+                            />
+                            <input
+                                type="number"
+                                min="0"
+                                class="w-32 px-3 py-2 border border-gray-300 rounded focus:outline-none focus:ring-2 focus:ring-purple-500"
+                                placeholder="Monthly"
+                                value={subscription_amount.map(|a| a.to_string()).unwrap_or_default()}
+                                oninput={
+                                    let subscription_amount = subscription_amount.clone();
+                                    Callback::from(move |e: InputEvent| {
+                                        let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                                        subscription_amount.set(input.value().parse::<Decimal>().ok());
+                                    })
+                                }
+                            />
+                            <button
+                                onclick={
+                                    let on_add_subscription = on_add_subscription.clone();
+                                    Callback::from(move |_| on_add_subscription.emit(ExpenseCategory::Lifestyle))
+                                }
+                                class="bg-purple-500 hover:bg-purple-600 text-white text-sm font-semibold py-2 px-3 rounded transition"
+                            >
+                                { "Add Lifestyle" }
+                            </button>
+                            <button
+                                onclick={
+                                    let on_add_subscription = on_add_subscription.clone();
+                                    Callback::from(move |_| on_add_subscription.emit(ExpenseCategory::Health))
+                                }
+                                class="bg-green-500 hover:bg-green-600 text-white text-sm font-semibold py-2 px-3 rounded transition"
+                            >
+                                { "Add Health" }
+                            </button>
+                        </div>
+                    </div>
+                </div>
+
+                // Investments & Accounts
+                <div class="bg-white rounded-lg shadow-md p-6 mb-6">
+                    <div class="flex justify-between items-center mb-4">
+                        <h3 class="text-lg font-semibold text-gray-800">{ "Investments & Accounts" }</h3>
+                        <div class="flex gap-2">
+                            {[("Brokerage", AccountKind::Taxable), ("Emergency Fund", AccountKind::EmergencyFund)]
+                                .into_iter()
+                                .map(|(name, kind)| {
+                                    let on_open_account = on_open_account.clone();
+                                    let onclick = Callback::from(move |_| {
+                                        on_open_account.emit((name.to_string(), kind.clone()))
+                                    });
+                                    html! {
+                                        <button
+                                            key={name}
+                                            {onclick}
+                                            class="bg-blue-500 hover:bg-blue-600 text-white text-sm font-semibold py-1 px-3 rounded transition"
+                                        >
+                                            { format!("Open {}", name) }
+                                        </button>
+                                    }
+                                })
+                                .collect::<Html>()}
+                        </div>
+                    </div>
+                    {if market_account_types.is_empty() {
+                        html! {}
+                    } else {
+                        html! {
+                            <div class="mb-4">
+                                <p class="text-sm font-semibold text-gray-700 mb-2">{ "Tax-advantaged accounts" }</p>
+                                <div class="space-y-2">
+                                    {market_account_types.iter().map(|account_type| {
+                                        let is_open = finances.accounts.iter().any(|a| matches!(
+                                            &a.kind,
+                                            AccountKind::Retirement { account_type_id } if *account_type_id == account_type.id
+                                        ));
+                                        let onclick = {
+                                            let on_open_market_account = on_open_market_account.clone();
+                                            let id = account_type.id.clone();
+                                            Callback::from(move |_| on_open_market_account.emit(id.clone()))
+                                        };
+                                        html! {
+                                            <div key={account_type.id.clone()} class="flex justify-between items-center p-3 border border-gray-200 rounded-lg">
+                                                <div>
+                                                    <p class="text-sm font-semibold text-gray-800">{ &account_type.name }</p>
+                                                    <p class="text-xs text-gray-500">
+                                                        {match account_type.annual_limit {
+                                                            Some(limit) => format!("Annual limit {}", currency.format(limit)),
+                                                            None => "No annual limit".to_string(),
+                                                        }}
+                                                        {if account_type.employer_match { " • Employer can contribute" } else { "" }}
+                                                    </p>
+                                                </div>
+                                                {if is_open {
+                                                    html! { <span class="text-xs font-semibold text-green-600">{ "Open" }</span> }
+                                                } else {
+                                                    html! {
+                                                        <button
+                                                            {onclick}
+                                                            class="bg-purple-500 hover:bg-purple-600 text-white text-xs font-semibold py-1 px-3 rounded transition"
+                                                        >
+                                                            { "Open" }
+                                                        </button>
+                                                    }
+                                                }}
+                                            </div>
+                                        }
+                                    }).collect::<Html>()}
+                                </div>
+                            </div>
+                        }
+                    }}
+                    {if finances.accounts.is_empty() {
+                        html! {}
+                    } else {
+                        html! {
+                            <input
+                                type="number"
+                                min="0"
+                                class="w-full px-3 py-2 border border-gray-300 rounded focus:outline-none focus:ring-2 focus:ring-blue-500 mb-3"
+                                placeholder="Amount to deposit or withdraw"
+                                value={account_amount.map(|a| a.to_string()).unwrap_or_default()}
+                                oninput={
+                                    let account_amount = account_amount.clone();
+                                    Callback::from(move |e: InputEvent| {
+                                        let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                                        account_amount.set(input.value().parse::<Decimal>().ok());
+                                    })
+                                }
+                            />
+                        }
+                    }}
+                    {if let Some(message) = &*account_message {
+                        html! { <p class="text-sm text-red-600 mb-3">{ message }</p> }
+                    } else {
+                        html! {}
+                    }}
+                    {if finances.accounts.is_empty() {
+                        html! {
+                            <p class="text-sm text-gray-500">
+                                { "You have no savings or investment accounts yet. Everything you own sits in cash." }
+                            </p>
+                        }
+                    } else {
+                        html! {
+                            <div class="space-y-3">
+                                {finances.accounts.iter().map(|account| {
+                                    let gain = account.capital_gain();
+                                    let move_money = |deposit: bool| {
+                                        let on_move_money = on_move_money.clone();
+                                        let account_id = account.id.clone();
+                                        Callback::from(move |_| on_move_money.emit((account_id.clone(), deposit)))
+                                    };
+                                    let (kind_label, limit) = match &account.kind {
+                                        AccountKind::Retirement { account_type_id } => {
+                                            let account_type = market_account_types.iter().find(|t| &t.id == account_type_id);
+                                            (
+                                                account_type.map_or_else(|| account_type_id.clone(), |t| t.name.clone()),
+                                                account_type.and_then(|t| t.annual_limit),
+                                            )
+                                        }
+                                        AccountKind::Taxable => ("Brokerage".to_string(), None),
+                                        AccountKind::EmergencyFund => ("Emergency Fund".to_string(), None),
+                                        AccountKind::SinkingFund { goal } => (format!("Saving for {}", goal), None),
+                                    };
+                                    html! {
+                                        <div key={account.id.clone()} class="p-3 bg-gray-50 rounded-lg">
+                                            <div class="flex justify-between items-center">
+                                                <div>
+                                                    <p class="text-sm font-semibold text-gray-800">{ &account.name }</p>
+                                                    <p class="text-xs text-gray-500">{ kind_label }</p>
+                                                    {match account.months_until_tax_exempt(months_elapsed, market.as_ref()) {
+                                                        Some(0) => html! {
+                                                            <p class="text-xs text-green-600">{ "Gains are tax-free" }</p>
+                                                        },
+                                                        Some(months) => html! {
+                                                            <p class="text-xs text-orange-600">{ format!("Tax-free in {} months", months) }</p>
+                                                        },
+                                                        None => html! {},
+                                                    }}
+                                                </div>
+                                                <div class="flex items-center gap-3">
+                                                    <div class="text-right">
+                                                        <p class="text-sm font-bold text-gray-800">{ currency.format(account.balance.round_dp(0)) }</p>
+                                                        <p class={if gain >= Decimal::ZERO { "text-xs text-green-600" } else { "text-xs text-red-600" }}>
+                                                            { format!("Growth: {}", currency.format(gain.round_dp(0))) }
+                                                        </p>
+                                                    </div>
+                                                    <button
+                                                        onclick={move_money(true)}
+                                                        class="bg-green-500 hover:bg-green-600 text-white text-xs font-semibold py-1 px-2 rounded transition"
+                                                    >
+                                                        { "Deposit" }
+                                                    </button>
+                                                    <button
+                                                        onclick={move_money(false)}
+                                                        class="bg-gray-500 hover:bg-gray-600 text-white text-xs font-semibold py-1 px-2 rounded transition"
+                                                    >
+                                                        { "Withdraw" }
+                                                    </button>
+                                                </div>
+                                            </div>
+                                            {if let Some(limit) = limit.filter(|l| *l > Decimal::ZERO) {
+                                                let used = (account.contributions_this_year / limit * Decimal::from(100))
+                                                    .min(Decimal::from(100))
+                                                    .round_dp(0);
+                                                html! {
+                                                    <div class="mt-2">
+                                                        <div class="flex justify-between text-xs text-gray-500 mb-1">
+                                                            <span>{ "Contributed this year" }</span>
+                                                            <span>
+                                                                { format!(
+                                                                    "{} / {}",
+                                                                    currency.format(account.contributions_this_year.round_dp(0)),
+                                                                    currency.format(limit),
+                                                                ) }
+                                                            </span>
+                                                        </div>
+                                                        <div class="bg-gray-200 rounded-full h-2">
+                                                            <div
+                                                                class="bg-purple-500 h-2 rounded-full transition-all"
+                                                                style={format!("width: {}%", used)}
+                                                            ></div>
+                                                        </div>
+                                                    </div>
+                                                }
+                                            } else {
+                                                html! {}
+                                            }}
+                                        </div>
+                                    }
+                                }).collect::<Html>()}
+                            </div>
+                        }
+                    }}
+                </div>
+
+                // Compound Growth Projection
+                <div class="bg-white rounded-lg shadow-md p-6 mb-6">
+                    <h3 class="text-lg font-semibold text-gray-800 mb-2">{ "Compound Growth Calculator" }</h3>
+                    <p class="text-sm text-gray-600 mb-4">
+                        { format!(
+                            "See where your investments could be if you add a fixed amount every month, assuming a steady {}% a year.",
+                            (expected_return * Decimal::from(100)).round_dp(1)
+                        ) }
+                    </p>
+                    <input
+                        type="number"
+                        min="0"
+                        class="w-full px-3 py-2 border border-gray-300 rounded focus:outline-none focus:ring-2 focus:ring-blue-500 mb-4"
+                        placeholder="Monthly contribution"
+                        value={projection_contribution.map(|a| a.to_string()).unwrap_or_default()}
+                        oninput={
+                            let projection_contribution = projection_contribution.clone();
+                            Callback::from(move |e: InputEvent| {
+                                let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                                projection_contribution.set(input.value().parse::<Decimal>().ok());
+                            })
+                        }
+                    />
+                    <div class="grid grid-cols-3 gap-3">
+                        {[5u32, 10, 20].into_iter().map(|years| {
+                            let monthly = projection_contribution.unwrap_or(Decimal::ZERO);
+                            let projected = finances.project_balance(monthly, expected_return, years);
+                            let contributed = finances.invested_balance() + monthly * Decimal::from(years * 12);
+                            html! {
+                                <div key={years} class="p-3 bg-blue-50 rounded-lg text-center">
+                                    <p class="text-xs text-gray-600">{ format!("In {} years", years) }</p>
+                                    <p class="text-lg font-bold text-blue-700">{ currency.format(projected.round_dp(0)) }</p>
+                                    <p class="text-xs text-gray-500">
+                                        { format!("Growth: {}", currency.format((projected - contributed).round_dp(0))) }
+                                    </p>
+                                </div>
+                            }
+                        }).collect::<Html>()}
+                    </div>
+                </div>
+
+                // Pay off debt vs invest
+                {if debt_advice.debt_name.is_some() {
+                    let advice = &debt_advice;
+                    let percent = |rate: Decimal| (rate * Decimal::from(100)).round_dp(1);
+                    let (badge, reason) = match advice.action {
+                        AdviceAction::PayOffDebt => (
+                            "bg-red-100 text-red-700",
+                            "Every extra payment earns the loan's rate with no risk - more than investing is expected to return after tax.",
+                        ),
+                        AdviceAction::Invest => (
+                            "bg-green-100 text-green-700",
+                            "The loan is cheaper than what investing is expected to return after tax, so keep paying it on schedule and invest the rest.",
+                        ),
+                    };
+                    html! {
+                        <div class="bg-white rounded-lg shadow-md p-6 mb-6">
+                            <div class="flex justify-between items-center mb-2">
+                                <h3 class="text-lg font-semibold text-gray-800">{ "Pay Off Debt or Invest?" }</h3>
+                                <span class={format!("text-sm font-semibold px-3 py-1 rounded-full {}", badge)}>
+                                    { advice.action.name() }
+                                </span>
+                            </div>
+                            <p class="text-sm text-gray-600 mb-2">
+                                { format!(
+                                    "{} costs {}% a year. Stocks are expected to return {}% ({}% after tax).",
+                                    advice.debt_name.as_deref().unwrap_or_default(),
+                                    percent(advice.debt_rate),
+                                    percent(advice.expected_return),
+                                    percent(advice.after_tax_return),
+                                ) }
+                            </p>
+                            <p class="text-xs text-gray-500">{ reason }</p>
+                        </div>
+                    }
+                } else {
+                    html! {}
+                }}
+
+                // Where to Save
+                {if contribution_suggestions.is_empty() {
+                    html! {}
+                } else {
+                    html! {
+                        <div class="bg-white rounded-lg shadow-md p-6 mb-6">
+                            <h3 class="text-lg font-semibold text-gray-800 mb-2">{ "Where to Save" }</h3>
+                            <p class="text-sm text-gray-600 mb-4">
+                                { "Fill employer-matched and tax-deductible accounts before investing in a taxable account." }
+                            </p>
+                            <div class="space-y-2">
+                                { for contribution_suggestions.iter().map(|suggestion| html! {
+                                    <div class="flex justify-between items-center border-b border-gray-100 pb-2">
+                                        <div>
+                                            <p class="text-sm font-medium text-gray-800">{ &suggestion.account_name }</p>
+                                            <p class="text-xs text-gray-500">
+                                                { suggestion.reason.name() }
+                                                {if suggestion.expected_tax_savings > Decimal::ZERO {
+                                                    format!(" · saves {} in tax", currency.format(suggestion.expected_tax_savings))
+                                                } else {
+                                                    String::new()
+                                                }}
+                                            </p>
+                                        </div>
+                                        <p class="text-sm font-semibold text-gray-800">
+                                            { format!("{}/month", currency.format(suggestion.monthly_amount())) }
+                                        </p>
+                                    </div>
+                                }) }
+                            </div>
+                        </div>
+                    }
+                }}
+
+                // Big Purchases
+                <div class="bg-white rounded-lg shadow-md p-6 mb-6">
+                    <h3 class="text-lg font-semibold text-gray-800 mb-2">{ "Big Purchases" }</h3>
+                    <p class="text-sm text-gray-600 mb-4">
+                        {match game_state.offered_loan_rate(LoanKind::Installment) {
+                            Ok(rate) => format!(
+                                "Pay the full price now, or put {}% down and pay the rest over {} months at {:.2}% a year.",
+                                (INSTALLMENT_DOWN_PAYMENT * Decimal::from(100)).normalize(),
+                                INSTALLMENT_MONTHS,
+                                rate * Decimal::from(100),
+                            ),
+                            Err(_) => "Your credit score is too low to buy on installments.".to_string(),
+                        }}
+                    </p>
+                    {if let Some(message) = &*purchase_message {
+                        html! { <p class="text-sm text-red-600 mb-3">{ message }</p> }
+                    } else {
+                        html! {}
+                    }}
+                    <div class="space-y-3">
+                        {PURCHASE_OPTIONS.iter().enumerate().map(|(index, option)| {
+                            let name = option.name;
+                            let price = option.price(market.as_ref());
+                            let buy = |financed: bool| {
+                                let on_purchase = on_purchase.clone();
+                                Callback::from(move |_| on_purchase.emit((index, financed)))
+                            };
+                            html! {
+                                <div key={name} class="flex justify-between items-center p-3 bg-gray-50 rounded-lg">
+                                    <div>
+                                        <p class="text-sm font-semibold text-gray-800">{ name }</p>
+                                        <p class="text-xs text-gray-500">{ currency.format(price) }</p>
+                                    </div>
+                                    <div class="flex gap-2">
+                                        <button
+                                            onclick={buy(false)}
+                                            class="bg-green-500 hover:bg-green-600 text-white text-sm font-semibold py-1 px-3 rounded transition"
+                                        >
+                                            { "Pay Cash" }
+                                        </button>
+                                        <button
+                                            onclick={buy(true)}
+                                            class="bg-yellow-500 hover:bg-yellow-600 text-white text-sm font-semibold py-1 px-3 rounded transition"
+                                        >
+                                            { "Installments" }
+                                        </button>
+                                    </div>
+                                </div>
+                            }
+                        }).collect::<Html>()}
+                    </div>
+                </div>
+
+                // Assets
+                {if !finances.assets.is_empty() {
+                    html! {
+                        <div class="bg-white rounded-lg shadow-md p-6 mb-6">
+                            <h3 class="text-lg font-semibold text-gray-800 mb-4">{ "Assets" }</h3>
+                            <div class="space-y-3">
+                                {finances.assets.iter().map(|asset| {
+                                    let gain = asset.capital_gain();
+                                    let on_sell = {
+                                        let on_sell_asset = on_sell_asset.clone();
+                                        let asset_id = asset.id.clone();
+                                        Callback::from(move |_| on_sell_asset.emit(asset_id.clone()))
+                                    };
+                                    let on_rent = {
+                                        let on_toggle_rental = on_toggle_rental.clone();
+                                        let asset_id = asset.id.clone();
+                                        Callback::from(move |_| on_toggle_rental.emit(asset_id.clone()))
+                                    };
+                                    let can_rent = asset.category == AssetCategory::RealEstate
+                                        && (asset.rented
+                                            || asset.market_rent(market.gross_rental_yield())
+                                                > asset.monthly_cost);
+                                    html! {
+                                        <div key={asset.id.clone()} class="flex justify-between items-center p-3 bg-gray-50 rounded-lg">
+                                            <div>
+                                                <p class="text-sm font-semibold text-gray-800">{ &asset.name }</p>
+                                                <p class="text-xs text-gray-600">
+                                                    { format!(
+                                                        "Bought for {} • {} {}",
+                                                        currency.format(asset.purchase_price),
+                                                        if gain >= Decimal::ZERO { "Gain" } else { "Loss" },
+                                                        currency.format(gain.abs()),
+                                                    ) }
+                                                </p>
+                                                {if asset.rented {
+                                                    html! {
+                                                        <p class="text-xs text-green-600">
+                                                            { format!("Let for {}/month ({} after upkeep)", currency.format(asset.rental_income), currency.format(asset.net_rental_income())) }
+                                                        </p>
+                                                    }
+                                                } else {
+                                                    html! {}
+                                                }}
+                                            </div>
+                                            <div class="flex items-center gap-3">
+                                                <span class="text-lg font-bold text-gray-800">
+                                                    { currency.format(asset.value) }
+                                                </span>
+                                                {if can_rent {
+                                                    html! {
+                                                        <button
+                                                            onclick={on_rent}
+                                                            class="bg-indigo-500 hover:bg-indigo-600 text-white text-sm font-semibold py-1 px-3 rounded transition"
+                                                            title={format!("Typical rent: {}/month", currency.format(asset.market_rent(market.gross_rental_yield())))}
+                                                        >
+                                                            { if asset.rented { "End tenancy" } else { "Rent out" } }
+                                                        </button>
+                                                    }
+                                                } else {
+                                                    html! {}
+                                                }}
+                                                <button
+                                                    onclick={on_sell}
+                                                    class="bg-red-500 hover:bg-red-600 text-white text-sm font-semibold py-1 px-3 rounded transition"
+                                                >
+                                                    { "Sell" }
+                                                </button>
+                                            </div>
+                                        </div>
+                                    }
+                                }).collect::<Html>()}
+                            </div>
+                        </div>
+                    }
+                } else {
+                    html! {}
+                }}
+
                 // Financial Overview
                 <div class="bg-white rounded-lg shadow-md p-6 mb-6">
                     <h3 class="text-lg font-semibold text-gray-800 mb-4">{ "Financial Overview" }</h3>
@@ -610,17 +1537,29 @@ pub fn planning_screen(props: &PlanningProps) -> Html {
                             <span class="text-gray-600">{ "Monthly Income (Gross)" }</span>
                             <span class="text-lg font-bold text-green-600">
                                 {if monthly_income > Decimal::ZERO {
-                                    html! { <>{ format!("{:.2}", monthly_income) }{ " Kč" }</> }
+                                    html! { <>{ currency.format(monthly_income) }</> }
                                 } else {
                                     html! { <span class="text-gray-400">{ "No income yet" }</span> }
                                 }}
                             </span>
                         </div>
+                        {if monthly_income == Decimal::ZERO && market.self_payer_health_insurance() > Decimal::ZERO {
+                            html! {
+                                <p class="text-xs text-orange-700 -mt-2">
+                                    { format!(
+                                        "Without income you must pay health insurance yourself: {} a month (unless registered as a job seeker).",
+                                        currency.format(market.self_payer_health_insurance())
+                                    ) }
+                                </p>
+                            }
+                        } else {
+                            html! {}
+                        }}
                         <div class="flex justify-between items-center pb-3 border-b border-gray-200">
                             <span class="text-gray-600">{ "Monthly Expenses" }</span>
                             <span class="text-lg font-bold text-red-600">
                                 {if monthly_expenses > Decimal::ZERO {
-                                    html! { <>{ format!("{:.2}", monthly_expenses) }{ " Kč" }</> }
+                                    html! { <>{ currency.format(monthly_expenses) }</> }
                                 } else {
                                     html! { <span class="text-gray-400">{ "No expenses yet" }</span> }
                                 }}
@@ -639,7 +1578,7 @@ pub fn planning_screen(props: &PlanningProps) -> Html {
                                             <div class="flex justify-between items-center text-sm mb-1">
                                                 <span class="text-gray-600">{ "Food & Groceries" }</span>
                                                 <span class="text-gray-700">
-                                                    { format!("{:.0} Kč", food_budget.allocated) }
+                                                    { currency.format(food_budget.allocated) }
                                                 </span>
                                             </div>
                                         }
@@ -653,9 +1592,11 @@ pub fn planning_screen(props: &PlanningProps) -> Html {
                                         .map(|expense| {
                                             html! {
                                                 <div class="flex justify-between items-center text-sm mb-1">
-                                                    <span class="text-gray-600">{ &expense.name }</span>
+                                                    <span class="text-gray-600">
+                                                        { format!("{} (due day {})", expense.name, expense.due_day) }
+                                                    </span>
                                                     <span class="text-gray-700">
-                                                        { format!("{:.0} Kč", expense.monthly_amount) }
+                                                        { currency.format(expense.monthly_amount) }
                                                     </span>
                                                 </div>
                                             }
@@ -670,15 +1611,16 @@ pub fn planning_screen(props: &PlanningProps) -> Html {
                         <div class="flex justify-between items-center">
                             <span class="text-gray-600 font-semibold">{ "Cash Balance" }</span>
                             <span class="text-xl font-bold text-gray-800">
-                                { format!("{:.2}", finances.cash) }
-                                { " Kč" }
+                                { currency.format(finances.cash) }
                             </span>
                         </div>
+
+                        <FireProgress game_state={game_state.clone()} />
                     </div>
                 </div>
 
-                // Getting Started Info
-                {if monthly_income == Decimal::ZERO {
+                // Getting Started Info (guided mode shows its own hints)
+                {if monthly_income == Decimal::ZERO && !game_state.tutorial_enabled {
                     html! {
                         <div class="bg-yellow-50 border-l-4 border-yellow-400 p-6 mb-6">
                             <div class="flex">
@@ -725,7 +1667,10 @@ pub fn planning_screen(props: &PlanningProps) -> Html {
                 html! {
                     <JobBrowser
                         career={career.clone()}
+                        skills={game_state.player.skills.clone()}
+                        economy={game_state.economy.clone()}
                         market_id={game_state.market_id.clone()}
+                        application_message={(*application_message).clone()}
                         on_accept_job={on_accept_job}
                         on_close={on_close_job_browser}
                     />
@@ -741,6 +1686,9 @@ pub fn planning_screen(props: &PlanningProps) -> Html {
                         current_housing={housing.clone()}
                         market_id={game_state.market_id.clone()}
                         current_cash={finances.cash}
+                        deposit_refund={game_state.deposit_refund()}
+                        net_income={net_income}
+                        housing_cost_ratio={finances.housing_cost_ratio(net_income)}
                         on_select_housing={on_select_housing}
                         on_close={on_close_housing_browser}
                     />
@@ -751,3 +1699,21 @@ pub fn planning_screen(props: &PlanningProps) -> Html {
         </div>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fin_engine::CzechMarket;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_essential_budget_input_enforces_market_minimum() {
+        let market = CzechMarket;
+        assert_eq!(
+            essential_budget_input(&market, "2000"),
+            Some(market.essential_minimum())
+        );
+        assert_eq!(essential_budget_input(&market, "5000"), Some(dec!(5000)));
+        assert_eq!(essential_budget_input(&market, "lots"), None);
+    }
+}
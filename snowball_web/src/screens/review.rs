@@ -1,20 +1,22 @@
-use fin_engine::{CzechMarket, GameState};
+use crate::components::{FireProgress, TutorialHint};
+use crate::market::get_market_profile;
+use fin_engine::{Achievement, GameState, Goal, EMERGENCY_FUND_SCHEDULE_MONTHS};
 use rust_decimal::Decimal;
+use std::rc::Rc;
 use yew::prelude::*;
 
-fn get_market_profile(market_id: &str) -> Box<dyn fin_engine::market::MarketProfile> {
-    match market_id {
-        "czech" => Box::new(CzechMarket),
-        _ => Box::new(CzechMarket),
-    }
-}
-
-#[derive(Properties, PartialEq)]
+#[derive(Properties)]
 pub struct ReviewProps {
-    pub game_state: GameState,
+    pub game_state: Rc<GameState>,
     pub on_next_month: Callback<()>,
 }
 
+impl PartialEq for ReviewProps {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.game_state, &other.game_state) && self.on_next_month == other.on_next_month
+    }
+}
+
 #[function_component(ReviewScreen)]
 pub fn review_screen(props: &ReviewProps) -> Html {
     let game_state = &props.game_state;
@@ -22,14 +24,30 @@ pub fn review_screen(props: &ReviewProps) -> Html {
     let finances = &game_state.finances;
 
     let net_worth = finances.net_worth();
-    let financial_peace = player.financial_peace_score();
+    let market = get_market_profile(&game_state.market_id);
     let months_elapsed = game_state.months_elapsed();
+    let liquid_net_worth = finances.liquid_net_worth(market.as_ref(), months_elapsed);
+    let financial_peace = player.financial_peace_trend();
+    // The CSV is only built when the player asks for it, not on every render
+    let on_export_csv = {
+        let game_state = Rc::clone(game_state);
+        Callback::from(move |e: MouseEvent| {
+            let link: web_sys::Element = e.target_unchecked_into();
+            let href = format!(
+                "data:text/csv;charset=utf-8,{}",
+                String::from(js_sys::encode_uri_component(&game_state.history_to_csv()))
+            );
+            if let Err(err) = link.set_attribute("href", &href) {
+                web_sys::console::error_1(&err);
+            }
+        })
+    };
 
     // Calculate monthly cash flow breakdown
     let gross_income = finances.monthly_gross_income();
-    let market = get_market_profile(&game_state.market_id);
+    let currency = market.currency();
     let (net_income, total_tax) = if gross_income > Decimal::ZERO {
-        if let Ok(tax_breakdown) = market.calculate_income_tax(gross_income) {
+        if let Ok(tax_breakdown) = finances.monthly_taxes(market.as_ref()) {
             (gross_income - tax_breakdown.total, tax_breakdown.total)
         } else {
             (gross_income, Decimal::ZERO)
@@ -38,7 +56,29 @@ pub fn review_screen(props: &ReviewProps) -> Html {
         (Decimal::ZERO, Decimal::ZERO)
     };
     let total_expenses = finances.monthly_expenses();
+    let savings_rates = game_state.savings_rate_series();
+    let retirement_readiness = game_state
+        .retirement_readiness(market.as_ref())
+        .unwrap_or(0);
     let net_cash_flow = net_income - total_expenses;
+    let mut spending: Vec<_> = finances.expense_breakdown().into_iter().collect();
+    spending.sort_by_key(|(_, amount)| std::cmp::Reverse(*amount));
+    let total_spending: Decimal = spending.iter().map(|(_, amount)| *amount).sum();
+    let emergency_fund_progress = finances.emergency_fund_progress().round_dp(0);
+    let emergency_fund_contribution =
+        finances.emergency_fund_contribution(EMERGENCY_FUND_SCHEDULE_MONTHS);
+    let wellbeing = game_state
+        .history
+        .last()
+        .map(|snapshot| snapshot.wellbeing.clone())
+        .unwrap_or_default();
+    let signed = |delta: i16| {
+        if delta > 0 {
+            format!("+{}", delta)
+        } else {
+            delta.to_string()
+        }
+    };
 
     let on_continue = {
         let on_next_month = props.on_next_month.clone();
@@ -69,6 +109,14 @@ pub fn review_screen(props: &ReviewProps) -> Html {
                             <p class="text-lg font-bold text-gray-800">
                                 { months_elapsed }
                             </p>
+                            <a
+                                href="#"
+                                onclick={on_export_csv}
+                                download="snowball_history.csv"
+                                class="text-xs text-blue-600 hover:underline"
+                            >
+                                { "Export CSV" }
+                            </a>
                         </div>
                     </div>
                 </div>
@@ -76,13 +124,21 @@ pub fn review_screen(props: &ReviewProps) -> Html {
 
             // Main Content
             <div class="max-w-4xl mx-auto px-4 py-8">
+                <TutorialHint game_state={game_state.clone()} />
+
                 // Phase Indicator
                 <div class="bg-green-500 text-white rounded-lg p-6 mb-6 shadow-lg">
                     <div class="flex items-center justify-between">
                         <div>
-                            <h2 class="text-2xl font-bold mb-1">{ "Monthly Review" }</h2>
+                            <h2 class="text-2xl font-bold mb-1">{ game_state.phase.name() }</h2>
                             <p class="text-green-100">
-                                { "Phase 3: Review your progress and prepare for next month" }
+                                {if game_state.phase.is_retirement() {
+                                    "You are retired: living costs are now drawn from your savings"
+                                } else if game_state.phase.is_bankrupt() {
+                                    "Game over: your overdraft grew too deep for too long"
+                                } else {
+                                    "Phase 3: Review your progress and prepare for next month"
+                                }}
                             </p>
                         </div>
                         <div class="bg-green-400 rounded-full w-16 h-16 flex items-center justify-center">
@@ -113,25 +169,25 @@ pub fn review_screen(props: &ReviewProps) -> Html {
                         <div class="flex justify-between items-center pb-2 border-b border-gray-200">
                             <span class="text-sm text-gray-600">{ "Gross Income" }</span>
                             <span class="text-lg font-semibold text-gray-800">
-                                { format!("+{:.0} Kč", gross_income) }
+                                { format!("+{}", currency.format(gross_income)) }
                             </span>
                         </div>
                         <div class="flex justify-between items-center pb-2 border-b border-gray-200">
                             <span class="text-sm text-gray-600">{ "Taxes & Insurance" }</span>
                             <span class="text-lg font-semibold text-red-600">
-                                { format!("-{:.0} Kč", total_tax) }
+                                { format!("-{}", currency.format(total_tax)) }
                             </span>
                         </div>
                         <div class="flex justify-between items-center pb-2 border-b border-gray-200">
                             <span class="text-sm text-gray-600">{ "Net Income (After Tax)" }</span>
                             <span class="text-lg font-semibold text-green-600">
-                                { format!("{:.0} Kč", net_income) }
+                                { currency.format(net_income) }
                             </span>
                         </div>
                         <div class="flex justify-between items-center pb-2 border-b border-gray-200">
                             <span class="text-sm text-gray-600">{ "Total Expenses" }</span>
                             <span class="text-lg font-semibold text-red-600">
-                                { format!("-{:.0} Kč", total_expenses) }
+                                { format!("-{}", currency.format(total_expenses)) }
                             </span>
                         </div>
                         <div class={format!(
@@ -152,15 +208,47 @@ pub fn review_screen(props: &ReviewProps) -> Html {
                                 }
                             )}>
                                 { if net_cash_flow >= Decimal::ZERO {
-                                    format!("+{:.0} Kč", net_cash_flow)
+                                    format!("+{}", currency.format(net_cash_flow))
                                 } else {
-                                    format!("{:.0} Kč", net_cash_flow)
+                                    currency.format(net_cash_flow)
                                 }}
                             </span>
                         </div>
                     </div>
                 </div>
 
+                // Where the Money Goes
+                {if total_spending > Decimal::ZERO {
+                    html! {
+                        <div class="bg-white rounded-lg shadow-md p-6 mb-6">
+                            <h3 class="text-lg font-semibold text-gray-800 mb-4">{ "Where the Money Goes" }</h3>
+                            <div class="space-y-3">
+                                { for spending.iter().map(|(category, amount)| {
+                                    let share = (*amount / total_spending * Decimal::from(100)).round_dp(0);
+                                    html! {
+                                        <div>
+                                            <div class="flex justify-between text-sm mb-1">
+                                                <span class="text-gray-600">{ category.name() }</span>
+                                                <span class="font-semibold text-gray-800">
+                                                    { format!("{} ({}%)", currency.format(*amount), share) }
+                                                </span>
+                                            </div>
+                                            <div class="w-full bg-gray-200 rounded-full h-2">
+                                                <div class="bg-indigo-500 h-2 rounded-full" style={format!("width: {}%", share)}></div>
+                                            </div>
+                                        </div>
+                                    }
+                                }) }
+                            </div>
+                            <p class="text-xs text-gray-500 mt-3">
+                                { "Recurring bills, insurance, debt payments and your monthly budgets." }
+                            </p>
+                        </div>
+                    }
+                } else {
+                    html! {}
+                }}
+
                 // Financial Summary
                 <div class="bg-white rounded-lg shadow-md p-6 mb-6">
                     <h3 class="text-lg font-semibold text-gray-800 mb-4">{ "Financial Summary" }</h3>
@@ -168,27 +256,81 @@ pub fn review_screen(props: &ReviewProps) -> Html {
                         <div class="bg-blue-50 rounded-lg p-4">
                             <p class="text-sm text-gray-600 mb-1">{ "Net Worth" }</p>
                             <p class="text-2xl font-bold text-blue-600">
-                                { format!("{:.2}", net_worth) }
-                                { " Kč" }
+                                { currency.format(net_worth) }
                             </p>
                             <p class="text-xs text-gray-500 mt-1">
                                 // TODO: Show change from last month
-                                { "—" }
+                                {if game_state.cumulative_inflation > Decimal::ZERO {
+                                    format!("{} in start-year money", currency.format(game_state.real_net_worth()))
+                                } else {
+                                    "—".to_string()
+                                }}
                             </p>
+                            {match &liquid_net_worth {
+                                Ok(liquid) if *liquid < net_worth => html! {
+                                    <p class="text-xs text-gray-500" title="After quick-sale discounts and tax on property and vehicles">
+                                        { format!("{} if sold quickly", currency.format(*liquid)) }
+                                    </p>
+                                },
+                                Ok(_) => html! {},
+                                Err(e) => html! {
+                                    <p class="text-xs text-gray-500" title={e.clone()}>
+                                        { "Quick-sale value unavailable" }
+                                    </p>
+                                },
+                            }}
                         </div>
                         <div class="bg-green-50 rounded-lg p-4">
                             <p class="text-sm text-gray-600 mb-1">{ "Cash Balance" }</p>
                             <p class="text-2xl font-bold text-green-600">
-                                { format!("{:.2}", finances.cash) }
-                                { " Kč" }
+                                { currency.format(finances.cash) }
                             </p>
                             <p class="text-xs text-gray-500 mt-1">
                                 { "Available for spending" }
                             </p>
                         </div>
                     </div>
+                    <div class="mt-4">
+                        <FireProgress game_state={game_state.clone()} />
+                    </div>
                 </div>
 
+                // Savings Rate Trend
+                {if !savings_rates.is_empty() {
+                    let recent = &savings_rates[savings_rates.len().saturating_sub(12)..];
+                    html! {
+                        <div class="bg-white rounded-lg shadow-md p-6 mb-6">
+                            <h3 class="text-lg font-semibold text-gray-800 mb-1">{ "Savings Rate" }</h3>
+                            <p class="text-sm text-gray-600 mb-4">
+                                { "Share of take-home pay you kept each month. The higher it stays, the sooner you reach financial independence." }
+                            </p>
+                            <div class="space-y-2">
+                                {recent.iter().map(|(time, rate)| {
+                                    let width = rate.abs().min(Decimal::from(100));
+                                    html! {
+                                        <div key={format!("{}-{}", time.year, time.month.value())} class="flex items-center gap-3 text-sm">
+                                            <span class="w-24 text-gray-600">
+                                                { format!("{} {}", time.month.name(), time.year) }
+                                            </span>
+                                            <div class="flex-1 bg-gray-100 rounded-full h-3">
+                                                <div
+                                                    class={if *rate >= Decimal::ZERO { "bg-green-500 h-3 rounded-full" } else { "bg-red-500 h-3 rounded-full" }}
+                                                    style={format!("width: {}%", width.round_dp(0))}
+                                                ></div>
+                                            </div>
+                                            <span class={if *rate >= Decimal::ZERO { "w-16 text-right font-semibold text-green-600" } else { "w-16 text-right font-semibold text-red-600" }}>
+                                                { format!("{}%", rate.round_dp(1)) }
+                                            </span>
+                                        </div>
+                                    }
+                                }).collect::<Html>()}
+                            </div>
+                        </div>
+                    }
+                } else {
+                    html! {}
+                }}
+
                 // Player Well-being
                 <div class="bg-white rounded-lg shadow-md p-6 mb-6">
                     <h3 class="text-lg font-semibold text-gray-800 mb-4">{ "Your Well-being" }</h3>
@@ -235,10 +377,13 @@ pub fn review_screen(props: &ReviewProps) -> Html {
 
                         <div>
                             <div class="flex justify-between mb-2">
-                                <span class="text-sm text-gray-600">{ "Financial Peace Score" }</span>
+                                <span class="text-sm text-gray-600">{ "Financial Peace Score (trend)" }</span>
                                 <span class="text-sm font-semibold text-indigo-600">
                                     { financial_peace }
                                     { "/100" }
+                                    <span class="text-xs font-normal text-gray-500">
+                                        { format!(" (this month {})", player.financial_peace_score()) }
+                                    </span>
                                 </span>
                             </div>
                             <div class="bg-gray-200 rounded-full h-3">
@@ -248,6 +393,77 @@ pub fn review_screen(props: &ReviewProps) -> Html {
                                 ></div>
                             </div>
                         </div>
+
+                        <div>
+                            <div class="flex justify-between mb-2">
+                                <span class="text-sm text-gray-600">
+                                    { format!("Retirement Readiness (age {})", game_state.retirement_age(market.as_ref())) }
+                                </span>
+                                <span class="text-sm font-semibold text-teal-600">
+                                    { retirement_readiness }
+                                    { "/100" }
+                                </span>
+                            </div>
+                            <div class="bg-gray-200 rounded-full h-3">
+                                <div
+                                    class="bg-teal-500 h-3 rounded-full transition-all"
+                                    style={format!("width: {}%", retirement_readiness)}
+                                ></div>
+                            </div>
+                        </div>
+                    </div>
+                </div>
+
+                // Goals
+                <div class="bg-white rounded-lg shadow-md p-6 mb-6">
+                    <h3 class="text-lg font-semibold text-gray-800 mb-4">{ "Goals" }</h3>
+                    <div class="space-y-2">
+                        {game_state.goal_progress(market.as_ref()).into_iter().map(|(goal, met)| {
+                            let tracks_emergency_fund = !met && goal == Goal::EmergencyFund;
+                            html! {
+                                <div class={format!(
+                                    "flex items-center gap-3 p-2 rounded-lg {}",
+                                    if met { "bg-green-50" } else { "bg-gray-50" }
+                                )}>
+                                    <span class="text-lg">{ if met { "✅" } else { "⬜" } }</span>
+                                    <div class="flex-1">
+                                        <span class={format!(
+                                            "text-sm {}",
+                                            if met { "font-semibold text-gray-800" } else { "text-gray-600" }
+                                        )}>
+                                            {match goal {
+                                                Goal::NetWorth(amount) => {
+                                                    format!("Reach {} net worth", currency.format(amount))
+                                                }
+                                                _ => goal.name(),
+                                            }}
+                                        </span>
+                                        {if tracks_emergency_fund {
+                                            html! {
+                                                <div class="mt-1">
+                                                    <div class="bg-gray-200 rounded-full h-2">
+                                                        <div
+                                                            class="bg-green-500 h-2 rounded-full"
+                                                            style={format!("width: {}%", emergency_fund_progress)}
+                                                        ></div>
+                                                    </div>
+                                                    <p class="text-xs text-gray-500 mt-1">
+                                                        { format!(
+                                                            "{}% saved. Set aside {} a month to finish in {} months.",
+                                                            emergency_fund_progress,
+                                                            currency.format(emergency_fund_contribution),
+                                                            EMERGENCY_FUND_SCHEDULE_MONTHS
+                                                        ) }
+                                                    </p>
+                                                </div>
+                                            }
+                                        } else {
+                                            html! {}
+                                        }}
+                                    </div>
+                                </div>
+                            }
+                        }).collect::<Html>()}
                     </div>
                 </div>
 
@@ -265,6 +481,51 @@ pub fn review_screen(props: &ReviewProps) -> Html {
                             </div>
                         </div>
 
+                        {if wellbeing.effects.is_empty() {
+                            html! {}
+                        } else {
+                            html! {
+                                <div class="flex items-start gap-3 p-3 bg-pink-50 rounded-lg">
+                                    <span class="text-xl">{ "💗" }</span>
+                                    <div class="flex-1">
+                                        <p class="text-sm font-semibold text-gray-800">
+                                            { format!(
+                                                "Well-being: happiness {}, burnout {}",
+                                                signed(wellbeing.happiness()),
+                                                signed(wellbeing.burnout())
+                                            ) }
+                                        </p>
+                                        {wellbeing.top_factors(3).into_iter().map(|effect| {
+                                            let mut changes = Vec::new();
+                                            if effect.happiness != 0 {
+                                                changes.push(format!("happiness {}", signed(effect.happiness)));
+                                            }
+                                            if effect.burnout != 0 {
+                                                changes.push(format!("burnout {}", signed(effect.burnout)));
+                                            }
+                                            html! {
+                                                <p class="text-xs text-gray-600">
+                                                    { format!("{}: {}", effect.factor.name(), changes.join(", ")) }
+                                                </p>
+                                            }
+                                        }).collect::<Html>()}
+                                    </div>
+                                </div>
+                            }
+                        }}
+
+                        {Achievement::all().into_iter().filter(|a| game_state.achievements.contains(a)).map(|achievement| {
+                            html! {
+                                <div class="flex items-start gap-3 p-3 bg-purple-50 rounded-lg">
+                                    <span class="text-xl">{ "🏆" }</span>
+                                    <div>
+                                        <p class="text-sm font-semibold text-gray-800">{ achievement.name() }</p>
+                                        <p class="text-xs text-gray-600">{ "Achievement" }</p>
+                                    </div>
+                                </div>
+                            }
+                        }).collect::<Html>()}
+
                         {if finances.has_emergency_fund() {
                             html! {
                                 <div class="flex items-start gap-3 p-3 bg-green-50 rounded-lg">
@@ -300,14 +561,27 @@ pub fn review_screen(props: &ReviewProps) -> Html {
                 </div>
 
                 // Action Button
-                <div class="flex justify-center">
-                    <button
-                        onclick={on_continue}
-                        class="bg-gradient-to-r from-green-500 to-emerald-600 text-white font-bold py-4 px-8 rounded-lg hover:from-green-600 hover:to-emerald-700 transform transition hover:scale-105 shadow-lg text-lg"
-                    >
-                        { "Continue to Next Month →" }
-                    </button>
-                </div>
+                {if game_state.phase.is_bankrupt() {
+                    html! {
+                        <div class="bg-red-50 border-2 border-red-400 rounded-lg p-6 text-center">
+                            <p class="text-xl font-bold text-red-800 mb-1">{ "Bankrupt" }</p>
+                            <p class="text-sm text-red-700">
+                                { "Your cash stayed deeply overdrawn for months. Start a new game to try again." }
+                            </p>
+                        </div>
+                    }
+                } else {
+                    html! {
+                        <div class="flex justify-center">
+                            <button
+                                onclick={on_continue}
+                                class="bg-gradient-to-r from-green-500 to-emerald-600 text-white font-bold py-4 px-8 rounded-lg hover:from-green-600 hover:to-emerald-700 transform transition hover:scale-105 shadow-lg text-lg"
+                            >
+                                { "Continue to Next Month →" }
+                            </button>
+                        </div>
+                    }
+                }}
             </div>
         </div>
     }